@@ -1,17 +1,23 @@
-use node_runtime::ProposalsConfigParameters;
+use node_runtime::{
+    default_proposal_parameters, proposal_parameters_with_grace_and_voting_periods, Balance,
+    BlockNumber, ProposalParameters, ProposalType,
+};
+
+/// Proposal parameters by proposal type
+pub type ProposalsParameters = Vec<(ProposalType, ProposalParameters<BlockNumber, Balance>)>;
 
 /// Development chain config. 0 grace period for all proposals, ie.
 /// proposals executed immediatly. Short voting period.
-pub fn development() -> ProposalsConfigParameters {
-    ProposalsConfigParameters::with_grace_and_voting_periods(0, 200)
+pub fn development() -> ProposalsParameters {
+    proposal_parameters_with_grace_and_voting_periods(0, 200)
 }
 
 /// Staging chain config. Shorter grace periods and voting periods than default.
-pub fn staging() -> ProposalsConfigParameters {
-    ProposalsConfigParameters::with_grace_and_voting_periods(20, 30)
+pub fn staging() -> ProposalsParameters {
+    proposal_parameters_with_grace_and_voting_periods(20, 30)
 }
 
 /// The default configuration as defined in the runtime module
-pub fn production() -> ProposalsConfigParameters {
-    ProposalsConfigParameters::default()
+pub fn production() -> ProposalsParameters {
+    default_proposal_parameters()
 }
@@ -0,0 +1,201 @@
+//! Council election RPC methods.
+//!
+//! Exposes the `CouncilElectionApi` runtime API to the clients: current election stage, stage
+//! deadline, round, applicant stakes and manifestos, archived election results, the vote
+//! commitment hashing used on reveal and the encryption of the scheduled reveals.
+
+#![warn(missing_docs)]
+
+use std::sync::Arc;
+
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use node_runtime::{
+    opaque::Block, AccountId, Balance, BlockNumber, ContentId,
+    CouncilElectionApi as ElectionRuntimeApi, ElectionResult, ElectionStage, Hash, Manifesto,
+};
+use sc_rpc_api::DenyUnsafe;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{Bytes, H256};
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+/// Council election RPC methods.
+#[rpc]
+pub trait CouncilElectionApi<BlockHash> {
+    /// Returns the current election stage with its end block. None if no election is running.
+    #[rpc(name = "councilElection_stage")]
+    fn stage(&self, at: Option<BlockHash>) -> Result<Option<ElectionStage<BlockNumber>>>;
+
+    /// Returns the block number at which the current election stage ends.
+    #[rpc(name = "councilElection_stageEndsAt")]
+    fn stage_ends_at(&self, at: Option<BlockHash>) -> Result<Option<BlockNumber>>;
+
+    /// Returns the current election round.
+    #[rpc(name = "councilElection_round")]
+    fn round(&self, at: Option<BlockHash>) -> Result<u32>;
+
+    /// Returns the applicants of the current election with their total stakes.
+    #[rpc(name = "councilElection_applicantStakes")]
+    fn applicant_stakes(&self, at: Option<BlockHash>) -> Result<Vec<(AccountId, Balance)>>;
+
+    /// Returns the applicants of the current election with their manifestos.
+    #[rpc(name = "councilElection_applicantManifestos")]
+    fn applicant_manifestos(
+        &self,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<(AccountId, Manifesto<ContentId>)>>;
+
+    /// Returns the archived results of the election round. None if the round didn't elect
+    /// a council.
+    #[rpc(name = "councilElection_result")]
+    fn election_result(
+        &self,
+        round: u32,
+        at: Option<BlockHash>,
+    ) -> Result<Option<ElectionResult<AccountId, Balance, BlockNumber, ContentId>>>;
+
+    /// Computes the salted commitment hash for a vote, as verified on reveal.
+    #[rpc(name = "councilElection_voteCommitment")]
+    fn vote_commitment(&self, vote: AccountId, salt: Bytes, at: Option<BlockHash>) -> Result<Hash>;
+
+    /// Encrypts the vote and salt with the key for the scheduled reveal. The key must be unique
+    /// per vote and stored in the voter's node offchain local storage. The call takes the secret
+    /// key, so it is unsafe and only served to the local clients.
+    #[rpc(name = "councilElection_encryptReveal")]
+    fn encrypt_reveal(
+        &self,
+        key: H256,
+        vote: AccountId,
+        salt: Bytes,
+        at: Option<BlockHash>,
+    ) -> Result<Bytes>;
+}
+
+/// Council election RPC handler.
+pub struct CouncilElection<C> {
+    client: Arc<C>,
+    deny_unsafe: DenyUnsafe,
+}
+
+impl<C> CouncilElection<C> {
+    /// Creates a new council election RPC handler.
+    pub fn new(client: Arc<C>, deny_unsafe: DenyUnsafe) -> Self {
+        CouncilElection {
+            client,
+            deny_unsafe,
+        }
+    }
+}
+
+// Error code for the failed runtime API calls.
+const RUNTIME_ERROR: i64 = 1;
+
+// Converts a runtime API error into the RPC error.
+fn runtime_error(err: impl std::fmt::Debug) -> RpcError {
+    RpcError {
+        code: ErrorCode::ServerError(RUNTIME_ERROR),
+        message: "Unable to query the council election state.".into(),
+        data: Some(format!("{:?}", err).into()),
+    }
+}
+
+impl<C> CouncilElection<C>
+where
+    C: HeaderBackend<Block>,
+{
+    // Resolves the requested block or falls back to the best block.
+    fn block_id(&self, at: Option<<Block as BlockT>::Hash>) -> BlockId<Block> {
+        BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash))
+    }
+}
+
+impl<C> CouncilElectionApi<<Block as BlockT>::Hash> for CouncilElection<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: ElectionRuntimeApi<Block>,
+{
+    fn stage(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Option<ElectionStage<BlockNumber>>> {
+        self.client
+            .runtime_api()
+            .stage(&self.block_id(at))
+            .map_err(runtime_error)
+    }
+
+    fn stage_ends_at(&self, at: Option<<Block as BlockT>::Hash>) -> Result<Option<BlockNumber>> {
+        self.client
+            .runtime_api()
+            .stage_ends_at(&self.block_id(at))
+            .map_err(runtime_error)
+    }
+
+    fn round(&self, at: Option<<Block as BlockT>::Hash>) -> Result<u32> {
+        self.client
+            .runtime_api()
+            .round(&self.block_id(at))
+            .map_err(runtime_error)
+    }
+
+    fn applicant_stakes(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<(AccountId, Balance)>> {
+        self.client
+            .runtime_api()
+            .applicant_stakes(&self.block_id(at))
+            .map_err(runtime_error)
+    }
+
+    fn applicant_manifestos(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<(AccountId, Manifesto<ContentId>)>> {
+        self.client
+            .runtime_api()
+            .applicant_manifestos(&self.block_id(at))
+            .map_err(runtime_error)
+    }
+
+    fn election_result(
+        &self,
+        round: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Option<ElectionResult<AccountId, Balance, BlockNumber, ContentId>>> {
+        self.client
+            .runtime_api()
+            .election_result(&self.block_id(at), round)
+            .map_err(runtime_error)
+    }
+
+    fn vote_commitment(
+        &self,
+        vote: AccountId,
+        salt: Bytes,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Hash> {
+        self.client
+            .runtime_api()
+            .vote_commitment(&self.block_id(at), vote, salt.0)
+            .map_err(runtime_error)
+    }
+
+    fn encrypt_reveal(
+        &self,
+        key: H256,
+        vote: AccountId,
+        salt: Bytes,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Bytes> {
+        self.deny_unsafe.check_if_safe()?;
+
+        self.client
+            .runtime_api()
+            .encrypt_reveal(&self.block_id(at), key.0, vote, salt.0)
+            .map_err(runtime_error)?
+            .map(Bytes)
+            .ok_or_else(|| RpcError::invalid_params("Unable to encrypt the reveal."))
+    }
+}
@@ -3,5 +3,7 @@ pub mod cli;
 #[macro_use]
 pub mod service;
 pub mod command;
+pub mod council_election_rpc;
 pub mod node_executor;
 pub mod node_rpc;
+pub mod proposals_engine_rpc;
@@ -108,10 +108,14 @@ where
     >,
     C::Api: BabeApi<Block>,
     C::Api: BlockBuilder<Block>,
+    C::Api: node_runtime::CouncilElectionApi<Block>,
+    C::Api: node_runtime::ProposalsEngineApi<Block>,
     P: TransactionPool + 'static,
     M: jsonrpc_core::Metadata + Default,
     SC: SelectChain<Block> + 'static,
 {
+    use crate::council_election_rpc::{CouncilElection, CouncilElectionApi};
+    use crate::proposals_engine_rpc::{ProposalsEngine, ProposalsEngineApi};
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApi};
     use substrate_frame_rpc_system::{FullSystem, SystemApi};
 
@@ -145,6 +149,13 @@ where
     io.extend_with(TransactionPaymentApi::to_delegate(TransactionPayment::new(
         client.clone(),
     )));
+    io.extend_with(CouncilElectionApi::to_delegate(CouncilElection::new(
+        client.clone(),
+        deny_unsafe,
+    )));
+    io.extend_with(ProposalsEngineApi::to_delegate(ProposalsEngine::new(
+        client.clone(),
+    )));
     io.extend_with(sc_consensus_babe_rpc::BabeApi::to_delegate(
         BabeRpcHandler::new(
             client,
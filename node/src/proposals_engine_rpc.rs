@@ -0,0 +1,180 @@
+//! Proposals engine RPC methods.
+//!
+//! Exposes the `ProposalsEngineApi` runtime API to the clients: active and pending execution
+//! proposals, their voting status with the decision if the voting ended now, the votes, the
+//! dry-run of the proposal call and the proposals history.
+
+#![warn(missing_docs)]
+
+use std::sync::Arc;
+
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use node_runtime::{
+    opaque::Block, BlockNumber, MemberId, ProposalDryRunResult, ProposalId, ProposalVotingStatus,
+    ProposalsEngineApi as EngineRuntimeApi, RuntimeArchivedProposal, RuntimeProposal, VoteKind,
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+// Proposal open for voting with its id and voting status.
+type ActiveProposal = (
+    ProposalId,
+    RuntimeProposal,
+    ProposalVotingStatus<BlockNumber>,
+);
+
+/// Proposals engine RPC methods.
+#[rpc]
+pub trait ProposalsEngineApi<BlockHash> {
+    /// Returns the proposals open for voting with their voting status.
+    #[rpc(name = "proposalsEngine_activeProposals")]
+    fn active_proposals(&self, at: Option<BlockHash>) -> Result<Vec<ActiveProposal>>;
+
+    /// Returns the approved proposals pending execution.
+    #[rpc(name = "proposalsEngine_pendingExecutionProposals")]
+    fn pending_execution_proposals(
+        &self,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<(ProposalId, RuntimeProposal)>>;
+
+    /// Returns the voting status of the proposal open for voting, including the decision if
+    /// the voting ended now. None if the proposal is not open for voting.
+    #[rpc(name = "proposalsEngine_votingStatus")]
+    fn voting_status(
+        &self,
+        proposal_id: ProposalId,
+        at: Option<BlockHash>,
+    ) -> Result<Option<ProposalVotingStatus<BlockNumber>>>;
+
+    /// Returns the current votes of the proposal by voter.
+    #[rpc(name = "proposalsEngine_votes")]
+    fn votes(
+        &self,
+        proposal_id: ProposalId,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<(MemberId, VoteKind)>>;
+
+    /// Dispatches the proposal call at the block state without committing the changes.
+    /// Returns the unmet dependencies or the dispatch error with its message if the proposal
+    /// execution would fail.
+    #[rpc(name = "proposalsEngine_dryRun")]
+    fn dry_run(
+        &self,
+        proposal_id: ProposalId,
+        at: Option<BlockHash>,
+    ) -> Result<ProposalDryRunResult>;
+
+    /// Returns a page of the proposals history: archived proposals with ids starting from the
+    /// provided id. The page covers `limit` proposal ids.
+    #[rpc(name = "proposalsEngine_proposalHistory")]
+    fn proposal_history(
+        &self,
+        first_proposal_id: ProposalId,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<(ProposalId, RuntimeArchivedProposal)>>;
+}
+
+/// Proposals engine RPC handler.
+pub struct ProposalsEngine<C> {
+    client: Arc<C>,
+}
+
+impl<C> ProposalsEngine<C> {
+    /// Creates a new proposals engine RPC handler.
+    pub fn new(client: Arc<C>) -> Self {
+        ProposalsEngine { client }
+    }
+}
+
+// Error code for the failed runtime API calls.
+const RUNTIME_ERROR: i64 = 1;
+
+// Converts a runtime API error into the RPC error.
+fn runtime_error(err: impl std::fmt::Debug) -> RpcError {
+    RpcError {
+        code: ErrorCode::ServerError(RUNTIME_ERROR),
+        message: "Unable to query the proposals engine state.".into(),
+        data: Some(format!("{:?}", err).into()),
+    }
+}
+
+impl<C> ProposalsEngine<C>
+where
+    C: HeaderBackend<Block>,
+{
+    // Resolves the requested block or falls back to the best block.
+    fn block_id(&self, at: Option<<Block as BlockT>::Hash>) -> BlockId<Block> {
+        BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash))
+    }
+}
+
+impl<C> ProposalsEngineApi<<Block as BlockT>::Hash> for ProposalsEngine<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: EngineRuntimeApi<Block>,
+{
+    fn active_proposals(&self, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<ActiveProposal>> {
+        self.client
+            .runtime_api()
+            .active_proposals(&self.block_id(at))
+            .map_err(runtime_error)
+    }
+
+    fn pending_execution_proposals(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<(ProposalId, RuntimeProposal)>> {
+        self.client
+            .runtime_api()
+            .pending_execution_proposals(&self.block_id(at))
+            .map_err(runtime_error)
+    }
+
+    fn voting_status(
+        &self,
+        proposal_id: ProposalId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Option<ProposalVotingStatus<BlockNumber>>> {
+        self.client
+            .runtime_api()
+            .voting_status(&self.block_id(at), proposal_id)
+            .map_err(runtime_error)
+    }
+
+    fn votes(
+        &self,
+        proposal_id: ProposalId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<(MemberId, VoteKind)>> {
+        self.client
+            .runtime_api()
+            .votes(&self.block_id(at), proposal_id)
+            .map_err(runtime_error)
+    }
+
+    fn dry_run(
+        &self,
+        proposal_id: ProposalId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<ProposalDryRunResult> {
+        self.client
+            .runtime_api()
+            .dry_run(&self.block_id(at), proposal_id)
+            .map_err(runtime_error)
+    }
+
+    fn proposal_history(
+        &self,
+        first_proposal_id: ProposalId,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<(ProposalId, RuntimeArchivedProposal)>> {
+        self.client
+            .runtime_api()
+            .proposal_history(&self.block_id(at), first_proposal_id, limit)
+            .map_err(runtime_error)
+    }
+}
@@ -3,8 +3,8 @@ use codec::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 
 /// Length constraint for input validation
-#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
-#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Copy)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Copy, Debug)]
 pub struct InputValidationLengthConstraint {
     /// Minimum length
     pub min: u16,
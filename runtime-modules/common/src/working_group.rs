@@ -12,4 +12,7 @@ pub enum WorkingGroup {
     */
     /// Storage working group: working_group::Instance2.
     Storage,
+
+    /// Content directory working group: working_group::Instance3.
+    Content,
 }
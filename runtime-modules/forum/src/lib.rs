@@ -359,6 +359,9 @@ decl_event!(
 
         /// Given account was set as forum sudo.
         ForumSudoSet(Option<AccountId>, Option<AccountId>),
+
+        /// Input validation constraints were updated.
+        InputValidationConstraintsUpdated,
     }
 );
 
@@ -393,6 +396,34 @@ decl_module! {
             Ok(())
         }
 
+        /// Set input validation constraints.
+        #[weight = 10_000_000] // TODO: adjust weight
+        fn set_input_validation_constraints(
+            origin,
+            category_title_constraint: InputValidationLengthConstraint,
+            category_description_constraint: InputValidationLengthConstraint,
+            thread_title_constraint: InputValidationLengthConstraint,
+            post_text_constraint: InputValidationLengthConstraint,
+            thread_moderation_rationale_constraint: InputValidationLengthConstraint,
+            post_moderation_rationale_constraint: InputValidationLengthConstraint
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            // Update constraints
+            CategoryTitleConstraint::put(category_title_constraint);
+            CategoryDescriptionConstraint::put(category_description_constraint);
+            ThreadTitleConstraint::put(thread_title_constraint);
+            PostTextConstraint::put(post_text_constraint);
+            ThreadModerationRationaleConstraint::put(thread_moderation_rationale_constraint);
+            PostModerationRationaleConstraint::put(post_moderation_rationale_constraint);
+
+            // Generate event
+            Self::deposit_event(RawEvent::InputValidationConstraintsUpdated);
+
+            // All good.
+            Ok(())
+        }
+
         /// Add a new category.
         #[weight = 10_000_000] // TODO: adjust weight
         fn create_category(origin, parent: Option<CategoryId>, title: Vec<u8>, description: Vec<u8>) -> DispatchResult {
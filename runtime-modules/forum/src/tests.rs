@@ -67,6 +67,61 @@ fn set_forum_sudo_update() {
     });
 }
 
+/*
+ * set_input_validation_constraints
+ * ==============================================================================
+ */
+
+#[test]
+fn set_input_validation_constraints_successfully() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let constraint = InputValidationLengthConstraint {
+            min: 5,
+            max_min_diff: 50,
+        };
+
+        // Non-root origin is rejected
+        assert!(TestForumModule::set_input_validation_constraints(
+            mock_origin(NOT_FORUM_SUDO_ORIGIN),
+            constraint,
+            constraint,
+            constraint,
+            constraint,
+            constraint,
+            constraint
+        )
+        .is_err());
+
+        assert_ok!(TestForumModule::set_input_validation_constraints(
+            mock_origin(OriginType::Root),
+            constraint,
+            constraint,
+            constraint,
+            constraint,
+            constraint,
+            constraint
+        ));
+
+        assert_eq!(TestForumModule::category_title_constraint(), constraint);
+        assert_eq!(
+            TestForumModule::category_description_constraint(),
+            constraint
+        );
+        assert_eq!(TestForumModule::thread_title_constraint(), constraint);
+        assert_eq!(TestForumModule::post_text_constraint(), constraint);
+        assert_eq!(
+            TestForumModule::thread_moderation_rationale_constraint(),
+            constraint
+        );
+        assert_eq!(
+            TestForumModule::post_moderation_rationale_constraint(),
+            constraint
+        );
+    });
+}
+
 /*
  * create_category
  * ==============================================================================
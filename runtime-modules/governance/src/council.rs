@@ -1,8 +1,20 @@
-use frame_support::{debug, decl_event, decl_module, decl_storage, ensure};
-use sp_arithmetic::traits::{One, Zero};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+use codec::{Decode, Encode};
+use frame_support::dispatch::UnfilteredDispatchable;
+use frame_support::storage::IterableStorageDoubleMap;
+use frame_support::storage::IterableStorageMap;
+use frame_support::traits::{EnsureOrigin, Filter, Get};
+use frame_support::{debug, decl_event, decl_module, decl_storage, ensure, Parameter};
+use sp_arithmetic::traits::{One, Saturating, Zero};
+use sp_arithmetic::Perbill;
+use sp_runtime::RuntimeDebug;
+use sp_std::boxed::Box;
+use sp_std::marker::PhantomData;
 use sp_std::vec;
 use sp_std::vec::Vec;
-use system::ensure_root;
+use system::{ensure_root, ensure_signed};
 
 pub use super::election::{self, CouncilElected, Seat, Seats};
 pub use common::currency::{BalanceOf, GovernanceCurrency};
@@ -26,6 +38,170 @@ pub trait Trait: system::Trait + recurringrewards::Trait + GovernanceCurrency {
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
 
     type CouncilTermEnded: CouncilTermEnded;
+
+    /// Origin the council motions are executed with.
+    type MotionOrigin: From<RawOrigin>;
+
+    /// Call type executed by the council motions.
+    type MotionCall: Parameter + UnfilteredDispatchable<Origin = Self::MotionOrigin>;
+
+    /// Whitelist of the calls allowed in the council motions.
+    type MotionCallFilter: Filter<Self::MotionCall>;
+
+    /// Origin allowed to make the calls whitelisted for the council motions: root or an
+    /// approved council motion.
+    type CouncilOrigin: EnsureOrigin<Self::Origin>;
+
+    /// Max length of the encoded motion call.
+    type MaxMotionCallLength: Get<u32>;
+
+    /// Number of blocks the motion can be approved for before it expires.
+    type MotionExpiryPeriod: Get<Self::BlockNumber>;
+
+    /// Council size the motion approval threshold is derived from.
+    type CouncilSize: Get<u32>;
+
+    /// Percentage of the council size required to approve a motion.
+    type MotionApprovalThresholdPercentage: Get<u32>;
+
+    /// Provides the id of the proposal being executed, if any. Recorded with the council spends.
+    type ExecutingProposalId: Get<Option<u32>>;
+}
+
+/// Council term index. The genesis council has term zero.
+pub type TermIndex = u32;
+
+/// Origin of the council mint spend.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq)]
+pub enum SpendingCategory {
+    /// Spend executed by an approved spending proposal.
+    SpendingProposal,
+
+    /// Spend executed directly with the root origin.
+    Root,
+
+    /// Councilor reward payout.
+    CouncilReward,
+
+    /// Recurring grant payout.
+    RecurringGrant,
+}
+
+impl SpendingCategory {
+    /// All spending categories.
+    pub fn all() -> Vec<SpendingCategory> {
+        vec![
+            SpendingCategory::SpendingProposal,
+            SpendingCategory::Root,
+            SpendingCategory::CouncilReward,
+            SpendingCategory::RecurringGrant,
+        ]
+    }
+}
+
+/// Index of the council mint spend in the term ledger.
+pub type SpendIndex = u32;
+
+/// Council mint spend record.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq)]
+pub struct SpendRecord<AccountId, Balance, BlockNumber> {
+    /// Account the tokens were transferred to.
+    pub destination: AccountId,
+
+    /// Amount spent from the council mint.
+    pub amount: Balance,
+
+    /// When the spend was made.
+    pub block: BlockNumber,
+
+    /// Proposal which executed the spend, if any.
+    pub proposal_id: Option<u32>,
+
+    /// Origin of the spend.
+    pub category: SpendingCategory,
+}
+
+/// Origin of the calls executed by the approved council motions.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum RawOrigin {
+    /// Call executed by the approved council motion.
+    Motion(MotionId),
+}
+
+/// Council origin type required by the runtime construction.
+pub type Origin = RawOrigin;
+
+/// Ensures the origin is root or an approved council motion.
+pub struct EnsureRootOrCouncilMotion<AccountId>(PhantomData<AccountId>);
+
+impl<O, AccountId> EnsureOrigin<O> for EnsureRootOrCouncilMotion<AccountId>
+where
+    O: Into<Result<system::RawOrigin<AccountId>, O>>
+        + From<system::RawOrigin<AccountId>>
+        + Into<Result<RawOrigin, O>>,
+{
+    type Success = ();
+
+    fn try_origin(origin: O) -> Result<Self::Success, O> {
+        match Into::<Result<system::RawOrigin<AccountId>, O>>::into(origin) {
+            Ok(system::RawOrigin::Root) => Ok(()),
+            Ok(other) => Err(O::from(other)),
+            Err(origin) => Into::<Result<RawOrigin, O>>::into(origin).map(|_| ()),
+        }
+    }
+}
+
+/// Council motion identifier.
+pub type MotionId = u32;
+
+/// Council motion: a call proposed by a councilor and executed once approved by the required
+/// number of councilors.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct Motion<AccountId, BlockNumber> {
+    /// Councilor who proposed the motion.
+    pub proposer: AccountId,
+
+    /// Encoded call to execute.
+    pub encoded_call: Vec<u8>,
+
+    /// Councilors who approved the motion, including the proposer.
+    pub approvals: Vec<AccountId>,
+
+    /// When the motion was proposed.
+    pub created_at: BlockNumber,
+
+    /// Block at which the motion expires if not yet approved.
+    pub expires_at: BlockNumber,
+}
+
+/// Recurring grant identifier.
+pub type RecurringGrantId = u32;
+
+/// Recurring grant: tokens periodically paid from the council mint to the destination account
+/// until the end block.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct RecurringGrant<AccountId, Balance, BlockNumber, RewardRelationshipId> {
+    /// Account the tokens are paid to.
+    pub destination: AccountId,
+
+    /// Amount paid at each payout.
+    pub amount_per_payout: Balance,
+
+    /// Interval in blocks between the payouts.
+    pub payout_interval: BlockNumber,
+
+    /// Block at which the grant ends.
+    pub ends_at: BlockNumber,
+
+    /// Reward relationship making the payouts.
+    pub reward_relationship: RewardRelationshipId,
+
+    /// Proposal which created the grant, if any.
+    pub proposal_id: Option<u32>,
 }
 
 decl_storage! {
@@ -51,6 +227,75 @@ decl_storage! {
 
         /// How many blocks after the reward is created, the first payout will be made
         pub FirstPayoutAfterRewardCreated get(fn first_payout_after_reward_created): T::BlockNumber;
+
+        /// Minimal share of the reward paid to a councilor regardless of the attendance. The
+        /// reward relationships pay out this share during the term.
+        pub AttendanceRewardFloor get(fn attendance_reward_floor): Perbill = Perbill::from_percent(50);
+
+        /// Number of proposals open for the voting of the council in the current term.
+        pub TermProposalCount get(fn term_proposal_count): u32;
+
+        /// Number of proposals each councilor voted on in the current term.
+        pub TermVotesByCouncilor get(fn term_votes_by_councilor): map hasher(blake2_128_concat)
+            T::AccountId => u32;
+
+        /// Number of the successful reward payouts to each councilor in the current term.
+        pub TermRewardPayouts get(fn term_reward_payouts): map hasher(blake2_128_concat)
+            T::AccountId => u32;
+
+        /// Count of all motions that have been proposed.
+        pub MotionCount get(fn motion_count): MotionId;
+
+        /// Motions waiting for the council approval.
+        pub Motions get(fn motions): map hasher(blake2_128_concat)
+            MotionId => Motion<T::AccountId, T::BlockNumber>;
+
+        /// Motions indexed by the block at which they expire.
+        MotionsByExpiry: double_map hasher(blake2_128_concat) T::BlockNumber,
+            hasher(blake2_128_concat) MotionId => ();
+
+        /// Index of the current council term, incremented on each elected council.
+        pub TermNumber get(fn term_number): TermIndex;
+
+        /// Number of the council mint spends recorded in each term.
+        pub TermSpendCount get(fn term_spend_count): map hasher(blake2_128_concat)
+            TermIndex => SpendIndex;
+
+        /// Council mint spends made in each term by the spend index.
+        pub TermSpends get(fn term_spends): double_map
+            hasher(blake2_128_concat) TermIndex,
+            hasher(blake2_128_concat) SpendIndex =>
+                SpendRecord<T::AccountId, minting::BalanceOf<T>, T::BlockNumber>;
+
+        /// Total amount spent from the council mint in each term.
+        pub TermSpendingTotal get(fn term_spending_total): map hasher(blake2_128_concat)
+            TermIndex => minting::BalanceOf<T>;
+
+        /// Amount spent from the council mint in each term per spending category.
+        pub TermSpendingByCategory get(fn term_spending_by_category): double_map
+            hasher(blake2_128_concat) TermIndex,
+            hasher(blake2_128_concat) SpendingCategory => minting::BalanceOf<T>;
+
+        /// Count of all recurring grants that have been created.
+        pub RecurringGrantCount get(fn recurring_grant_count): RecurringGrantId;
+
+        /// Active recurring grants paid from the council mint.
+        pub RecurringGrants get(fn recurring_grants): map hasher(blake2_128_concat)
+            RecurringGrantId => RecurringGrant<
+                T::AccountId,
+                minting::BalanceOf<T>,
+                T::BlockNumber,
+                T::RewardRelationshipId
+            >;
+
+        /// Active recurring grant ids by their end block.
+        pub RecurringGrantsByEndBlock get(fn recurring_grants_by_end_block): double_map
+            hasher(blake2_128_concat) T::BlockNumber,
+            hasher(blake2_128_concat) RecurringGrantId => ();
+
+        /// Active recurring grant ids by the reward relationship making their payouts.
+        pub RecurringGrantIdByRewardRelationship get(fn recurring_grant_id_by_reward_relationship):
+            map hasher(blake2_128_concat) T::RewardRelationshipId => Option<RecurringGrantId>;
     }
     add_extra_genesis {
         build(|_config: &GenesisConfig<T>| {
@@ -71,9 +316,27 @@ decl_storage! {
 
 // Event for this module.
 decl_event!(
-    pub enum Event<T> where <T as system::Trait>::BlockNumber {
+    pub enum Event<T> where
+    <T as system::Trait>::BlockNumber,
+    <T as system::Trait>::AccountId,
+    Balance = minting::BalanceOf<T> {
         CouncilTermEnded(BlockNumber),
         NewCouncilTermStarted(BlockNumber),
+        MotionProposed(MotionId, AccountId),
+        MotionApproved(MotionId, AccountId),
+        MotionCanceled(MotionId),
+        /// Motion expired without reaching the approval threshold.
+        MotionExpired(MotionId),
+        /// Motion was executed, the flag shows whether the call succeeded.
+        MotionExecuted(MotionId, bool),
+        /// Tokens were spent from the council mint: destination, amount and proposal id if any.
+        CouncilMintSpent(AccountId, Balance, Option<u32>),
+        /// Recurring grant was created: grant id, destination and amount per payout.
+        RecurringGrantCreated(RecurringGrantId, AccountId, Balance),
+        /// Recurring grant was canceled before its end block.
+        RecurringGrantCanceled(RecurringGrantId),
+        /// Recurring grant reached its end block.
+        RecurringGrantEnded(RecurringGrantId),
     }
 );
 
@@ -85,14 +348,61 @@ impl<T: Trait> CouncilElected<Seats<T::AccountId, BalanceOf<T>>, T::BlockNumber>
 
         <TermEndsAt<T>>::put(next_term_ends_at);
 
+        TermNumber::mutate(|term| *term += 1);
+
         for seat in seats.iter() {
             Self::add_reward_relationship(&seat.member, Self::council_mint());
         }
 
+        // motions and attendance of the previous council are dropped
+        Self::clear_motions();
+        Self::clear_term_attendance();
+
         Self::deposit_event(RawEvent::NewCouncilTermStarted(next_term_ends_at));
     }
 }
 
+impl<T: Trait> recurringrewards::PayoutStatusHandler<T> for Module<T> {
+    fn payout_succeeded(
+        id: T::RewardRelationshipId,
+        destination_account: &T::AccountId,
+        amount: minting::BalanceOf<T>,
+    ) {
+        // count the councilor reward payouts for the attendance rewards
+        if RewardRelationships::<T>::contains_key(destination_account)
+            && Self::reward_relationships(destination_account) == id
+        {
+            <TermRewardPayouts<T>>::mutate(destination_account, |count| *count += 1);
+
+            Self::record_spend(
+                destination_account.clone(),
+                amount,
+                SpendingCategory::CouncilReward,
+                None,
+            );
+        }
+
+        // record the recurring grant payouts in the council spends ledger
+        if let Some(grant_id) = Self::recurring_grant_id_by_reward_relationship(id) {
+            let grant = Self::recurring_grants(grant_id);
+
+            Self::record_spend(
+                destination_account.clone(),
+                amount,
+                SpendingCategory::RecurringGrant,
+                grant.proposal_id,
+            );
+        }
+    }
+
+    fn payout_failed(
+        _id: T::RewardRelationshipId,
+        _destination_account: &T::AccountId,
+        _amount: minting::BalanceOf<T>,
+    ) {
+    }
+}
+
 impl<T: Trait> Module<T> {
     pub fn is_term_ended() -> bool {
         <system::Module<T>>::block_number() >= Self::term_ends_at()
@@ -102,6 +412,213 @@ impl<T: Trait> Module<T> {
         Self::active_council().iter().any(|c| c.member == *sender)
     }
 
+    /// Share of the proposals open for voting in the current term voted on by the councilor. Full
+    /// attendance is assumed while the council has no proposals to vote on.
+    pub fn councilor_attendance(councilor: &T::AccountId) -> Perbill {
+        let proposal_count = Self::term_proposal_count();
+        if proposal_count == 0 {
+            return Perbill::one();
+        }
+
+        Perbill::from_rational_approximation(
+            Self::term_votes_by_councilor(councilor),
+            proposal_count,
+        )
+    }
+
+    /// Reward amount per payout for the councilor scaled by the attendance ratio, but not lower
+    /// than the attendance reward floor.
+    pub fn councilor_reward_amount(councilor: &T::AccountId) -> minting::BalanceOf<T> {
+        let reward_share =
+            Self::councilor_attendance(councilor).max(Self::attendance_reward_floor());
+
+        reward_share * Self::amount_per_payout()
+    }
+
+    /// Notes the proposal opened for the voting of the council in the current term.
+    pub fn note_proposal_opened() {
+        TermProposalCount::mutate(|count| *count += 1);
+    }
+
+    /// Notes the proposal vote for the councilors attendance tracking.
+    pub fn note_proposal_vote(voter: &T::AccountId) {
+        if Self::is_councilor(voter) {
+            <TermVotesByCouncilor<T>>::mutate(voter, |count| *count += 1);
+        }
+    }
+
+    // Pays the councilors the attendance scaled rewards for the term payouts above the floor share
+    // already paid by the reward relationships. The unpaid remainder stays in the council mint.
+    fn pay_attendance_rewards() {
+        for seat in Self::active_council().iter() {
+            if !RewardRelationships::<T>::contains_key(&seat.member) {
+                continue;
+            }
+
+            let relationship_id = Self::reward_relationships(&seat.member);
+            let paid_per_payout =
+                <recurringrewards::Module<T>>::reward_relationships(relationship_id)
+                    .amount_per_payout;
+
+            let payouts = minting::BalanceOf::<T>::from(Self::term_reward_payouts(&seat.member));
+            let attendance_reward = Self::councilor_reward_amount(&seat.member)
+                .saturating_sub(paid_per_payout)
+                .saturating_mul(payouts);
+
+            if attendance_reward.is_zero() {
+                continue;
+            }
+
+            if minting::Module::<T>::transfer_tokens(
+                Self::council_mint(),
+                attendance_reward,
+                &seat.member,
+            )
+            .is_ok()
+            {
+                Self::record_spend(
+                    seat.member.clone(),
+                    attendance_reward,
+                    SpendingCategory::CouncilReward,
+                    None,
+                );
+            } else {
+                debug::warn!("Failed to pay the attendance reward for council seat");
+            }
+        }
+    }
+
+    fn clear_term_attendance() {
+        let councilors: Vec<T::AccountId> = <TermVotesByCouncilor<T>>::iter()
+            .map(|(councilor, _)| councilor)
+            .collect();
+
+        for councilor in councilors {
+            <TermVotesByCouncilor<T>>::remove(councilor);
+        }
+
+        let paid_councilors: Vec<T::AccountId> = <TermRewardPayouts<T>>::iter()
+            .map(|(councilor, _)| councilor)
+            .collect();
+
+        for councilor in paid_councilors {
+            <TermRewardPayouts<T>>::remove(councilor);
+        }
+
+        TermProposalCount::kill();
+    }
+
+    /// Number of the councilor approvals required to execute a motion.
+    pub fn motion_approval_threshold() -> u32 {
+        let threshold = T::CouncilSize::get()
+            .saturating_mul(T::MotionApprovalThresholdPercentage::get())
+            .saturating_add(99)
+            / 100;
+
+        threshold.max(1)
+    }
+
+    // Executes the motion if it got enough approvals from the current councilors.
+    fn try_execute_motion(motion_id: MotionId) {
+        let motion = Self::motions(motion_id);
+
+        let approvals_count = motion
+            .approvals
+            .iter()
+            .filter(|councilor| Self::is_councilor(councilor))
+            .count() as u32;
+
+        if approvals_count < Self::motion_approval_threshold() {
+            return;
+        }
+
+        Self::remove_motion(motion_id);
+
+        let executed = match T::MotionCall::decode(&mut &motion.encoded_call[..]) {
+            Ok(call) => call
+                .dispatch_bypass_filter(RawOrigin::Motion(motion_id).into())
+                .is_ok(),
+            Err(_) => false,
+        };
+
+        Self::deposit_event(RawEvent::MotionExecuted(motion_id, executed));
+    }
+
+    // Records the council mint spend in the current term ledger and the term totals.
+    fn record_spend(
+        destination: T::AccountId,
+        amount: minting::BalanceOf<T>,
+        category: SpendingCategory,
+        proposal_id: Option<u32>,
+    ) {
+        let term = Self::term_number();
+        let spend_index = Self::term_spend_count(term);
+
+        let record = SpendRecord {
+            destination: destination.clone(),
+            amount,
+            block: <system::Module<T>>::block_number(),
+            proposal_id,
+            category,
+        };
+
+        <TermSpends<T>>::insert(term, spend_index, record);
+        TermSpendCount::insert(term, spend_index + 1);
+        <TermSpendingTotal<T>>::mutate(term, |total| *total = total.saturating_add(amount));
+        <TermSpendingByCategory<T>>::mutate(term, category, |total| {
+            *total = total.saturating_add(amount)
+        });
+
+        Self::deposit_event(RawEvent::CouncilMintSpent(destination, amount, proposal_id));
+    }
+
+    /// Council mint spends made in the term in the order they were recorded.
+    pub fn term_spend_ledger(
+        term: TermIndex,
+    ) -> Vec<SpendRecord<T::AccountId, minting::BalanceOf<T>, T::BlockNumber>> {
+        (0..Self::term_spend_count(term))
+            .map(|spend_index| Self::term_spends(term, spend_index))
+            .collect()
+    }
+
+    /// Amounts spent from the council mint in the term for each spending category.
+    pub fn term_spending_totals_by_category(
+        term: TermIndex,
+    ) -> Vec<(SpendingCategory, minting::BalanceOf<T>)> {
+        SpendingCategory::all()
+            .into_iter()
+            .map(|category| (category, Self::term_spending_by_category(term, category)))
+            .collect()
+    }
+
+    fn clear_motions() {
+        let motion_ids: Vec<MotionId> = <Motions<T>>::iter().map(|(id, _)| id).collect();
+
+        for motion_id in motion_ids {
+            Self::remove_motion(motion_id);
+        }
+    }
+
+    // Removes the motion with its expiry index entry.
+    fn remove_motion(motion_id: MotionId) {
+        let motion = <Motions<T>>::take(motion_id);
+
+        <MotionsByExpiry<T>>::remove(motion.expires_at, motion_id);
+    }
+
+    // Removes the motions expiring at the block.
+    fn expire_motions(now: T::BlockNumber) {
+        let expired_motion_ids: Vec<MotionId> = <MotionsByExpiry<T>>::iter_prefix(now)
+            .map(|(motion_id, _)| motion_id)
+            .collect();
+
+        for motion_id in expired_motion_ids {
+            Self::remove_motion(motion_id);
+
+            Self::deposit_event(RawEvent::MotionExpired(motion_id));
+        }
+    }
+
     fn add_reward_relationship(destination: &T::AccountId, reward_source: T::MintId) {
         let recipient = <recurringrewards::Module<T>>::add_recipient();
 
@@ -111,11 +628,14 @@ impl<T: Trait> Module<T> {
             + Self::first_payout_after_reward_created()
             + T::BlockNumber::one();
 
+        // the attendance scaled remainder of the reward is paid at the end of the term
+        let reward_floor_amount = Self::attendance_reward_floor() * Self::amount_per_payout();
+
         if let Ok(relationship_id) = <recurringrewards::Module<T>>::add_reward_relationship(
             reward_source,
             recipient,
             destination.clone(),
-            Self::amount_per_payout(),
+            reward_floor_amount,
             next_payout_at,
             Self::payout_interval(),
         ) {
@@ -134,12 +654,36 @@ impl<T: Trait> Module<T> {
         }
     }
 
+    // Removes the recurring grant with its index entries and stops its payouts.
+    fn remove_recurring_grant(grant_id: RecurringGrantId) {
+        let grant = <RecurringGrants<T>>::take(grant_id);
+
+        <RecurringGrantsByEndBlock<T>>::remove(grant.ends_at, grant_id);
+        <RecurringGrantIdByRewardRelationship<T>>::remove(grant.reward_relationship);
+        <recurringrewards::Module<T>>::remove_reward_relationship(grant.reward_relationship);
+    }
+
+    // Removes the recurring grants reaching their end block.
+    fn end_recurring_grants(now: T::BlockNumber) {
+        let ended_grant_ids: Vec<RecurringGrantId> =
+            <RecurringGrantsByEndBlock<T>>::iter_prefix(now)
+                .map(|(grant_id, _)| grant_id)
+                .collect();
+
+        for grant_id in ended_grant_ids {
+            Self::remove_recurring_grant(grant_id);
+
+            Self::deposit_event(RawEvent::RecurringGrantEnded(grant_id));
+        }
+    }
+
     fn on_term_ended(now: T::BlockNumber) {
         // Stop paying out rewards when the term ends.
         // Note: Is it not simpler to just do a single payout at end of term?
         // During the term the recurring reward module could unfairly pay some but not all council members
         // If there is insufficient mint capacity.. so doing it at this point offers more control
         // and a potentially more fair outcome in such a case.
+        Self::pay_attendance_rewards();
         Self::remove_reward_relationships();
 
         Self::deposit_event(RawEvent::CouncilTermEnded(now));
@@ -156,6 +700,9 @@ decl_module! {
             if now == Self::term_ends_at() {
                 Self::on_term_ended(now);
             }
+
+            Self::end_recurring_grants(now);
+            Self::expire_motions(now);
         }
 
         // Privileged methods
@@ -242,13 +789,156 @@ decl_module! {
             minting::Module::<T>::set_mint_capacity(Self::council_mint(), capacity).map_err(<&str>::from)?;
         }
 
-        /// Attempts to mint and transfer amount to destination account
+        /// Attempts to mint and transfer amount to destination account. The spend is recorded in
+        /// the current term ledger.
         #[weight = 10_000_000] // TODO: adjust weight
         fn spend_from_council_mint(origin, amount: minting::BalanceOf<T>, destination: T::AccountId) {
             ensure_root(origin)?;
 
             minting::Module::<T>::transfer_tokens(Self::council_mint(), amount, &destination)
                 .map_err(<&str>::from)?;
+
+            let proposal_id = T::ExecutingProposalId::get();
+            let category = if proposal_id.is_some() {
+                SpendingCategory::SpendingProposal
+            } else {
+                SpendingCategory::Root
+            };
+
+            Self::record_spend(destination, amount, category, proposal_id);
+        }
+
+        /// Creates a recurring grant paying the amount from the council mint to the destination
+        /// account each payout interval until the end block. The first payout is made after the
+        /// payout interval. The payouts are recorded in the term ledger.
+        #[weight = 10_000_000] // TODO: adjust weight
+        fn create_recurring_grant(
+            origin,
+            destination: T::AccountId,
+            amount_per_payout: minting::BalanceOf<T>,
+            payout_interval: T::BlockNumber,
+            ends_at: T::BlockNumber
+        ) {
+            ensure_root(origin)?;
+
+            ensure!(!amount_per_payout.is_zero(), "recurring grant amount cannot be zero");
+            ensure!(!payout_interval.is_zero(), "recurring grant payout interval cannot be zero");
+
+            let first_payout_at = <system::Module<T>>::block_number() + payout_interval;
+            ensure!(ends_at > first_payout_at, "recurring grant must end after the first payout");
+
+            let recipient = <recurringrewards::Module<T>>::add_recipient();
+
+            let reward_relationship = <recurringrewards::Module<T>>::add_reward_relationship(
+                Self::council_mint(),
+                recipient,
+                destination.clone(),
+                amount_per_payout,
+                first_payout_at,
+                Some(payout_interval),
+            ).map_err(|_| "failed to create a reward relationship for the recurring grant")?;
+
+            let grant_id = Self::recurring_grant_count() + 1;
+
+            let grant = RecurringGrant {
+                destination: destination.clone(),
+                amount_per_payout,
+                payout_interval,
+                ends_at,
+                reward_relationship,
+                proposal_id: T::ExecutingProposalId::get(),
+            };
+
+            <RecurringGrants<T>>::insert(grant_id, grant);
+            <RecurringGrantsByEndBlock<T>>::insert(ends_at, grant_id, ());
+            <RecurringGrantIdByRewardRelationship<T>>::insert(reward_relationship, grant_id);
+            RecurringGrantCount::put(grant_id);
+            Self::deposit_event(RawEvent::RecurringGrantCreated(grant_id, destination, amount_per_payout));
+        }
+
+        /// Cancels the recurring grant before its end block. Can be called by an approved council
+        /// motion.
+        #[weight = 10_000_000] // TODO: adjust weight
+        fn cancel_recurring_grant(origin, grant_id: RecurringGrantId) {
+            T::CouncilOrigin::ensure_origin(origin)?;
+
+            ensure!(<RecurringGrants<T>>::contains_key(grant_id), "recurring grant not found");
+
+            Self::remove_recurring_grant(grant_id);
+            Self::deposit_event(RawEvent::RecurringGrantCanceled(grant_id));
+        }
+
+        /// Sets the minimal share of the councilor reward paid regardless of the attendance. The
+        /// reward relationships pay out the new floor share from the next council term. Can be
+        /// called by an approved council motion.
+        #[weight = 10_000_000] // TODO: adjust weight
+        fn set_attendance_reward_floor(origin, floor: Perbill) {
+            T::CouncilOrigin::ensure_origin(origin)?;
+
+            AttendanceRewardFloor::put(floor);
+        }
+
+        /// Proposes a council motion. Only whitelisted calls are allowed. The call is executed with
+        /// the council motion origin once approved by the threshold of the council size. The motion
+        /// expires after the motion expiry period.
+        #[weight = 10_000_000] // TODO: adjust weight
+        fn propose_motion(origin, call: Box<T::MotionCall>) {
+            let sender = ensure_signed(origin)?;
+            ensure!(Self::is_councilor(&sender), "only councilors can propose motions");
+            ensure!(T::MotionCallFilter::filter(&call), "call is not allowed in motions");
+
+            let encoded_call = call.encode();
+            ensure!(
+                encoded_call.len() <= T::MaxMotionCallLength::get() as usize,
+                "motion call is too long"
+            );
+
+            let motion_id = Self::motion_count() + 1;
+            let created_at = <system::Module<T>>::block_number();
+            let expires_at = created_at + T::MotionExpiryPeriod::get();
+
+            let motion = Motion {
+                proposer: sender.clone(),
+                encoded_call,
+                approvals: vec![sender.clone()],
+                created_at,
+                expires_at,
+            };
+
+            <Motions<T>>::insert(motion_id, motion);
+            <MotionsByExpiry<T>>::insert(expires_at, motion_id, ());
+            MotionCount::put(motion_id);
+            Self::deposit_event(RawEvent::MotionProposed(motion_id, sender));
+
+            Self::try_execute_motion(motion_id);
+        }
+
+        /// Approves a council motion. Executes the motion once the approval threshold is reached.
+        #[weight = 10_000_000] // TODO: adjust weight
+        fn approve_motion(origin, motion_id: MotionId) {
+            let sender = ensure_signed(origin)?;
+            ensure!(Self::is_councilor(&sender), "only councilors can approve motions");
+            ensure!(<Motions<T>>::contains_key(motion_id), "motion not found");
+            ensure!(
+                !Self::motions(motion_id).approvals.contains(&sender),
+                "motion already approved"
+            );
+
+            <Motions<T>>::mutate(motion_id, |motion| motion.approvals.push(sender.clone()));
+            Self::deposit_event(RawEvent::MotionApproved(motion_id, sender));
+
+            Self::try_execute_motion(motion_id);
+        }
+
+        /// Cancels a council motion. Only the proposer can cancel the motion.
+        #[weight = 10_000_000] // TODO: adjust weight
+        fn cancel_motion(origin, motion_id: MotionId) {
+            let sender = ensure_signed(origin)?;
+            ensure!(<Motions<T>>::contains_key(motion_id), "motion not found");
+            ensure!(Self::motions(motion_id).proposer == sender, "only proposer can cancel motion");
+
+            Self::remove_motion(motion_id);
+            Self::deposit_event(RawEvent::MotionCanceled(motion_id));
         }
 
         /// Sets the council rewards which is only applied on new council being elected.
@@ -276,8 +966,10 @@ decl_module! {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::mock::Origin;
     use crate::mock::*;
     use crate::DispatchResult;
+    use frame_support::traits::OnFinalize;
     use frame_support::*;
 
     fn add_council_member_as_root(account: <Test as system::Trait>::AccountId) -> DispatchResult {
@@ -368,4 +1060,608 @@ mod tests {
             assert!(RewardRelationships::<Test>::contains_key(&7));
         });
     }
+
+    fn set_council_as_root(accounts: Vec<<Test as system::Trait>::AccountId>) {
+        assert_ok!(Council::set_council(
+            system::RawOrigin::Root.into(),
+            accounts
+        ));
+    }
+
+    fn create_recurring_grant_as_root() -> RecurringGrantId {
+        assert_ok!(Council::create_recurring_grant(
+            system::RawOrigin::Root.into(),
+            10,
+            100,
+            5,
+            20
+        ));
+
+        Council::recurring_grant_count()
+    }
+
+    fn propose_cancel_grant_motion(
+        proposer: <Test as system::Trait>::AccountId,
+        grant_id: RecurringGrantId,
+    ) -> MotionId {
+        assert_ok!(Council::propose_motion(
+            Origin::signed(proposer),
+            Box::new(Call::cancel_recurring_grant(grant_id))
+        ));
+
+        Council::motion_count()
+    }
+
+    #[test]
+    fn motion_approval_threshold_test() {
+        initial_test_ext().execute_with(|| {
+            // 66% of the council size 4
+            assert_eq!(Council::motion_approval_threshold(), 3);
+        });
+    }
+
+    #[test]
+    fn motion_executes_when_threshold_is_reached() {
+        initial_test_ext().execute_with(|| {
+            set_council_as_root(vec![1, 2, 3, 4]);
+            let grant_id = create_recurring_grant_as_root();
+
+            let motion_id = propose_cancel_grant_motion(1, grant_id);
+            assert_eq!(Council::motions(motion_id).approvals, vec![1]);
+
+            assert_ok!(Council::approve_motion(Origin::signed(2), motion_id));
+            assert!(<Motions<Test>>::contains_key(motion_id));
+            assert!(<RecurringGrants<Test>>::contains_key(grant_id));
+
+            assert_ok!(Council::approve_motion(Origin::signed(3), motion_id));
+            assert!(!<Motions<Test>>::contains_key(motion_id));
+            assert!(!<RecurringGrants<Test>>::contains_key(grant_id));
+        });
+    }
+
+    #[test]
+    fn motion_call_fails_without_council_origin_support() {
+        initial_test_ext().execute_with(|| {
+            set_council_as_root(vec![1, 2, 3, 4]);
+
+            assert_ok!(Council::propose_motion(
+                Origin::signed(1),
+                Box::new(Call::set_council_mint_capacity(1000))
+            ));
+            let motion_id = Council::motion_count();
+
+            assert_ok!(Council::approve_motion(Origin::signed(2), motion_id));
+            assert_ok!(Council::approve_motion(Origin::signed(3), motion_id));
+
+            // the motion is executed, but the call requires the root origin
+            assert!(!<Motions<Test>>::contains_key(motion_id));
+            assert_ne!(
+                minting::Module::<Test>::get_mint_capacity(Council::council_mint()).ok(),
+                Some(1000)
+            );
+        });
+    }
+
+    #[test]
+    fn motion_approvals_of_removed_councilors_are_not_counted() {
+        initial_test_ext().execute_with(|| {
+            set_council_as_root(vec![1, 2, 3, 4]);
+            let grant_id = create_recurring_grant_as_root();
+
+            let motion_id = propose_cancel_grant_motion(1, grant_id);
+            assert_ok!(Council::approve_motion(Origin::signed(2), motion_id));
+
+            assert_ok!(Council::remove_council_member(
+                system::RawOrigin::Root.into(),
+                1
+            ));
+
+            assert_ok!(Council::approve_motion(Origin::signed(3), motion_id));
+            assert!(<Motions<Test>>::contains_key(motion_id));
+
+            assert_ok!(Council::approve_motion(Origin::signed(4), motion_id));
+            assert!(!<RecurringGrants<Test>>::contains_key(grant_id));
+        });
+    }
+
+    #[test]
+    fn propose_motion_fails_for_non_councilor() {
+        initial_test_ext().execute_with(|| {
+            set_council_as_root(vec![1, 2, 3, 4]);
+
+            assert_err!(
+                Council::propose_motion(
+                    Origin::signed(5),
+                    Box::new(Call::cancel_recurring_grant(1))
+                ),
+                "only councilors can propose motions"
+            );
+        });
+    }
+
+    #[test]
+    fn propose_motion_fails_for_not_whitelisted_call() {
+        initial_test_ext().execute_with(|| {
+            set_council_as_root(vec![1, 2, 3, 4]);
+
+            assert_err!(
+                Council::propose_motion(Origin::signed(1), Box::new(Call::set_council(vec![1]))),
+                "call is not allowed in motions"
+            );
+        });
+    }
+
+    #[test]
+    fn propose_motion_fails_for_too_long_call() {
+        initial_test_ext().execute_with(|| {
+            set_council_as_root(vec![1, 2, 3, 4]);
+
+            assert_err!(
+                Council::propose_motion(
+                    Origin::signed(1),
+                    Box::new(Call::spend_from_council_mint(100, 5))
+                ),
+                "motion call is too long"
+            );
+        });
+    }
+
+    #[test]
+    fn approve_motion_fails_with_invalid_approvals() {
+        initial_test_ext().execute_with(|| {
+            set_council_as_root(vec![1, 2, 3, 4]);
+
+            let motion_id = propose_cancel_grant_motion(1, 1);
+
+            assert_err!(
+                Council::approve_motion(Origin::signed(1), motion_id),
+                "motion already approved"
+            );
+            assert_err!(
+                Council::approve_motion(Origin::signed(5), motion_id),
+                "only councilors can approve motions"
+            );
+            assert_err!(
+                Council::approve_motion(Origin::signed(2), motion_id + 1),
+                "motion not found"
+            );
+        });
+    }
+
+    #[test]
+    fn cancel_motion_test() {
+        initial_test_ext().execute_with(|| {
+            set_council_as_root(vec![1, 2, 3, 4]);
+
+            let motion_id = propose_cancel_grant_motion(1, 1);
+
+            assert_err!(
+                Council::cancel_motion(Origin::signed(2), motion_id),
+                "only proposer can cancel motion"
+            );
+
+            assert_ok!(Council::cancel_motion(Origin::signed(1), motion_id));
+            assert!(!<Motions<Test>>::contains_key(motion_id));
+        });
+    }
+
+    #[test]
+    fn motion_expires_after_expiry_period() {
+        initial_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            set_council_as_root(vec![1, 2, 3, 4]);
+
+            let motion_id = propose_cancel_grant_motion(1, 1);
+            let expires_at = Council::motions(motion_id).expires_at;
+            assert_eq!(expires_at, 1 + MotionExpiryPeriod::get());
+
+            Council::on_finalize(expires_at - 1);
+            assert!(<Motions<Test>>::contains_key(motion_id));
+
+            Council::on_finalize(expires_at);
+            assert!(!<Motions<Test>>::contains_key(motion_id));
+            assert!(!<MotionsByExpiry<Test>>::contains_key(
+                expires_at, motion_id
+            ));
+
+            assert_err!(
+                Council::approve_motion(Origin::signed(2), motion_id),
+                "motion not found"
+            );
+        });
+    }
+
+    #[test]
+    fn motions_are_cleared_when_council_is_elected() {
+        initial_test_ext().execute_with(|| {
+            set_council_as_root(vec![1, 2, 3, 4]);
+
+            let motion_id = propose_cancel_grant_motion(1, 1);
+            let expires_at = Council::motions(motion_id).expires_at;
+
+            Council::council_elected(
+                vec![Seat {
+                    member: 5,
+                    stake: 0,
+                    backers: vec![],
+                }],
+                50,
+            );
+
+            assert!(!<Motions<Test>>::contains_key(motion_id));
+            assert!(!<MotionsByExpiry<Test>>::contains_key(
+                expires_at, motion_id
+            ));
+        });
+    }
+
+    fn councilor_reward_amount_in_relationship(
+        councilor: <Test as system::Trait>::AccountId,
+    ) -> u64 {
+        let relationship_id = Council::reward_relationships(councilor);
+
+        recurringrewards::Module::<Test>::reward_relationships(relationship_id).amount_per_payout
+    }
+
+    fn elect_council_with_rewards(members: Vec<<Test as system::Trait>::AccountId>) {
+        assert_ok!(Council::set_council_mint_capacity(
+            system::RawOrigin::Root.into(),
+            1000
+        ));
+        assert_ok!(Council::set_council_rewards(
+            system::RawOrigin::Root.into(),
+            100,
+            Some(10),
+            5
+        ));
+
+        let seats = members
+            .into_iter()
+            .map(|member| Seat {
+                member,
+                stake: 0,
+                backers: vec![],
+            })
+            .collect();
+
+        Council::council_elected(seats, 50);
+    }
+
+    #[test]
+    fn councilor_attendance_is_tracked() {
+        initial_test_ext().execute_with(|| {
+            elect_council_with_rewards(vec![5, 6]);
+
+            // full attendance is assumed with no proposals to vote on
+            assert_eq!(Council::councilor_attendance(&5), Perbill::one());
+
+            Council::note_proposal_opened();
+            Council::note_proposal_opened();
+            Council::note_proposal_vote(&5);
+            Council::note_proposal_vote(&6);
+            Council::note_proposal_vote(&5);
+            // votes of non councilors are not tracked
+            Council::note_proposal_vote(&7);
+
+            assert_eq!(Council::term_proposal_count(), 2);
+            assert_eq!(Council::term_votes_by_councilor(&5), 2);
+            assert_eq!(Council::term_votes_by_councilor(&6), 1);
+            assert_eq!(Council::term_votes_by_councilor(&7), 0);
+            assert_eq!(Council::councilor_attendance(&6), Perbill::from_percent(50));
+
+            // proposals nobody voted on count against the attendance
+            Council::note_proposal_opened();
+            Council::note_proposal_opened();
+            assert_eq!(Council::councilor_attendance(&5), Perbill::from_percent(50));
+        });
+    }
+
+    #[test]
+    fn councilor_rewards_are_scaled_by_attendance_at_term_end() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(Council::set_attendance_reward_floor(
+                system::RawOrigin::Root.into(),
+                Perbill::from_percent(20)
+            ));
+            elect_council_with_rewards(vec![5, 6, 7]);
+
+            // the floor share is paid out during the term
+            assert_eq!(councilor_reward_amount_in_relationship(5), 20);
+
+            Council::note_proposal_opened();
+            Council::note_proposal_opened();
+            Council::note_proposal_vote(&5);
+            Council::note_proposal_vote(&5);
+            Council::note_proposal_vote(&6);
+
+            // payouts at the blocks 6 and 16
+            recurringrewards::Module::<Test>::on_finalize(6);
+            recurringrewards::Module::<Test>::on_finalize(16);
+            assert_eq!(Council::term_reward_payouts(&5), 2);
+            assert_eq!(Balances::free_balance(&5), 40);
+
+            Council::on_finalize(Council::term_ends_at());
+
+            // the attendance share of the term payouts is paid at the term end
+            assert_eq!(Balances::free_balance(&5), 200);
+            assert_eq!(Balances::free_balance(&6), 100);
+            // no attendance gets the floor share only
+            assert_eq!(Balances::free_balance(&7), 40);
+        });
+    }
+
+    #[test]
+    fn councilor_attendance_is_reset_when_council_is_elected() {
+        initial_test_ext().execute_with(|| {
+            elect_council_with_rewards(vec![5, 6]);
+
+            Council::note_proposal_opened();
+            Council::note_proposal_vote(&5);
+            recurringrewards::Module::<Test>::on_finalize(6);
+
+            elect_council_with_rewards(vec![5, 6]);
+
+            assert_eq!(Council::term_proposal_count(), 0);
+            assert_eq!(Council::term_votes_by_councilor(&5), 0);
+            assert_eq!(Council::term_reward_payouts(&5), 0);
+        });
+    }
+
+    #[test]
+    fn council_mint_spends_are_recorded_per_term_and_category() {
+        initial_test_ext().execute_with(|| {
+            set_council_as_root(vec![1, 2, 3, 4]);
+            assert_ok!(Council::set_council_mint_capacity(
+                system::RawOrigin::Root.into(),
+                1000
+            ));
+
+            assert_ok!(Council::spend_from_council_mint(
+                system::RawOrigin::Root.into(),
+                100,
+                10
+            ));
+
+            set_executing_proposal_id(Some(7));
+            assert_ok!(Council::spend_from_council_mint(
+                system::RawOrigin::Root.into(),
+                200,
+                11
+            ));
+            set_executing_proposal_id(None);
+
+            let spends = Council::term_spend_ledger(0);
+            assert_eq!(spends.len(), 2);
+            assert_eq!(
+                spends[1],
+                SpendRecord {
+                    destination: 11,
+                    amount: 200,
+                    block: 0,
+                    proposal_id: Some(7),
+                    category: SpendingCategory::SpendingProposal,
+                }
+            );
+            assert_eq!(spends[0].category, SpendingCategory::Root);
+            assert_eq!(spends[0].proposal_id, None);
+
+            assert_eq!(Council::term_spending_total(0), 300);
+            assert_eq!(
+                Council::term_spending_totals_by_category(0),
+                vec![
+                    (SpendingCategory::SpendingProposal, 200),
+                    (SpendingCategory::Root, 100),
+                    (SpendingCategory::CouncilReward, 0),
+                    (SpendingCategory::RecurringGrant, 0),
+                ]
+            );
+            assert_eq!(Council::term_spend_count(0), 2);
+            assert_eq!(Council::term_spends(0, 1), spends[1]);
+        });
+    }
+
+    #[test]
+    fn council_mint_spends_are_recorded_in_new_term_after_election() {
+        initial_test_ext().execute_with(|| {
+            elect_council_with_rewards(vec![5, 6]);
+            assert_eq!(Council::term_number(), 1);
+
+            assert_ok!(Council::spend_from_council_mint(
+                system::RawOrigin::Root.into(),
+                100,
+                10
+            ));
+
+            assert!(Council::term_spend_ledger(0).is_empty());
+            assert_eq!(Council::term_spend_ledger(1).len(), 1);
+            assert_eq!(Council::term_spending_total(1), 100);
+        });
+    }
+
+    #[test]
+    fn council_reward_payouts_are_recorded() {
+        initial_test_ext().execute_with(|| {
+            elect_council_with_rewards(vec![5, 6]);
+
+            Council::note_proposal_opened();
+            Council::note_proposal_vote(&5);
+
+            recurringrewards::Module::<Test>::on_finalize(6);
+            Council::on_finalize(Council::term_ends_at());
+
+            let spends = Council::term_spend_ledger(1);
+            // two payouts of the floor share and the attendance reward of the councilor 5
+            assert_eq!(spends.len(), 3);
+            assert!(spends
+                .iter()
+                .all(|spend| spend.category == SpendingCategory::CouncilReward));
+            assert_eq!(spends[2].destination, 5);
+            assert_eq!(spends[2].amount, 50);
+            assert_eq!(
+                Council::term_spending_by_category(1, SpendingCategory::CouncilReward),
+                150
+            );
+        });
+    }
+
+    #[test]
+    fn failed_council_mint_spend_is_not_recorded() {
+        initial_test_ext().execute_with(|| {
+            assert!(
+                Council::spend_from_council_mint(system::RawOrigin::Root.into(), 100, 10).is_err()
+            );
+
+            assert!(Council::term_spend_ledger(0).is_empty());
+            assert_eq!(Council::term_spending_total(0), 0);
+        });
+    }
+
+    #[test]
+    fn recurring_grant_is_created_and_ended() {
+        initial_test_ext().execute_with(|| {
+            set_executing_proposal_id(Some(7));
+            assert_ok!(Council::create_recurring_grant(
+                system::RawOrigin::Root.into(),
+                10,
+                100,
+                5,
+                20
+            ));
+            set_executing_proposal_id(None);
+
+            let grant_id = Council::recurring_grant_count();
+            assert_eq!(grant_id, 1);
+
+            let grant = Council::recurring_grants(grant_id);
+            assert_eq!(grant.destination, 10);
+            assert_eq!(grant.amount_per_payout, 100);
+            assert_eq!(grant.proposal_id, Some(7));
+
+            let relationship =
+                recurringrewards::Module::<Test>::reward_relationships(grant.reward_relationship);
+            assert_eq!(relationship.amount_per_payout, 100);
+            assert_eq!(relationship.next_payment_at_block, Some(5));
+            assert_eq!(relationship.payout_interval, Some(5));
+
+            assert!(RecurringGrantsByEndBlock::<Test>::contains_key(
+                20, grant_id
+            ));
+            assert_eq!(
+                Council::recurring_grant_id_by_reward_relationship(grant.reward_relationship),
+                Some(grant_id)
+            );
+
+            Council::on_finalize(19);
+            assert!(RecurringGrants::<Test>::contains_key(grant_id));
+
+            Council::on_finalize(20);
+            assert!(!RecurringGrants::<Test>::contains_key(grant_id));
+            assert!(!RecurringGrantsByEndBlock::<Test>::contains_key(
+                20, grant_id
+            ));
+            assert_eq!(
+                Council::recurring_grant_id_by_reward_relationship(grant.reward_relationship),
+                None
+            );
+            assert!(
+                !recurringrewards::RewardRelationships::<Test>::contains_key(
+                    grant.reward_relationship
+                )
+            );
+        });
+    }
+
+    #[test]
+    fn recurring_grant_payouts_are_recorded() {
+        initial_test_ext().execute_with(|| {
+            set_executing_proposal_id(Some(7));
+            assert_ok!(Council::create_recurring_grant(
+                system::RawOrigin::Root.into(),
+                10,
+                100,
+                5,
+                20
+            ));
+            set_executing_proposal_id(None);
+
+            let grant = Council::recurring_grants(Council::recurring_grant_count());
+
+            <Council as recurringrewards::PayoutStatusHandler<Test>>::payout_succeeded(
+                grant.reward_relationship,
+                &10,
+                100,
+            );
+
+            assert_eq!(
+                Council::term_spend_ledger(0),
+                vec![SpendRecord {
+                    destination: 10,
+                    amount: 100,
+                    block: 0,
+                    proposal_id: Some(7),
+                    category: SpendingCategory::RecurringGrant,
+                }]
+            );
+            assert_eq!(
+                Council::term_spending_by_category(0, SpendingCategory::RecurringGrant),
+                100
+            );
+        });
+    }
+
+    #[test]
+    fn recurring_grant_is_canceled() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(Council::create_recurring_grant(
+                system::RawOrigin::Root.into(),
+                10,
+                100,
+                5,
+                20
+            ));
+
+            let grant_id = Council::recurring_grant_count();
+            let grant = Council::recurring_grants(grant_id);
+
+            assert_ok!(Council::cancel_recurring_grant(
+                system::RawOrigin::Root.into(),
+                grant_id
+            ));
+
+            assert!(!RecurringGrants::<Test>::contains_key(grant_id));
+            assert!(
+                !recurringrewards::RewardRelationships::<Test>::contains_key(
+                    grant.reward_relationship
+                )
+            );
+
+            assert!(
+                Council::cancel_recurring_grant(system::RawOrigin::Root.into(), grant_id).is_err()
+            );
+        });
+    }
+
+    #[test]
+    fn create_recurring_grant_fails_with_invalid_parameters() {
+        initial_test_ext().execute_with(|| {
+            assert!(
+                Council::create_recurring_grant(system::RawOrigin::Root.into(), 10, 0, 5, 20)
+                    .is_err()
+            );
+            assert!(Council::create_recurring_grant(
+                system::RawOrigin::Root.into(),
+                10,
+                100,
+                0,
+                20
+            )
+            .is_err());
+            assert!(
+                Council::create_recurring_grant(system::RawOrigin::Root.into(), 10, 100, 5, 5)
+                    .is_err()
+            );
+
+            assert_eq!(Council::recurring_grant_count(), 0);
+        });
+    }
 }
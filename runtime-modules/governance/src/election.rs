@@ -32,18 +32,22 @@
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 
+use chacha20poly1305::aead::generic_array::GenericArray;
+use chacha20poly1305::aead::{Aead, NewAead};
+use chacha20poly1305::ChaCha20Poly1305;
 use codec::{Decode, Encode};
 use frame_support::traits::{Currency, ReservableCurrency};
-use frame_support::{decl_event, decl_module, decl_storage, ensure};
+use frame_support::{decl_event, decl_module, decl_storage, ensure, Parameter};
 use sp_arithmetic::traits::Zero;
-use sp_runtime::traits::Hash;
+use sp_runtime::offchain::storage::StorageValueRef;
+use sp_runtime::traits::{Hash, Member};
 use sp_std::collections::btree_map::BTreeMap;
 use sp_std::ops::Add;
 use sp_std::vec;
 use sp_std::vec::Vec;
 use system::{ensure_root, ensure_signed};
 
-use super::sealed_vote::SealedVote;
+use super::sealed_vote::{commitment_payload, SealedVote};
 use super::stake::Stake;
 
 use super::council;
@@ -56,11 +60,83 @@ pub trait Trait: system::Trait + council::Trait + GovernanceCurrency + membershi
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
 
     type CouncilElected: CouncilElected<Seats<Self::AccountId, BalanceOf<Self>>, Self::BlockNumber>;
+
+    /// Submits the scheduled reveals decrypted by the offchain worker.
+    type RevealSubmitter: RevealSubmitter<Self::AccountId, Self::Hash>;
+
+    /// Id of the manifesto content uploaded to the data directory.
+    type ManifestoContentId: Parameter + Member + Copy + Default;
+
+    /// Verifies the manifesto content exists in the data directory.
+    type ManifestoContentValidator: ManifestoContentValidator<Self::ManifestoContentId>;
 }
 
 pub static MSG_CANNOT_CHANGE_PARAMS_DURING_ELECTION: &str = "CannotChangeParamsDuringElection";
 
-#[derive(Clone, Copy, Encode, Decode)]
+/// Max length of the encrypted reveal data.
+pub const MAX_ENCRYPTED_REVEAL_LENGTH: usize = 256;
+
+/// Offchain local storage key prefix of the reveal keys. The key for the scheduled reveal is
+/// stored by the voter's node under the prefix followed by the encoded vote commitment.
+pub const REVEAL_KEY_STORAGE_PREFIX: &[u8] = b"joystream::election::reveal_key::";
+
+// Offchain local storage key prefix of the reveals already submitted by the offchain worker.
+const SUBMITTED_REVEAL_STORAGE_PREFIX: &[u8] = b"joystream::election::submitted_reveal::";
+
+/// Key used to encrypt the scheduled reveal.
+pub type RevealKey = [u8; 32];
+
+// Length of the ChaCha20-Poly1305 nonce, taken from the vote commitment.
+const REVEAL_NONCE_LENGTH: usize = 12;
+
+/// Max length of the manifesto text.
+pub const MAX_MANIFESTO_LENGTH: usize = 4096;
+
+/// Applicant manifesto: the platform of the council candidate.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Encode, Decode, PartialEq, Eq, Debug)]
+pub enum Manifesto<ContentId> {
+    /// Manifesto text stored on chain.
+    Text(Vec<u8>),
+
+    /// Manifesto uploaded to the data directory.
+    Content(ContentId),
+}
+
+pub type ManifestoOf<T> = Manifesto<<T as Trait>::ManifestoContentId>;
+
+/// Applicant of the finished election with the final stake and the manifesto.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct ApplicantRecord<AccountId, Balance, ContentId> {
+    pub applicant: AccountId,
+    pub stake: Balance,
+    pub manifesto: Option<Manifesto<ContentId>>,
+}
+
+/// Archived results of the election round.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct ElectionResult<AccountId, Balance, BlockNumber, ContentId> {
+    /// When the council was elected.
+    pub elected_at: BlockNumber,
+
+    /// Elected council.
+    pub council: Seats<AccountId, Balance>,
+
+    /// Applicants which reached the voting stage.
+    pub applicants: Vec<ApplicantRecord<AccountId, Balance, ContentId>>,
+}
+
+pub type ElectionResultOf<T> = ElectionResult<
+    <T as system::Trait>::AccountId,
+    BalanceOf<T>,
+    <T as system::Trait>::BlockNumber,
+    <T as Trait>::ManifestoContentId,
+>;
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq)]
 pub enum ElectionStage<BlockNumber> {
     Announcing(BlockNumber),
     Voting(BlockNumber),
@@ -123,6 +199,35 @@ impl<
     }
 }
 
+// Hook for submitting the reveals decrypted by the offchain worker
+pub trait RevealSubmitter<AccountId, Hash> {
+    /// Submits the reveal transaction signed by the voter. Returns true if it was submitted.
+    fn submit_reveal(voter: AccountId, commitment: Hash, vote: AccountId, salt: Vec<u8>) -> bool;
+}
+
+impl<AccountId, Hash> RevealSubmitter<AccountId, Hash> for () {
+    fn submit_reveal(
+        _voter: AccountId,
+        _commitment: Hash,
+        _vote: AccountId,
+        _salt: Vec<u8>,
+    ) -> bool {
+        false
+    }
+}
+
+// Hook for verifying the manifesto content in the data directory
+pub trait ManifestoContentValidator<ContentId> {
+    /// Returns true if the content exists in the data directory.
+    fn has_content(content_id: &ContentId) -> bool;
+}
+
+impl<ContentId> ManifestoContentValidator<ContentId> for () {
+    fn has_content(_content_id: &ContentId) -> bool {
+        true
+    }
+}
+
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
 #[derive(Clone, Copy, Encode, Decode, Default)]
 pub struct TransferableStake<Balance> {
@@ -151,6 +256,12 @@ decl_storage! {
         Applicants get(fn applicants): Vec<T::AccountId>;
         ApplicantStakes get(fn applicant_stakes): map hasher(blake2_128_concat)
             T::AccountId => ElectionStake<T>;
+        ApplicantManifestos get(fn applicant_manifestos): map hasher(blake2_128_concat)
+            T::AccountId => Option<ManifestoOf<T>>;
+
+        // Results of the election rounds which elected a council
+        ElectionResults get(fn election_results): map hasher(blake2_128_concat)
+            u32 => Option<ElectionResultOf<T>>;
 
         Commitments get(fn commitments): Vec<T::Hash>;
 
@@ -158,6 +269,10 @@ decl_storage! {
         Votes get(fn votes): map hasher(blake2_128_concat)
             T::Hash => SealedVote<T::AccountId, ElectionStake<T>, T::Hash, T::AccountId>;
 
+        // Encrypted reveals scheduled for the automatic submission by the offchain worker
+        ScheduledReveals get(fn scheduled_reveals): map hasher(blake2_128_concat)
+            T::Hash => Vec<u8>;
+
         // Current Election Parameters.
         // Should we replace all the individual values with a single ElectionParameters type?
         // Having them individually makes it more flexible to add and remove new parameters in future
@@ -170,6 +285,10 @@ decl_storage! {
         MinCouncilStake get(fn min_council_stake): BalanceOf<T>;
         NewTermDuration get(fn new_term_duration): T::BlockNumber;
         MinVotingStake get(fn min_voting_stake): BalanceOf<T>;
+
+        // Election parameters to be set at the start of the next election
+        PendingElectionParameters get(fn pending_election_parameters):
+            Option<ElectionParameters<BalanceOf<T>, T::BlockNumber>>;
     }
     add_extra_genesis {
         config(election_parameters): ElectionParameters<BalanceOf<T>, T::BlockNumber>;
@@ -198,6 +317,8 @@ decl_event!(
         Applied(AccountId),
         Voted(AccountId, Hash),
         Revealed(AccountId, Hash, AccountId),
+        RevealScheduled(AccountId, Hash),
+        ManifestoUpdated(AccountId),
     }
 );
 
@@ -241,6 +362,144 @@ impl<T: Trait> Module<T> {
         }
     }
 
+    /// Returns the total stake of every applicant in the current election.
+    pub fn applicants_with_stakes() -> Vec<(T::AccountId, BalanceOf<T>)> {
+        Self::applicants()
+            .into_iter()
+            .map(|applicant| {
+                let stake = Self::applicant_stakes(&applicant).total();
+                (applicant, stake)
+            })
+            .collect()
+    }
+
+    /// Returns the manifestos of the applicants in the current election.
+    pub fn applicants_with_manifestos() -> Vec<(T::AccountId, ManifestoOf<T>)> {
+        Self::applicants()
+            .into_iter()
+            .filter_map(|applicant| {
+                Self::applicant_manifestos(&applicant).map(|manifesto| (applicant, manifesto))
+            })
+            .collect()
+    }
+
+    fn ensure_valid_manifesto(manifesto: &ManifestoOf<T>) -> DispatchResult {
+        match manifesto {
+            Manifesto::Text(text) => {
+                ensure!(text.len() <= MAX_MANIFESTO_LENGTH, "manifesto too long");
+            }
+            Manifesto::Content(content_id) => {
+                ensure!(
+                    T::ManifestoContentValidator::has_content(content_id),
+                    "manifesto content not found"
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Computes the salted commitment hash of a vote for an applicant, as verified
+    /// when the vote is revealed.
+    pub fn vote_commitment(vote_for: &T::AccountId, salt: &[u8]) -> T::Hash {
+        <T as system::Trait>::Hashing::hash(&commitment_payload(vote_for, salt))
+    }
+
+    /// Encrypts the vote and salt for the scheduled reveal with ChaCha20-Poly1305. The nonce is
+    /// taken from the vote commitment, so the key must be unique per vote. The key is kept in the
+    /// voter's node offchain local storage until the revealing stage.
+    pub fn encrypt_reveal(
+        key: &RevealKey,
+        vote_for: &T::AccountId,
+        salt: &[u8],
+    ) -> Option<Vec<u8>> {
+        let commitment = Self::vote_commitment(vote_for, salt);
+        let nonce = commitment.as_ref().get(..REVEAL_NONCE_LENGTH)?;
+
+        ChaCha20Poly1305::new(GenericArray::from_slice(key))
+            .encrypt(
+                GenericArray::from_slice(nonce),
+                (vote_for, salt).encode().as_slice(),
+            )
+            .ok()
+    }
+
+    /// Decrypts and authenticates the scheduled reveal data of the vote commitment. Returns None
+    /// if the data cannot be decrypted or decoded.
+    pub fn decrypt_reveal(
+        key: &RevealKey,
+        commitment: &T::Hash,
+        encrypted: &[u8],
+    ) -> Option<(T::AccountId, Vec<u8>)> {
+        let nonce = commitment.as_ref().get(..REVEAL_NONCE_LENGTH)?;
+
+        let payload = ChaCha20Poly1305::new(GenericArray::from_slice(key))
+            .decrypt(GenericArray::from_slice(nonce), encrypted)
+            .ok()?;
+
+        Decode::decode(&mut &payload[..]).ok()
+    }
+
+    // Builds the offchain local storage key for the vote commitment.
+    fn offchain_storage_key(prefix: &[u8], commitment: &T::Hash) -> Vec<u8> {
+        let mut key = prefix.to_vec();
+        key.extend_from_slice(&commitment.encode());
+        key
+    }
+
+    /// Offchain worker part of the automatic reveal: during the revealing stage decrypts the
+    /// scheduled reveals with the keys found in the local storage and submits them.
+    fn submit_scheduled_reveals() {
+        let is_revealing = match Self::stage() {
+            Some(ElectionStage::Revealing(_)) => true,
+            _ => false,
+        };
+        if !is_revealing {
+            return;
+        }
+
+        for commitment in Self::commitments() {
+            if !<ScheduledReveals<T>>::contains_key(&commitment) {
+                continue;
+            }
+
+            let sealed_vote = Self::votes(&commitment);
+            if !sealed_vote.is_not_revealed() {
+                continue;
+            }
+
+            let submitted_key =
+                Self::offchain_storage_key(SUBMITTED_REVEAL_STORAGE_PREFIX, &commitment);
+            let submitted = StorageValueRef::persistent(&submitted_key);
+            if let Some(Some(round)) = submitted.get::<u32>() {
+                if round == Self::round() {
+                    continue;
+                }
+            }
+
+            let reveal_key_key = Self::offchain_storage_key(REVEAL_KEY_STORAGE_PREFIX, &commitment);
+            let reveal_key = match StorageValueRef::persistent(&reveal_key_key).get::<RevealKey>() {
+                Some(Some(reveal_key)) => reveal_key,
+                _ => continue,
+            };
+
+            let encrypted = Self::scheduled_reveals(&commitment);
+            let (vote_for, salt) = match Self::decrypt_reveal(&reveal_key, &commitment, &encrypted)
+            {
+                Some(reveal) => reveal,
+                None => continue,
+            };
+
+            if Self::vote_commitment(&vote_for, &salt) != commitment {
+                continue;
+            }
+
+            if T::RevealSubmitter::submit_reveal(sealed_vote.voter, commitment, vote_for, salt) {
+                submitted.set(&Self::round());
+            }
+        }
+    }
+
     // PRIVATE MUTABLES
 
     /// Starts an election. Will fail if an election is already running
@@ -254,6 +513,11 @@ impl<T: Trait> Module<T> {
         ensure!(Self::applicants().is_empty(), "applicants must be empty");
         ensure!(Self::commitments().is_empty(), "commitments must be empty");
 
+        // Apply the election parameters deferred until the next election
+        if let Some(params) = <PendingElectionParameters<T>>::take() {
+            Self::set_verified_election_parameters(params);
+        }
+
         // Take snapshot of seat and backing stakes of an existing council
         // Its important to note that the election system takes ownership of these stakes, and is responsible
         // to return any unused stake to original owners at the end of the election.
@@ -385,6 +649,8 @@ impl<T: Trait> Module<T> {
         // unless we want to add more filtering criteria to what is considered a successful election
         // other than just the minimum stake for candidacy, we have a new council!
 
+        Self::archive_election_result(&new_council);
+
         Self::teardown_election(
             &votes,
             &new_council,
@@ -397,6 +663,28 @@ impl<T: Trait> Module<T> {
         Self::deposit_event(RawEvent::CouncilElected(<system::Module<T>>::block_number()));
     }
 
+    // Stores the elected council with the applicants stakes and manifestos for the round.
+    fn archive_election_result(
+        new_council: &BTreeMap<T::AccountId, Seat<T::AccountId, BalanceOf<T>>>,
+    ) {
+        let applicants = Self::applicants()
+            .into_iter()
+            .map(|applicant| ApplicantRecord {
+                stake: Self::applicant_stakes(&applicant).total(),
+                manifesto: Self::applicant_manifestos(&applicant),
+                applicant,
+            })
+            .collect();
+
+        let result = ElectionResult {
+            elected_at: <system::Module<T>>::block_number(),
+            council: new_council.values().cloned().collect(),
+            applicants,
+        };
+
+        <ElectionResults<T>>::insert(Self::round(), result);
+    }
+
     fn teardown_election(
         votes: &[SealedVote<T::AccountId, Stake<BalanceOf<T>>, T::Hash, T::AccountId>],
         new_council: &BTreeMap<T::AccountId, Seat<T::AccountId, BalanceOf<T>>>,
@@ -440,7 +728,8 @@ impl<T: Trait> Module<T> {
 
     fn clear_applicants() {
         for applicant in Self::applicants() {
-            <ApplicantStakes<T>>::remove(applicant);
+            <ApplicantStakes<T>>::remove(&applicant);
+            <ApplicantManifestos<T>>::remove(&applicant);
         }
         <Applicants<T>>::kill();
     }
@@ -470,6 +759,7 @@ impl<T: Trait> Module<T> {
         for applicant in drop {
             Self::refund_applicant(applicant);
             <ApplicantStakes<T>>::remove(applicant);
+            <ApplicantManifestos<T>>::remove(applicant);
         }
 
         <Applicants<T>>::put(not_dropped);
@@ -520,6 +810,7 @@ impl<T: Trait> Module<T> {
     fn clear_votes() {
         for commitment in Self::commitments() {
             <Votes<T>>::remove(commitment);
+            <ScheduledReveals<T>>::remove(commitment);
         }
         <Commitments<T>>::kill();
     }
@@ -782,10 +1073,8 @@ impl<T: Trait> Module<T> {
             "vote for non-applicant not allowed"
         );
 
-        let mut salt = salt;
-
         // Tries to unseal, if salt is invalid will return error
-        sealed_vote.unseal(vote_for, &mut salt, <T as system::Trait>::Hashing::hash)?;
+        sealed_vote.unseal(vote_for, &salt, <T as system::Trait>::Hashing::hash)?;
 
         // Update the revealed vote
         <Votes<T>>::insert(commitment, sealed_vote);
@@ -814,6 +1103,10 @@ decl_module! {
             Self::check_if_stage_is_ending(now);
         }
 
+        fn offchain_worker(_now: T::BlockNumber) {
+            Self::submit_scheduled_reveals();
+        }
+
         // Member can apply during announcing stage only. On first call a minimum stake will need to be provided.
         // Member can make subsequent calls during announcing stage to increase their stake.
         #[weight = 10_000_000] // TODO: adjust weight
@@ -840,6 +1133,33 @@ decl_module! {
             Self::deposit_event(RawEvent::Applied(sender));
         }
 
+        // Applicant can attach the manifesto to the application, or remove it, during the announcing
+        // stage. The manifesto is either a bounded text or the content id in the data directory.
+        #[weight = 10_000_000] // TODO: adjust weight
+        fn set_manifesto(origin, manifesto: Option<ManifestoOf<T>>) {
+            let sender = ensure_signed(origin)?;
+
+            let stage = Self::stage();
+            ensure!(Self::stage().is_some(), "election not running");
+
+            let is_announcing = match stage.unwrap() {
+                ElectionStage::Announcing(_) => true,
+                _ => false
+            };
+            ensure!(is_announcing, "election not in announcing stage");
+
+            ensure!(<ApplicantStakes<T>>::contains_key(&sender), "only applicants can set manifesto");
+
+            if let Some(manifesto) = manifesto {
+                Self::ensure_valid_manifesto(&manifesto)?;
+                <ApplicantManifestos<T>>::insert(&sender, manifesto);
+            } else {
+                <ApplicantManifestos<T>>::remove(&sender);
+            }
+
+            Self::deposit_event(RawEvent::ManifestoUpdated(sender));
+        }
+
         #[weight = 10_000_000] // TODO: adjust weight
         fn vote(origin, commitment: T::Hash, stake: BalanceOf<T>) {
             let sender = ensure_signed(origin)?;
@@ -878,6 +1198,38 @@ decl_module! {
             Self::deposit_event(RawEvent::Revealed(sender, commitment, vote));
         }
 
+        // Voter can schedule the automatic reveal of the vote during the voting stage. The reveal
+        // data is encrypted with the key stored in the voter's node offchain local storage
+        // (see `encrypt_reveal`). The offchain worker of that node submits the reveal
+        // during the revealing stage if the voter's key is in its keystore.
+        #[weight = 10_000_000] // TODO: adjust weight
+        fn schedule_reveal(origin, commitment: T::Hash, encrypted_reveal: Vec<u8>) {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(
+                encrypted_reveal.len() <= MAX_ENCRYPTED_REVEAL_LENGTH,
+                "encrypted reveal too large"
+            );
+
+            let stage = Self::stage();
+            ensure!(Self::stage().is_some(), "election not running");
+
+            let is_voting = match stage.unwrap() {
+                ElectionStage::Voting(_) => true,
+                _ => false
+            };
+            ensure!(is_voting, "election not in voting stage");
+
+            ensure!(<Votes<T>>::contains_key(&commitment), "commitment not found");
+            ensure!(
+                Self::votes(&commitment).is_owned_by(sender.clone()),
+                "only voter can schedule reveal"
+            );
+
+            <ScheduledReveals<T>>::insert(commitment, encrypted_reveal);
+            Self::deposit_event(RawEvent::RevealScheduled(sender, commitment));
+        }
+
         #[weight = 10_000_000] // TODO: adjust weight
         fn set_stage_announcing(origin, ends_at: T::BlockNumber) {
             ensure_root(origin)?;
@@ -912,6 +1264,15 @@ decl_module! {
             Self::set_verified_election_parameters(params);
         }
 
+        /// Sets new election parameters at the start of the next election. Can be called during
+        /// the election. Replaces previously deferred parameters.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn set_election_parameters_at_next_election(origin, params: ElectionParameters<BalanceOf<T>, T::BlockNumber>) {
+            ensure_root(origin)?;
+            params.ensure_valid()?;
+            <PendingElectionParameters<T>>::put(params);
+        }
+
         #[weight = 10_000_000] // TODO: adjust weight
         fn force_stop_election(origin) {
             ensure_root(origin)?;
@@ -959,8 +1320,9 @@ mod tests {
     use super::*;
     use crate::mock::*;
     use codec::Encode;
-    use frame_support::traits::OnFinalize;
+    use frame_support::traits::{OffchainWorker, OnFinalize};
     use frame_support::{assert_err, assert_ok};
+    use sp_core::offchain::{testing::TestOffchainExt, OffchainExt, StorageKind};
     use system::RawOrigin;
 
     #[test]
@@ -1055,6 +1417,43 @@ mod tests {
         });
     }
 
+    #[test]
+    fn start_election_should_set_pending_election_parameters() {
+        initial_test_ext().execute_with(|| {
+            System::set_block_number(1);
+
+            let new_parameters = ElectionParameters {
+                announcing_period: 30,
+                voting_period: 2,
+                revealing_period: 3,
+                council_size: 4,
+                candidacy_limit: 5,
+                min_voting_stake: 6,
+                min_council_stake: 7,
+                new_term_duration: 8,
+            };
+
+            assert_ok!(Election::set_election_parameters_at_next_election(
+                RawOrigin::Root.into(),
+                new_parameters
+            ));
+            assert_eq!(
+                Election::pending_election_parameters(),
+                Some(new_parameters)
+            );
+            assert_ne!(
+                Election::announcing_period(),
+                new_parameters.announcing_period
+            );
+
+            assert_ok!(Election::start_election(vec![]));
+
+            assert!(Election::pending_election_parameters().is_none());
+            assert_eq!(CouncilSize::get(), new_parameters.council_size);
+            assert_announcing_period(1 + new_parameters.announcing_period);
+        });
+    }
+
     #[test]
     fn init_transferable_stake_should_work() {
         initial_test_ext().execute_with(|| {
@@ -1545,6 +1944,357 @@ mod tests {
         });
     }
 
+    #[test]
+    fn vote_commitment_helper_matches_revealed_commitment() {
+        initial_test_ext().execute_with(|| {
+            let applicant = 20 as u64;
+            let salt = vec![128u8, 7u8];
+            let commitment = Election::vote_commitment(&applicant, &salt);
+            let voter = 10 as u64;
+
+            assert_eq!(
+                commitment,
+                make_commitment_for_applicant(applicant, &mut salt.clone())
+            );
+
+            <ApplicantStakes<Test>>::insert(
+                &applicant,
+                Stake {
+                    new: 0,
+                    transferred: 0,
+                },
+            );
+
+            <Votes<Test>>::insert(
+                &commitment,
+                SealedVote::new(
+                    voter,
+                    Stake {
+                        new: 100,
+                        transferred: 0,
+                    },
+                    commitment,
+                ),
+            );
+
+            assert!(Election::try_reveal_vote(voter, commitment, applicant, salt).is_ok());
+        });
+    }
+
+    #[test]
+    fn encrypted_reveal_roundtrip_works() {
+        initial_test_ext().execute_with(|| {
+            let key = [7u8; 32];
+            let salt = vec![1u8; 32];
+
+            let commitment = Election::vote_commitment(&20, &salt);
+
+            let encrypted = Election::encrypt_reveal(&key, &20, &salt).unwrap();
+
+            assert_ne!(encrypted, (20u64, salt.clone()).encode());
+            assert_eq!(
+                Election::decrypt_reveal(&key, &commitment, &encrypted),
+                Some((20, salt))
+            );
+
+            // tampered data and wrong keys fail the authentication
+            let mut tampered = encrypted.clone();
+            tampered[0] ^= 1;
+            assert_eq!(Election::decrypt_reveal(&key, &commitment, &tampered), None);
+            assert_eq!(
+                Election::decrypt_reveal(&[8u8; 32], &commitment, &encrypted),
+                None
+            );
+        });
+    }
+
+    fn schedule_vote_with_reveal(
+        voter: <Test as system::Trait>::AccountId,
+        applicant: <Test as system::Trait>::AccountId,
+        salt: &[u8],
+        key: &RevealKey,
+    ) -> <Test as system::Trait>::Hash {
+        let commitment = Election::vote_commitment(&applicant, salt);
+        let encrypted = Election::encrypt_reveal(key, &applicant, salt).unwrap();
+
+        <Votes<Test>>::insert(
+            &commitment,
+            SealedVote::new(
+                voter,
+                Stake {
+                    new: 100,
+                    transferred: 0,
+                },
+                commitment,
+            ),
+        );
+        <Commitments<Test>>::mutate(|commitments| commitments.push(commitment));
+
+        assert_ok!(Election::schedule_reveal(
+            Origin::signed(voter),
+            commitment,
+            encrypted.clone()
+        ));
+        assert_eq!(Election::scheduled_reveals(commitment), encrypted);
+
+        commitment
+    }
+
+    #[test]
+    fn scheduling_reveal_works() {
+        initial_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            <Stage<Test>>::put(ElectionStage::Voting(10));
+
+            schedule_vote_with_reveal(10, 20, &[1u8], &[7u8; 32]);
+        });
+    }
+
+    #[test]
+    fn scheduling_reveal_should_only_work_in_voting_stage() {
+        initial_test_ext().execute_with(|| {
+            let commitment = Election::vote_commitment(&20, &[1u8]);
+
+            assert_err!(
+                Election::schedule_reveal(Origin::signed(10), commitment, vec![1u8]),
+                "election not running"
+            );
+
+            <Stage<Test>>::put(ElectionStage::Revealing(10));
+            assert_err!(
+                Election::schedule_reveal(Origin::signed(10), commitment, vec![1u8]),
+                "election not in voting stage"
+            );
+        });
+    }
+
+    #[test]
+    fn scheduling_reveal_should_only_work_for_voter() {
+        initial_test_ext().execute_with(|| {
+            <Stage<Test>>::put(ElectionStage::Voting(10));
+            let commitment = schedule_vote_with_reveal(10, 20, &[1u8], &[7u8; 32]);
+
+            assert_err!(
+                Election::schedule_reveal(Origin::signed(11), commitment, vec![1u8]),
+                "only voter can schedule reveal"
+            );
+
+            let unknown_commitment = Election::vote_commitment(&30, &[1u8]);
+            assert_err!(
+                Election::schedule_reveal(Origin::signed(10), unknown_commitment, vec![1u8]),
+                "commitment not found"
+            );
+        });
+    }
+
+    #[test]
+    fn scheduling_too_large_reveal_should_not_work() {
+        initial_test_ext().execute_with(|| {
+            <Stage<Test>>::put(ElectionStage::Voting(10));
+            let commitment = Election::vote_commitment(&20, &[1u8]);
+
+            assert_err!(
+                Election::schedule_reveal(
+                    Origin::signed(10),
+                    commitment,
+                    vec![1u8; MAX_ENCRYPTED_REVEAL_LENGTH + 1]
+                ),
+                "encrypted reveal too large"
+            );
+        });
+    }
+
+    #[test]
+    fn offchain_worker_submits_scheduled_reveals_with_local_keys() {
+        let (offchain, _state) = TestOffchainExt::new();
+        let mut ext = initial_test_ext();
+        ext.register_extension(OffchainExt::new(offchain));
+
+        ext.execute_with(|| {
+            System::set_block_number(1);
+            let key = [7u8; 32];
+            let salt = vec![1u8, 2u8];
+
+            <Stage<Test>>::put(ElectionStage::Voting(10));
+            let commitment = schedule_vote_with_reveal(10, 20, &salt, &key);
+            // the key for the other vote is not stored locally
+            schedule_vote_with_reveal(11, 20, &[3u8], &[8u8; 32]);
+
+            let mut storage_key = REVEAL_KEY_STORAGE_PREFIX.to_vec();
+            storage_key.extend_from_slice(&commitment.encode());
+            sp_io::offchain::local_storage_set(StorageKind::PERSISTENT, &storage_key, &key);
+
+            // nothing is submitted before the revealing stage
+            Election::offchain_worker(1);
+            SUBMITTED_REVEALS.with(|reveals| assert!(reveals.borrow().is_empty()));
+
+            <Stage<Test>>::put(ElectionStage::Revealing(20));
+            Election::offchain_worker(11);
+            // the reveal is submitted only once
+            Election::offchain_worker(12);
+
+            SUBMITTED_REVEALS.with(|reveals| {
+                assert_eq!(*reveals.borrow(), vec![(10, commitment, 20, salt.clone())])
+            });
+        });
+    }
+
+    #[test]
+    fn scheduled_reveals_are_cleared_with_votes() {
+        initial_test_ext().execute_with(|| {
+            <Stage<Test>>::put(ElectionStage::Voting(10));
+            let commitment = schedule_vote_with_reveal(10, 20, &[1u8], &[7u8; 32]);
+
+            Election::clear_votes();
+
+            assert!(!<ScheduledReveals<Test>>::contains_key(commitment));
+        });
+    }
+
+    #[test]
+    fn applicants_with_stakes_returns_total_stakes() {
+        initial_test_ext().execute_with(|| {
+            <Applicants<Test>>::put(vec![10, 20]);
+            <ApplicantStakes<Test>>::insert(
+                10,
+                Stake {
+                    new: 100,
+                    transferred: 50,
+                },
+            );
+            <ApplicantStakes<Test>>::insert(
+                20,
+                Stake {
+                    new: 0,
+                    transferred: 30,
+                },
+            );
+
+            assert_eq!(
+                Election::applicants_with_stakes(),
+                vec![(10, 150), (20, 30)]
+            );
+        });
+    }
+
+    #[test]
+    fn setting_manifesto_works() {
+        initial_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(Election::start_election(vec![]));
+
+            let _ = Balances::deposit_creating(&1, 1000);
+
+            assert_err!(
+                Election::set_manifesto(Origin::signed(1), Some(Manifesto::Content(1))),
+                "only applicants can set manifesto"
+            );
+
+            assert_ok!(Election::try_add_applicant(1, 100));
+            assert_ok!(Election::set_manifesto(
+                Origin::signed(1),
+                Some(Manifesto::Content(1))
+            ));
+            assert_eq!(
+                Election::applicants_with_manifestos(),
+                vec![(1, Manifesto::Content(1))]
+            );
+
+            assert_ok!(Election::set_manifesto(Origin::signed(1), None));
+            assert!(Election::applicant_manifestos(1).is_none());
+        });
+    }
+
+    #[test]
+    fn setting_invalid_manifesto_should_not_work() {
+        initial_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(Election::start_election(vec![]));
+
+            let _ = Balances::deposit_creating(&1, 1000);
+            assert_ok!(Election::try_add_applicant(1, 100));
+
+            assert_err!(
+                Election::set_manifesto(
+                    Origin::signed(1),
+                    Some(Manifesto::Text(vec![0u8; MAX_MANIFESTO_LENGTH + 1]))
+                ),
+                "manifesto too long"
+            );
+            assert_err!(
+                Election::set_manifesto(Origin::signed(1), Some(Manifesto::Content(100))),
+                "manifesto content not found"
+            );
+        });
+    }
+
+    #[test]
+    fn setting_manifesto_should_only_work_in_announcing_stage() {
+        initial_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(Election::start_election(vec![]));
+
+            let _ = Balances::deposit_creating(&1, 1000);
+            assert_ok!(Election::try_add_applicant(1, 100));
+
+            Election::move_to_voting_stage();
+
+            assert_err!(
+                Election::set_manifesto(Origin::signed(1), Some(Manifesto::Content(1))),
+                "election not in announcing stage"
+            );
+        });
+    }
+
+    #[test]
+    fn election_result_is_archived_with_manifestos() {
+        initial_test_ext().execute_with(|| {
+            CouncilSize::put(1);
+            System::set_block_number(1);
+            assert_ok!(Election::start_election(vec![]));
+
+            let _ = Balances::deposit_creating(&1, 1000);
+            let _ = Balances::deposit_creating(&2, 1000);
+            assert_ok!(Election::try_add_applicant(1, 100));
+            assert_ok!(Election::try_add_applicant(2, 200));
+
+            let manifesto = Manifesto::Text(b"platform".to_vec());
+            assert_ok!(Election::set_manifesto(
+                Origin::signed(2),
+                Some(manifesto.clone())
+            ));
+
+            Election::on_revealing_ended();
+
+            assert_eq!(
+                Election::election_results(Election::round()),
+                Some(ElectionResult {
+                    elected_at: 1,
+                    council: vec![Seat {
+                        member: 2,
+                        stake: 200,
+                        backers: vec![],
+                    }],
+                    applicants: vec![
+                        ApplicantRecord {
+                            applicant: 2,
+                            stake: 200,
+                            manifesto: Some(manifesto),
+                        },
+                        ApplicantRecord {
+                            applicant: 1,
+                            stake: 100,
+                            manifesto: None,
+                        },
+                    ],
+                })
+            );
+
+            // manifestos are cleared with the applicants
+            assert!(Election::applicant_manifestos(2).is_none());
+        });
+    }
+
     #[test]
     fn revealing_with_bad_salt_should_not_work() {
         initial_test_ext().execute_with(|| {
@@ -3,6 +3,7 @@
 pub use super::{council, election};
 pub use common::currency::GovernanceCurrency;
 
+use frame_support::traits::{Filter, Get};
 use frame_support::{impl_outer_origin, parameter_types};
 use sp_core::H256;
 use sp_runtime::{
@@ -10,10 +11,13 @@ use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
     BuildStorage, Perbill,
 };
+use std::cell::RefCell;
 pub use system;
 
 impl_outer_origin! {
-    pub enum Origin for Test {}
+    pub enum Origin for Test {
+        council
+    }
 }
 
 // Workaround for https://github.com/rust-lang/rust/issues/26925 . Remove when sorted.
@@ -59,15 +63,100 @@ impl pallet_timestamp::Trait for Test {
     type OnTimestampSet = ();
     type MinimumPeriod = MinimumPeriod;
 }
+parameter_types! {
+    pub const CouncilSize: u32 = 4;
+    pub const MotionApprovalThresholdPercentage: u32 = 66;
+    pub const MaxMotionCallLength: u32 = 9;
+    pub const MotionExpiryPeriod: u64 = 10;
+}
+
 impl council::Trait for Test {
     type Event = ();
 
     type CouncilTermEnded = (Election,);
+
+    type MotionOrigin = Origin;
+
+    type MotionCall = council::Call<Test>;
+
+    type MotionCallFilter = TestMotionCallFilter;
+
+    type CouncilOrigin = council::EnsureRootOrCouncilMotion<u64>;
+
+    type MaxMotionCallLength = MaxMotionCallLength;
+
+    type MotionExpiryPeriod = MotionExpiryPeriod;
+
+    type CouncilSize = CouncilSize;
+
+    type MotionApprovalThresholdPercentage = MotionApprovalThresholdPercentage;
+
+    type ExecutingProposalId = TestExecutingProposalId;
+}
+
+thread_local! {
+    pub static EXECUTING_PROPOSAL_ID: RefCell<Option<u32>> = RefCell::new(None);
+}
+
+// Provides the proposal id set by the tests.
+pub struct TestExecutingProposalId;
+impl Get<Option<u32>> for TestExecutingProposalId {
+    fn get() -> Option<u32> {
+        EXECUTING_PROPOSAL_ID.with(|id| *id.borrow())
+    }
+}
+
+pub fn set_executing_proposal_id(proposal_id: Option<u32>) {
+    EXECUTING_PROPOSAL_ID.with(|id| *id.borrow_mut() = proposal_id);
+}
+
+// Allows the recurring grant cancelling, council mint capacity and council mint spending calls
+// in the motions. The council mint capacity call doesn't accept the council motion origin and the
+// council mint spending call exceeds the max motion call length.
+pub struct TestMotionCallFilter;
+impl Filter<council::Call<Test>> for TestMotionCallFilter {
+    fn filter(call: &council::Call<Test>) -> bool {
+        match call {
+            council::Call::cancel_recurring_grant(..)
+            | council::Call::set_council_mint_capacity(..)
+            | council::Call::spend_from_council_mint(..) => true,
+            _ => false,
+        }
+    }
 }
 impl election::Trait for Test {
     type Event = ();
 
     type CouncilElected = (Council,);
+
+    type RevealSubmitter = TestRevealSubmitter;
+
+    type ManifestoContentId = u64;
+
+    type ManifestoContentValidator = TestManifestoContentValidator;
+}
+
+// Content ids below 100 exist in the test data directory.
+pub struct TestManifestoContentValidator;
+impl election::ManifestoContentValidator<u64> for TestManifestoContentValidator {
+    fn has_content(content_id: &u64) -> bool {
+        *content_id < 100
+    }
+}
+
+thread_local! {
+    pub static SUBMITTED_REVEALS: RefCell<Vec<(u64, H256, u64, Vec<u8>)>> = RefCell::new(Vec::new());
+}
+
+// Records the reveals submitted by the offchain worker.
+pub struct TestRevealSubmitter;
+impl election::RevealSubmitter<u64, H256> for TestRevealSubmitter {
+    fn submit_reveal(voter: u64, commitment: H256, vote: u64, salt: Vec<u8>) -> bool {
+        SUBMITTED_REVEALS
+            .with(|reveals| reveals.borrow_mut().push((voter, commitment, vote, salt)));
+
+        true
+    }
 }
 impl membership::Trait for Test {
     type Event = ();
@@ -81,7 +170,7 @@ impl minting::Trait for Test {
     type MintId = u64;
 }
 impl recurringrewards::Trait for Test {
-    type PayoutStatusHandler = ();
+    type PayoutStatusHandler = Council;
     type RecipientId = u64;
     type RewardRelationshipId = u64;
 }
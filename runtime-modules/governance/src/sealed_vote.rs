@@ -2,6 +2,13 @@ use codec::{Decode, Encode};
 use frame_support::ensure;
 use sp_std::vec::Vec;
 
+/// Builds the payload hashed into a vote commitment: the serialized vote with the salt appended.
+pub fn commitment_payload<Vote: Encode>(vote: &Vote, salt: &[u8]) -> Vec<u8> {
+    let mut payload = vote.encode();
+    payload.extend_from_slice(salt);
+    payload
+}
+
 #[derive(Clone, Copy, Encode, Decode, Default)]
 pub struct SealedVote<AccountId, Stake, Hash, Vote>
 where
@@ -51,15 +58,13 @@ where
     pub fn unseal(
         &mut self,
         vote: Vote,
-        salt: &mut Vec<u8>,
+        salt: &[u8],
         hasher: fn(&[u8]) -> Hash,
     ) -> Result<(), &'static str> {
         // only unseal once
         ensure!(self.is_not_revealed(), "vote already unsealed");
 
-        // seralize the vote and append the salt
-        let mut payload = vote.encode();
-        payload.append(salt);
+        let payload = commitment_payload(&vote, salt);
 
         // hash the payload, if it matches the commitment it is a valid revealing of the vote
         if self.commitment == hasher(&payload) {
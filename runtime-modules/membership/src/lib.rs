@@ -446,6 +446,33 @@ decl_module! {
             ensure_root(origin)?;
             <ScreeningAuthority<T>>::put(authority);
         }
+
+        /// Enables or disables registering of the new members. Requires root privileges.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn set_new_memberships_allowed(origin, allowed: bool) {
+            ensure_root(origin)?;
+            NewMembershipsAllowed::put(allowed);
+        }
+
+        /// Sets the user info length constraints. Requires root privileges.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn set_user_info_constraints(
+            origin,
+            min_handle_length: u32,
+            max_handle_length: u32,
+            max_avatar_uri_length: u32,
+            max_about_text_length: u32
+        ) {
+            ensure_root(origin)?;
+
+            ensure!(min_handle_length > 0, "min handle length must be positive");
+            ensure!(min_handle_length <= max_handle_length, "min handle length exceeds max handle length");
+
+            MinHandleLength::put(min_handle_length);
+            MaxHandleLength::put(max_handle_length);
+            MaxAvatarUriLength::put(max_avatar_uri_length);
+            MaxAboutTextLength::put(max_about_text_length);
+        }
     }
 }
 
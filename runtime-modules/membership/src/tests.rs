@@ -337,3 +337,50 @@ fn set_root_account() {
             assert!(<crate::MemberIdsByRootAccountId<Test>>::get(&ALICE_ACCOUNT_ID).is_empty());
         });
 }
+
+#[test]
+fn set_new_memberships_allowed() {
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(genesis::GenesisConfigBuilder::default().build())
+        .build()
+        .execute_with(|| {
+            assert_eq!(
+                Members::set_new_memberships_allowed(Origin::signed(ALICE_ACCOUNT_ID), false),
+                Err(sp_runtime::DispatchError::BadOrigin)
+            );
+
+            assert_ok!(Members::set_new_memberships_allowed(
+                system::RawOrigin::Root.into(),
+                false
+            ));
+
+            assert!(!Members::new_memberships_allowed());
+        });
+}
+
+#[test]
+fn set_user_info_constraints() {
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(genesis::GenesisConfigBuilder::default().build())
+        .build()
+        .execute_with(|| {
+            assert_dispatch_error_message(
+                Members::set_user_info_constraints(system::RawOrigin::Root.into(), 10, 5, 100, 200)
+                    .map_err(|err| err.into()),
+                "min handle length exceeds max handle length",
+            );
+
+            assert_ok!(Members::set_user_info_constraints(
+                system::RawOrigin::Root.into(),
+                3,
+                20,
+                100,
+                200
+            ));
+
+            assert_eq!(Members::min_handle_length(), 3);
+            assert_eq!(Members::max_handle_length(), 20);
+            assert_eq!(Members::max_avatar_uri_length(), 100);
+            assert_eq!(Members::max_about_text_length(), 200);
+        });
+}
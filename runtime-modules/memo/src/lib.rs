@@ -5,7 +5,7 @@ use frame_support::traits::Currency;
 use frame_support::{decl_event, decl_module, decl_storage, ensure};
 use sp_arithmetic::traits::Zero;
 use sp_std::vec::Vec;
-use system::ensure_signed;
+use system::{ensure_root, ensure_signed};
 
 use common::currency::GovernanceCurrency;
 
@@ -25,6 +25,7 @@ decl_storage! {
 decl_event! {
     pub enum Event<T> where <T as system::Trait>::AccountId {
         MemoUpdated(AccountId),
+        MaxMemoLengthUpdated(u32),
     }
 }
 
@@ -42,5 +43,15 @@ decl_module! {
             <Memo<T>>::insert(&sender, memo);
             Self::deposit_event(RawEvent::MemoUpdated(sender));
         }
+
+        /// Set the max memo length. Existing memos longer than the new limit are kept.
+        #[weight = 10_000_000] // TODO: adjust weight
+        fn set_max_memo_length(origin, max_memo_length: u32) {
+            ensure_root(origin)?;
+            ensure!(max_memo_length > 0, "max memo length must be positive");
+
+            MaxMemoLength::put(max_memo_length);
+            Self::deposit_event(RawEvent::MaxMemoLengthUpdated(max_memo_length));
+        }
     }
 }
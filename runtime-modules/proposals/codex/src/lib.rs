@@ -7,9 +7,9 @@
 //! The proposals codex module serves as a facade and entry point of the proposals system. It uses
 //! proposals `engine` module to maintain a lifecycle of the proposal and to execute proposals.
 //! During the proposal creation, `codex` also create a discussion thread using the `discussion`
-//! proposals module. `Codex` uses predefined parameters (eg.:`voting_period`) for each proposal and
-//! encodes extrinsic calls from dependency modules in order to create proposals inside the `engine`
-//! module. For each proposal, [its crucial details](./enum.ProposalDetails.html) are saved to the
+//! proposals module. `Codex` uses parameters (eg.:`voting_period`) of each proposal type from the
+//! `ProposalParametersByType` map and encodes extrinsic calls from dependency modules in order to
+//! create proposals inside the `engine` module. For each proposal, [its crucial details](./enum.ProposalDetails.html) are saved to the
 //! `ProposalDetailsByProposalId` map.
 //!
 //! ### General proposals
@@ -20,12 +20,17 @@
 //! ### Council and election proposals
 //! - [create_set_election_parameters_proposal](./struct.Module.html#method.create_set_election_parameters_proposal)
 //! - [create_spending_proposal](./struct.Module.html#method.create_spending_proposal)
+//! - [create_recurring_grant_proposal](./struct.Module.html#method.create_recurring_grant_proposal)
+//! - [create_cancel_recurring_grant_proposal](./struct.Module.html#method.create_cancel_recurring_grant_proposal)
 //!
 //! ### Content working group proposals
 //! - [create_set_lead_proposal](./struct.Module.html#method.create_set_lead_proposal)
 //! - [create_set_content_working_group_mint_capacity_proposal](./struct.Module.html#method.create_set_content_working_group_mint_capacity_proposal)
 //!
 //! ### Working group proposals
+//! Working group proposals can be created for any working group instance registered in the
+//! runtime. The instance is selected by the `WorkingGroup` parameter of the proposal.
+//!
 //! - [create_add_working_group_leader_opening_proposal](./struct.Module.html#method.create_add_working_group_leader_opening_proposal)
 //! - [create_begin_review_working_group_leader_applications_proposal](./struct.Module.html#method.create_begin_review_working_group_leader_applications_proposal)
 //! - [create_fill_working_group_leader_opening_proposal](./struct.Module.html#method.create_fill_working_group_leader_opening_proposal)
@@ -35,9 +40,60 @@
 //! - [create_set_working_group_leader_reward_proposal](./struct.Module.html#method.create_set_working_group_leader_reward_proposal)
 //! - [create_terminate_working_group_leader_role_proposal](./struct.Module.html#method.create_terminate_working_group_leader_role_proposal)
 //!
+//! ### Platform parameters proposals
+//! - [create_set_new_memberships_allowed_proposal](./struct.Module.html#method.create_set_new_memberships_allowed_proposal)
+//! - [create_set_membership_user_info_constraints_proposal](./struct.Module.html#method.create_set_membership_user_info_constraints_proposal)
+//! - [create_set_membership_screening_authority_proposal](./struct.Module.html#method.create_set_membership_screening_authority_proposal)
+//! - [create_set_forum_sudo_proposal](./struct.Module.html#method.create_set_forum_sudo_proposal)
+//! - [create_set_forum_input_validation_constraints_proposal](./struct.Module.html#method.create_set_forum_input_validation_constraints_proposal)
+//! - [create_set_max_memo_length_proposal](./struct.Module.html#method.create_set_max_memo_length_proposal)
+//! - [create_set_service_discovery_default_lifetime_proposal](./struct.Module.html#method.create_set_service_discovery_default_lifetime_proposal)
+//!
+//! ### Storage proposals
+//! Data object type proposals override the storage working group leader.
+//!
+//! - [create_register_data_object_type_proposal](./struct.Module.html#method.create_register_data_object_type_proposal)
+//! - [create_update_data_object_type_proposal](./struct.Module.html#method.create_update_data_object_type_proposal)
+//! - [create_activate_data_object_type_proposal](./struct.Module.html#method.create_activate_data_object_type_proposal)
+//! - [create_deactivate_data_object_type_proposal](./struct.Module.html#method.create_deactivate_data_object_type_proposal)
+//! - [create_set_service_discovery_bootstrap_endpoints_proposal](./struct.Module.html#method.create_set_service_discovery_bootstrap_endpoints_proposal)
+//!
+//! ### Proposal parameters proposals
+//! - [create_update_proposal_parameters_proposal](./struct.Module.html#method.create_update_proposal_parameters_proposal)
+//!
+//! ### Batch proposals
+//! - [create_batch_proposal](./struct.Module.html#method.create_batch_proposal)
+//!
+//! ### Signal proposals
+//! Signal proposals are non-binding polls tracked along with the binding proposals. They have
+//! multiple options, any member can vote for one of them and the results are recorded without
+//! the stake slashing or the execution. The proposer reserves a deposit and the signal proposal
+//! counts towards the active proposal limits of the proposals engine until its voting period ends.
+//! The results are emitted with the `SignalProposalVotingEnded` event at the end of the voting
+//! period, then the signal proposal, its votes and discussion are pruned and the deposit is refunded.
+//!
+//! - [create_signal_proposal](./struct.Module.html#method.create_signal_proposal)
+//! - [vote_on_signal_proposal](./struct.Module.html#method.vote_on_signal_proposal)
+//!
+//! ### Runtime upgrade preimage
+//! - [upload_runtime_upgrade_preimage](./struct.Module.html#method.upload_runtime_upgrade_preimage) -
+//! uploads the wasm code of the proposed runtime upgrade. The 'runtime upgrade' proposal carries
+//! only the code hash, the code itself is uploaded separately by anyone and checked for the size
+//! and the runtime `spec_name`. The uploader reserves a deposit proportional to the code length.
+//! The code hash is referenced by every pending proposal carrying it: the preimage is removed and
+//! the deposit is refunded when the last of these proposals gets its final decision or is amended.
+//!
+//! ### Proposal amendment
+//! - [amend_proposal](./struct.Module.html#method.amend_proposal) - amends the title, description
+//! and details of the proposal. The proposals engine keeps the previous versions of the proposal,
+//! the discussion thread gets the amended title.
+//!
 //! ### Proposal implementations of this module
 //! - execute_text_proposal - prints the proposal to the log
-//! - execute_runtime_upgrade_proposal - sets the runtime code
+//! - execute_runtime_upgrade_proposal - sets the runtime code from the uploaded preimage
+//! - execute_update_proposal_parameters_proposal - sets the proposal parameters of the proposal type
+//! - execute_set_election_parameters_proposal - sets the election parameters or defers them until
+//! the next election
 //!
 //! ### Dependencies:
 //! - [proposals engine](../substrate_proposals_engine_module/index.html)
@@ -60,27 +116,63 @@
 // Do not delete! Cannot be uncommented by default, because of Parity decl_module! issue.
 // #![warn(missing_docs)]
 
+mod migration;
 mod proposal_types;
 
 #[cfg(test)]
 mod tests;
 
+use codec::Decode;
 use frame_support::dispatch::DispatchResult;
-use frame_support::traits::{Currency, Get};
-use frame_support::{decl_error, decl_module, decl_storage, ensure, print};
-use sp_arithmetic::traits::Zero;
+use frame_support::storage::IterableStorageDoubleMap;
+use frame_support::traits::{Currency, Get, ReservableCurrency};
+use frame_support::weights::Weight;
+use frame_support::{decl_error, decl_event, decl_module, decl_storage, ensure, print};
+use sp_arithmetic::traits::{Saturating, UniqueSaturatedInto, Zero};
+use sp_runtime::traits::Hash;
 use sp_std::clone::Clone;
+use sp_std::mem;
 use sp_std::str::from_utf8;
+use sp_std::vec;
 use sp_std::vec::Vec;
-use system::ensure_root;
+use sp_version::RuntimeVersion;
+use system::{ensure_root, ensure_signed};
 
+use common::currency::GovernanceCurrency;
 use common::origin::ActorOriginValidator;
 use common::working_group::WorkingGroup;
+use governance::council::RecurringGrantId;
 use governance::election_params::ElectionParameters;
-use proposals_engine::ProposalParameters;
+use proposals_engine::{
+    ExecutionSchedule, ProposalCreationParameters, ProposalObserver, ProposalParameters,
+    ProposalType,
+};
 
+pub use crate::proposal_types::parameters::{
+    default_proposal_parameters, proposal_parameters_with_grace_and_voting_periods,
+};
 pub use crate::proposal_types::{
-    AddOpeningParameters, FillOpeningParameters, ProposalsConfigParameters, TerminateRoleParameters,
+    AddOpeningParameters, DataObjectTypeId, DataObjectTypeParameters, FillOpeningParameters,
+    ForumInputValidationConstraints, MembershipUserInfoConstraints, RecurringGrantParameters,
+    SignalProposal, SignalProposalId, SignalProposalOf, TerminateRoleParameters,
+    ACTIVATE_DATA_OBJECT_TYPE_PROPOSAL_TYPE, ADD_WORKING_GROUP_LEADER_OPENING_PROPOSAL_TYPE,
+    BATCH_PROPOSAL_TYPE, BEGIN_REVIEW_WORKING_GROUP_LEADER_APPLICATIONS_PROPOSAL_TYPE,
+    CANCEL_RECURRING_GRANT_PROPOSAL_TYPE, DEACTIVATE_DATA_OBJECT_TYPE_PROPOSAL_TYPE,
+    DECREASE_WORKING_GROUP_LEADER_STAKE_PROPOSAL_TYPE, EVICT_STORAGE_PROVIDER_PROPOSAL_TYPE,
+    FILL_WORKING_GROUP_LEADER_OPENING_PROPOSAL_TYPE, RECURRING_GRANT_PROPOSAL_TYPE,
+    REGISTER_DATA_OBJECT_TYPE_PROPOSAL_TYPE, RUNTIME_UPGRADE_PROPOSAL_TYPE,
+    SET_CONTENT_WORKING_GROUP_MINT_CAPACITY_PROPOSAL_TYPE, SET_ELECTION_PARAMETERS_PROPOSAL_TYPE,
+    SET_FORUM_INPUT_VALIDATION_CONSTRAINTS_PROPOSAL_TYPE, SET_FORUM_SUDO_PROPOSAL_TYPE,
+    SET_LEAD_PROPOSAL_TYPE, SET_MAX_MEMO_LENGTH_PROPOSAL_TYPE,
+    SET_MEMBERSHIP_SCREENING_AUTHORITY_PROPOSAL_TYPE,
+    SET_MEMBERSHIP_USER_INFO_CONSTRAINTS_PROPOSAL_TYPE, SET_NEW_MEMBERSHIPS_ALLOWED_PROPOSAL_TYPE,
+    SET_SERVICE_DISCOVERY_BOOTSTRAP_ENDPOINTS_PROPOSAL_TYPE,
+    SET_SERVICE_DISCOVERY_DEFAULT_LIFETIME_PROPOSAL_TYPE,
+    SET_STORAGE_ROLE_PARAMETERS_PROPOSAL_TYPE, SET_VALIDATOR_COUNT_PROPOSAL_TYPE,
+    SET_WORKING_GROUP_LEADER_REWARD_PROPOSAL_TYPE, SET_WORKING_GROUP_MINT_CAPACITY_PROPOSAL_TYPE,
+    SIGNAL_PROPOSAL_TYPE, SLASH_WORKING_GROUP_LEADER_STAKE_PROPOSAL_TYPE, SPENDING_PROPOSAL_TYPE,
+    TERMINATE_WORKING_GROUP_LEADER_ROLE_PROPOSAL_TYPE, TEXT_PROPOSAL_TYPE,
+    UPDATE_DATA_OBJECT_TYPE_PROPOSAL_TYPE, UPDATE_PROPOSAL_PARAMETERS_PROPOSAL_TYPE,
 };
 pub use proposal_types::{ProposalDetails, ProposalDetailsOf, ProposalEncoder};
 
@@ -90,8 +182,27 @@ const WORKING_GROUP_MINT_CAPACITY_MAX_VALUE: u32 = 5_000_000;
 const CONTENT_WORKING_GROUP_MINT_CAPACITY_MAX_VALUE: u32 = 1_000_000;
 // Max allowed value for 'spending' proposal
 const MAX_SPENDING_PROPOSAL_VALUE: u32 = 5_000_000_u32;
+// Min value of the 'spending' proposal that requires the approval of two councils
+const LARGE_SPENDING_PROPOSAL_VALUE: u32 = 1_000_000_u32;
+// Max number of the proposals in the 'batch' proposal
+const MAX_BATCH_PROPOSAL_SIZE: usize = 10;
 // Max validator count for the 'set validator count' proposal
 const MAX_VALIDATOR_COUNT: u32 = 100;
+// Max length of the data object type description for the data object type proposals
+const DATA_OBJECT_TYPE_DESCRIPTION_MAX_LENGTH: usize = 1000;
+// Max number of the endpoints for the 'set service discovery bootstrap endpoints' proposal
+const MAX_BOOTSTRAP_ENDPOINTS: usize = 20;
+// Max endpoint length for the 'set service discovery bootstrap endpoints' proposal
+const BOOTSTRAP_ENDPOINT_MAX_LENGTH: usize = 500;
+// Min value for the 'set service discovery default lifetime' proposal. Equals to the service
+// discovery minimum lifetime.
+const SERVICE_DISCOVERY_MIN_DEFAULT_LIFETIME: u32 = 600;
+// Min number of the options of the 'signal' proposal
+const SIGNAL_PROPOSAL_MIN_OPTIONS: usize = 2;
+// Max number of the options of the 'signal' proposal
+const SIGNAL_PROPOSAL_MAX_OPTIONS: usize = 10;
+// Max option length of the 'signal' proposal
+const SIGNAL_PROPOSAL_OPTION_MAX_LENGTH: usize = 200;
 // council_size min value for the 'set election parameters' proposal
 const ELECTION_PARAMETERS_COUNCIL_SIZE_MIN_VALUE: u32 = 4;
 // council_size max value for the 'set election parameters' proposal
@@ -133,6 +244,8 @@ struct CreateProposalParameters<T: Trait> {
     pub title: Vec<u8>,
     pub description: Vec<u8>,
     pub stake_balance: Option<BalanceOf<T>>,
+    pub execution_schedule: Option<ExecutionSchedule<T::BlockNumber>>,
+    pub dependencies: Vec<T::ProposalId>,
     pub proposal_code: Vec<u8>,
     pub proposal_parameters: ProposalParameters<T::BlockNumber, BalanceOf<T>>,
     pub proposal_details: ProposalDetailsOf<T>,
@@ -148,12 +261,21 @@ pub trait Trait:
     + content_working_group::Trait
     + staking::Trait
 {
+    /// Codex event type.
+    type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
+
     /// Defines max allowed text proposal length.
     type TextProposalMaxLength: Get<u32>;
 
     /// Defines max wasm code length of the runtime upgrade proposal.
     type RuntimeUpgradeWasmProposalMaxLength: Get<u32>;
 
+    /// Deposit reserved per byte of the uploaded runtime upgrade code.
+    type RuntimeUpgradePreimageByteDeposit: Get<BalanceOfGovernanceCurrency<Self>>;
+
+    /// Deposit reserved by the proposer of the signal proposal until its voting period ends.
+    type SignalProposalDeposit: Get<BalanceOfGovernanceCurrency<Self>>;
+
     /// Validates member id and origin combination
     type MembershipOriginValidator: ActorOriginValidator<
         Self::Origin,
@@ -236,6 +358,10 @@ decl_error! {
         /// Invalid council election parameter - announcing_period
         InvalidCouncilElectionParameterAnnouncingPeriod,
 
+        /// Election parameters cannot be changed during the election unless deferred
+        /// until the next election
+        ElectionParametersChangeDuringElection,
+
         /// Invalid content working group mint capacity parameter
         InvalidContentWorkingGroupMintCapacity,
 
@@ -250,144 +376,217 @@ decl_error! {
 
         /// Invalid 'decrease stake proposal' parameter - cannot decrease by zero balance.
         DecreasingStakeIsZero,
-    }
-}
-
-// Storage for the proposals codex module
-decl_storage! {
-    pub trait Store for Module<T: Trait> as ProposalCodex{
-        /// Map proposal id to its discussion thread id
-        pub ThreadIdByProposalId get(fn thread_id_by_proposal_id):
-            map hasher(blake2_128_concat) T::ProposalId => T::ThreadId;
-
-        /// Map proposal id to proposal details
-        pub ProposalDetailsByProposalId get(fn proposal_details_by_proposal_id):
-            map hasher(blake2_128_concat) T::ProposalId => ProposalDetailsOf<T>;
 
-        /// Voting period for the 'set validator count' proposal
-        pub SetValidatorCountProposalVotingPeriod get(fn set_validator_count_proposal_voting_period)
-            config(): T::BlockNumber;
+        /// Invalid 'batch proposal' parameter - the batch is empty or exceeds the size limit.
+        InvalidBatchProposalSize,
 
-        /// Grace period for the 'set validator count' proposal
-        pub SetValidatorCountProposalGracePeriod get(fn set_validator_count_proposal_grace_period)
-            config(): T::BlockNumber;
+        /// Invalid 'batch proposal' parameter - the proposal cannot be batched.
+        UnsupportedBatchedProposal,
 
-        /// Voting period for the 'runtime upgrade' proposal
-        pub RuntimeUpgradeProposalVotingPeriod get(fn runtime_upgrade_proposal_voting_period)
-            config(): T::BlockNumber;
+        /// Invalid proposal amendment - the amended details must be of the same proposal type
+        /// with the same proposal parameters.
+        InvalidProposalAmendment,
 
-        /// Grace period for the 'runtime upgrade' proposal
-        pub RuntimeUpgradeProposalGracePeriod get(fn runtime_upgrade_proposal_grace_period)
-            config(): T::BlockNumber;
+        /// Invalid 'update proposal parameters' proposal parameter - the proposal type has no
+        /// proposal parameters.
+        InvalidProposalParametersProposalType,
 
-        /// Voting period for the 'set election parameters' proposal
-        pub SetElectionParametersProposalVotingPeriod get(fn set_election_parameters_proposal_voting_period)
-            config(): T::BlockNumber;
+        /// Invalid 'update proposal parameters' proposal parameter - invalid proposal parameters.
+        InvalidProposalParameters,
 
-        /// Grace period for the 'set election parameters' proposal
-        pub SetElectionParametersProposalGracePeriod get(fn set_election_parameters_proposal_grace_period)
-            config(): T::BlockNumber;
+        /// Invalid 'recurring grant' proposal parameter - invalid amount per payout.
+        InvalidRecurringGrantAmount,
 
-        /// Voting period for the 'text' proposal
-        pub TextProposalVotingPeriod get(fn text_proposal_voting_period) config(): T::BlockNumber;
+        /// Invalid 'recurring grant' proposal parameter - payout interval cannot be zero.
+        InvalidRecurringGrantPayoutInterval,
 
-        /// Grace period for the 'text' proposal
-        pub TextProposalGracePeriod get(fn text_proposal_grace_period) config(): T::BlockNumber;
+        /// Invalid 'recurring grant' proposal parameter - the grant must end after the first
+        /// payout.
+        InvalidRecurringGrantEndBlock,
 
-        /// Voting period for the 'set content working group mint capacity' proposal
-        pub SetContentWorkingGroupMintCapacityProposalVotingPeriod get(fn set_content_working_group_mint_capacity_proposal_voting_period)
-            config(): T::BlockNumber;
+        /// Invalid 'cancel recurring grant' proposal parameter - recurring grant not found.
+        InvalidRecurringGrantId,
 
-        /// Grace period for the 'set content working group mint capacity' proposal
-        pub SetContentWorkingGroupMintCapacityProposalGracePeriod get(fn set_content_working_group_mint_capacity_proposal_grace_period)
-            config(): T::BlockNumber;
+        /// Invalid 'set membership user info constraints' proposal parameter - min handle length
+        /// must be positive and not greater than max handle length, max lengths must be positive.
+        InvalidMembershipUserInfoConstraints,
 
-        /// Voting period for the 'set lead' proposal
-        pub SetLeadProposalVotingPeriod get(fn set_lead_proposal_voting_period)
-            config(): T::BlockNumber;
+        /// Invalid 'set forum input validation constraints' proposal parameter - min length of
+        /// the constraint must be positive and max length must fit in u16.
+        InvalidForumInputValidationConstraints,
 
-        /// Grace period for the 'set lead' proposal
-        pub SetLeadProposalGracePeriod get(fn set_lead_proposal_grace_period)
-            config(): T::BlockNumber;
+        /// Invalid 'set max memo length' proposal parameter - max memo length cannot be zero.
+        InvalidMaxMemoLength,
 
-        /// Voting period for the 'spending' proposal
-        pub SpendingProposalVotingPeriod get(fn spending_proposal_voting_period) config(): T::BlockNumber;
+        /// Invalid 'set service discovery default lifetime' proposal parameter - default lifetime
+        /// is less than the service discovery minimum lifetime.
+        InvalidServiceDiscoveryDefaultLifetime,
 
-        /// Grace period for the 'spending' proposal
-        pub SpendingProposalGracePeriod get(fn spending_proposal_grace_period) config(): T::BlockNumber;
+        /// Invalid data object type proposal parameter - description cannot be empty or exceed
+        /// the max length.
+        InvalidDataObjectTypeDescription,
 
-        /// Voting period for the 'add working group opening' proposal
-        pub AddWorkingGroupOpeningProposalVotingPeriod get(fn add_working_group_opening_proposal_voting_period) config(): T::BlockNumber;
+        /// Invalid 'set service discovery bootstrap endpoints' proposal parameter - too many
+        /// endpoints, or an endpoint is empty or exceeds the max length.
+        InvalidBootstrapEndpoints,
 
-        /// Grace period for the 'add working group opening' proposal
-        pub AddWorkingGroupOpeningProposalGracePeriod get(fn add_working_group_opening_proposal_grace_period) config(): T::BlockNumber;
+        /// Runtime upgrade preimage upload failed - the code hash is not proposed by the
+        /// 'runtime upgrade' proposal.
+        RuntimeUpgradeCodeHashNotProposed,
 
-        /// Voting period for the 'begin review working group leader applications' proposal
-        pub BeginReviewWorkingGroupLeaderApplicationsProposalVotingPeriod get(fn begin_review_working_group_leader_applications_proposal_voting_period) config(): T::BlockNumber;
+        /// Runtime upgrade preimage upload failed - the preimage is already uploaded.
+        RuntimeUpgradePreimageAlreadyUploaded,
 
-        /// Grace period for the 'begin review working group leader applications' proposal
-        pub BeginReviewWorkingGroupLeaderApplicationsProposalGracePeriod get(fn begin_review_working_group_leader_applications_proposal_grace_period) config(): T::BlockNumber;
+        /// Runtime upgrade preimage upload failed - cannot extract the runtime version from the
+        /// code or its `spec_name` doesn't match the current runtime.
+        InvalidRuntimeUpgradeCode,
 
-        /// Voting period for the 'fill working group leader opening' proposal
-        pub FillWorkingGroupLeaderOpeningProposalVotingPeriod get(fn fill_working_group_leader_opening_proposal_voting_period) config(): T::BlockNumber;
+        /// Runtime upgrade execution failed - the preimage of the code hash is not uploaded.
+        RuntimeUpgradePreimageNotFound,
 
-        /// Grace period for the 'fill working group leader opening' proposal
-        pub FillWorkingGroupLeaderOpeningProposalGracePeriod get(fn fill_working_group_leader_opening_proposal_grace_period) config(): T::BlockNumber;
+        /// Runtime upgrade preimage upload failed - insufficient balance for the preimage deposit.
+        InsufficientBalanceForPreimageDeposit,
 
-        /// Voting period for the 'set working group mint capacity' proposal
-        pub SetWorkingGroupMintCapacityProposalVotingPeriod get(fn set_working_group_mint_capacity_proposal_voting_period)
-            config(): T::BlockNumber;
+        /// Invalid council election parameter - candidacy_limit is lower than the size of the
+        /// active council.
+        CandidacyLimitLowerThanActiveCouncilSize,
 
-        /// Grace period for the 'set working group mint capacity' proposal
-        pub SetWorkingGroupMintCapacityProposalGracePeriod get(fn set_working_group_mint_capacity_proposal_grace_period)
-            config(): T::BlockNumber;
+        /// Invalid 'signal' proposal options - too few or too many options, or an option is empty
+        /// or exceeds the max length.
+        InvalidSignalProposalOptions,
 
-        /// Voting period for the 'decrease working group leader stake' proposal
-        pub DecreaseWorkingGroupLeaderStakeProposalVotingPeriod get(fn decrease_working_group_leader_stake_proposal_voting_period)
-            config(): T::BlockNumber;
+        /// Signal proposal with the provided id doesn't exist.
+        SignalProposalNotFound,
 
-        /// Grace period for the 'decrease working group leader stake' proposal
-        pub DecreaseWorkingGroupLeaderStakeProposalGracePeriod get(fn decrease_working_group_leader_stake_proposal_grace_period)
-            config(): T::BlockNumber;
+        /// Signal proposal voting period has expired.
+        SignalProposalVotingPeriodExpired,
 
-        /// Voting period for the 'slash working group leader stake' proposal
-        pub SlashWorkingGroupLeaderStakeProposalVotingPeriod get(fn slash_working_group_leader_stake_proposal_voting_period)
-            config(): T::BlockNumber;
+        /// Invalid signal proposal option index.
+        InvalidSignalProposalOption,
 
-        /// Grace period for the 'slash working group leader stake' proposal
-        pub SlashWorkingGroupLeaderStakeProposalGracePeriod get(fn slash_working_group_leader_stake_proposal_grace_period)
-            config(): T::BlockNumber;
+        /// The member has already voted on the signal proposal.
+        SignalProposalAlreadyVoted,
 
-        /// Voting period for the 'set working group leader reward' proposal
-        pub SetWorkingGroupLeaderRewardProposalVotingPeriod get(fn set_working_group_leader_reward_proposal_voting_period)
-            config(): T::BlockNumber;
+        /// Signal proposal creation failed - insufficient balance for the signal proposal deposit.
+        InsufficientBalanceForSignalProposalDeposit,
+    }
+}
 
-        /// Grace period for the 'set working group leader reward' proposal
-        pub SetWorkingGroupLeaderRewardProposalGracePeriod get(fn set_working_group_leader_reward_proposal_grace_period)
-            config(): T::BlockNumber;
+// Storage for the proposals codex module
+decl_storage! {
+    pub trait Store for Module<T: Trait> as ProposalCodex{
+        /// Map proposal id to its discussion thread id
+        pub ThreadIdByProposalId get(fn thread_id_by_proposal_id):
+            map hasher(blake2_128_concat) T::ProposalId => T::ThreadId;
 
-        /// Voting period for the 'terminate working group leader role' proposal
-        pub TerminateWorkingGroupLeaderRoleProposalVotingPeriod get(fn terminate_working_group_leader_role_proposal_voting_period)
-            config(): T::BlockNumber;
+        /// Map proposal id to proposal details
+        pub ProposalDetailsByProposalId get(fn proposal_details_by_proposal_id):
+            map hasher(blake2_128_concat) T::ProposalId => ProposalDetailsOf<T>;
 
-        /// Grace period for the 'terminate working group leader role' proposal
-        pub TerminateWorkingGroupLeaderRoleProposalGracePeriod get(fn terminate_working_group_leader_role_proposal_grace_period)
-            config(): T::BlockNumber;
+        /// Code hashes of the proposed runtime upgrades with the count of the pending proposals
+        /// referencing them. Only their preimages can be uploaded.
+        pub ProposedRuntimeUpgradeCodeHashes get(fn proposed_runtime_upgrade_code_hashes):
+            map hasher(blake2_128_concat) T::Hash => u32;
+
+        /// Map runtime upgrade code hash to the uploaded wasm code
+        pub RuntimeUpgradePreimages get(fn runtime_upgrade_preimage):
+            map hasher(blake2_128_concat) T::Hash => Vec<u8>;
+
+        /// Map runtime upgrade code hash to the uploader account and the reserved deposit
+        pub RuntimeUpgradePreimageDeposits get(fn runtime_upgrade_preimage_deposit):
+            map hasher(blake2_128_concat) T::Hash
+            => Option<(T::AccountId, BalanceOfGovernanceCurrency<T>)>;
+
+        /// Count of all signal proposals that have been created.
+        pub SignalProposalCount get(fn signal_proposal_count): SignalProposalId;
+
+        /// Map signal proposal id to the signal proposal
+        pub SignalProposals get(fn signal_proposals):
+            map hasher(blake2_128_concat) SignalProposalId => SignalProposalOf<T>;
+
+        /// Map signal proposal id and member id to the index of the option the member voted for
+        pub SignalProposalVoteByMember get(fn signal_proposal_vote_by_member):
+            double_map hasher(blake2_128_concat) SignalProposalId,
+            hasher(blake2_128_concat) MemberId<T> => Option<u32>;
+
+        /// Map signal proposal id to the proposer account and the reserved deposit
+        pub SignalProposalDeposits get(fn signal_proposal_deposit):
+            map hasher(blake2_128_concat) SignalProposalId
+            => Option<(T::AccountId, BalanceOfGovernanceCurrency<T>)>;
+
+        /// Ids of the signal proposals indexed by the block their voting period ends at
+        pub SignalProposalsByVotingEnd get(fn signal_proposals_by_voting_end):
+            double_map hasher(blake2_128_concat) T::BlockNumber,
+            hasher(blake2_128_concat) SignalProposalId => ();
+
+        /// Map proposal type to its proposal parameters
+        pub ProposalParametersByType get(fn proposal_parameters_by_type) config(proposal_parameters):
+            map hasher(blake2_128_concat) ProposalType => ProposalParameters<T::BlockNumber, BalanceOf<T>>;
     }
 }
 
+decl_event!(
+    /// Proposals codex events
+    pub enum Event<T>
+    where
+        MemberId = MemberId<T>,
+    {
+        /// Emits on the signal proposal creation.
+        /// Params:
+        /// - Member id of a proposer.
+        /// - Id of the created signal proposal.
+        SignalProposalCreated(MemberId, SignalProposalId),
+
+        /// Emits on voting for the option of the signal proposal.
+        /// Params:
+        /// - Member id of a voter.
+        /// - Id of the signal proposal.
+        /// - Index of the option.
+        SignalProposalVoted(MemberId, SignalProposalId, u32),
+
+        /// Emits on the end of the signal proposal voting period before the signal proposal
+        /// pruning.
+        /// Params:
+        /// - Id of the signal proposal.
+        /// - Vote count per option.
+        SignalProposalVotingEnded(SignalProposalId, Vec<u32>),
+    }
+);
+
 decl_module! {
     /// Proposal codex substrate module Call
     pub struct Module<T: Trait> for enum Call where origin: T::Origin {
         /// Predefined errors
         type Error = Error<T>;
 
+        /// Deposit event.
+        fn deposit_event() = default;
+
         /// Exports max allowed text proposal length const.
         const TextProposalMaxLength: u32 = T::TextProposalMaxLength::get();
 
         /// Exports max wasm code length of the runtime upgrade proposal const.
         const RuntimeUpgradeWasmProposalMaxLength: u32 = T::RuntimeUpgradeWasmProposalMaxLength::get();
 
+        /// Exports the deposit reserved per byte of the uploaded runtime upgrade code.
+        const RuntimeUpgradePreimageByteDeposit: BalanceOfGovernanceCurrency<T> =
+            T::RuntimeUpgradePreimageByteDeposit::get();
+
+        /// Exports the deposit of the signal proposal.
+        const SignalProposalDeposit: BalanceOfGovernanceCurrency<T> = T::SignalProposalDeposit::get();
+
+        /// Block finalization. Ends the voting of the signal proposals with the voting period
+        /// ending at the block.
+        fn on_finalize(now: T::BlockNumber) {
+            let signal_proposal_ids = <SignalProposalsByVotingEnd<T>>::iter_prefix(now)
+                .map(|(signal_proposal_id, _)| signal_proposal_id)
+                .collect::<Vec<_>>();
+
+            for signal_proposal_id in signal_proposal_ids {
+                Self::end_signal_proposal_voting(now, signal_proposal_id);
+            }
+        }
+
         /// Create 'Text (signal)' proposal type.
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn create_text_proposal(
@@ -397,28 +596,30 @@ decl_module! {
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
             text: Vec<u8>,
+            execution_schedule: Option<ExecutionSchedule<T::BlockNumber>>,
+            dependencies: Vec<T::ProposalId>,
         ) {
-            ensure!(!text.is_empty(), Error::<T>::TextProposalIsEmpty);
-            ensure!(text.len() as u32 <=  T::TextProposalMaxLength::get(),
-                Error::<T>::TextProposalSizeExceeded);
-
             let proposal_details = ProposalDetails::Text(text);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
             let params = CreateProposalParameters{
                 origin,
                 member_id,
                 title,
                 description,
                 stake_balance,
+                execution_schedule,
+                dependencies,
                 proposal_details: proposal_details.clone(),
-                proposal_parameters: proposal_types::parameters::text_proposal::<T>(),
+                proposal_parameters: proposal_types::parameters::proposal_parameters::<T>(TEXT_PROPOSAL_TYPE),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
             };
 
             Self::create_proposal(params)?;
         }
 
-        /// Create 'Runtime upgrade' proposal type. Runtime upgrade can be initiated only by
-        /// members from the hardcoded list `RuntimeUpgradeProposalAllowedProposers`
+        /// Create 'Runtime upgrade' proposal type. The proposal carries only the wasm code hash,
+        /// the code is uploaded separately using `upload_runtime_upgrade_preimage()`.
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn create_runtime_upgrade_proposal(
             origin,
@@ -426,29 +627,34 @@ decl_module! {
             title: Vec<u8>,
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
-            wasm: Vec<u8>,
+            wasm_hash: T::Hash,
+            execution_schedule: Option<ExecutionSchedule<T::BlockNumber>>,
+            dependencies: Vec<T::ProposalId>,
         ) {
-            ensure!(!wasm.is_empty(), Error::<T>::RuntimeProposalIsEmpty);
-            ensure!(wasm.len() as u32 <= T::RuntimeUpgradeWasmProposalMaxLength::get(),
-                Error::<T>::RuntimeProposalSizeExceeded);
+            let proposal_details = ProposalDetails::RuntimeUpgrade(wasm_hash);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
 
-            let proposal_details = ProposalDetails::RuntimeUpgrade(wasm);
             let params = CreateProposalParameters{
                 origin,
                 member_id,
                 title,
                 description,
                 stake_balance,
+                execution_schedule,
+                dependencies,
                 proposal_details: proposal_details.clone(),
-                proposal_parameters: proposal_types::parameters::runtime_upgrade_proposal::<T>(),
+                proposal_parameters: proposal_types::parameters::proposal_parameters::<T>(RUNTIME_UPGRADE_PROPOSAL_TYPE),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
             };
 
             Self::create_proposal(params)?;
         }
 
-        /// Create 'Set election parameters' proposal type. This proposal uses `set_election_parameters()`
-        /// extrinsic from the `governance::election module`.
+        /// Create 'Set election parameters' proposal type. This proposal uses
+        /// `execute_set_election_parameters_proposal()` extrinsic which revalidates the parameters
+        /// and applies them with `set_election_parameters()` of the `governance::election module` or
+        /// `set_election_parameters_at_next_election()` when the `apply_at_next_election` flag is
+        /// set or an election is running on the execution.
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn create_set_election_parameters_proposal(
             origin,
@@ -457,20 +663,24 @@ decl_module! {
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
             election_parameters: ElectionParameters<BalanceOfGovernanceCurrency<T>, T::BlockNumber>,
+            apply_at_next_election: bool,
+            execution_schedule: Option<ExecutionSchedule<T::BlockNumber>>,
+            dependencies: Vec<T::ProposalId>,
         ) {
-            election_parameters.ensure_valid()?;
+            let proposal_details =
+                ProposalDetails::SetElectionParameters(election_parameters, apply_at_next_election);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
 
-            Self::ensure_council_election_parameters_valid(&election_parameters)?;
-
-            let proposal_details = ProposalDetails::SetElectionParameters(election_parameters);
             let params = CreateProposalParameters{
                 origin,
                 member_id,
                 title,
                 description,
                 stake_balance,
+                execution_schedule,
+                dependencies,
                 proposal_details: proposal_details.clone(),
-                proposal_parameters: proposal_types::parameters::set_election_parameters_proposal::<T>(),
+                proposal_parameters: proposal_types::parameters::proposal_parameters::<T>(SET_ELECTION_PARAMETERS_PROPOSAL_TYPE),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
             };
 
@@ -487,21 +697,22 @@ decl_module! {
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
             mint_balance: BalanceOfMint<T>,
+            execution_schedule: Option<ExecutionSchedule<T::BlockNumber>>,
+            dependencies: Vec<T::ProposalId>,
         ) {
-            ensure!(
-                mint_balance <= <BalanceOfMint<T>>::from(CONTENT_WORKING_GROUP_MINT_CAPACITY_MAX_VALUE),
-                Error::<T>::InvalidContentWorkingGroupMintCapacity
-            );
-
             let proposal_details = ProposalDetails::SetContentWorkingGroupMintCapacity(mint_balance);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
             let params = CreateProposalParameters{
                 origin,
                 member_id,
                 title,
                 description,
                 stake_balance,
+                execution_schedule,
+                dependencies,
                 proposal_details: proposal_details.clone(),
-                proposal_parameters: proposal_types::parameters::set_content_working_group_mint_capacity_proposal::<T>(),
+                proposal_parameters: proposal_types::parameters::proposal_parameters::<T>(SET_CONTENT_WORKING_GROUP_MINT_CAPACITY_PROPOSAL_TYPE),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
             };
 
@@ -519,22 +730,86 @@ decl_module! {
             stake_balance: Option<BalanceOf<T>>,
             balance: BalanceOfMint<T>,
             destination: T::AccountId,
+            execution_schedule: Option<ExecutionSchedule<T::BlockNumber>>,
+            dependencies: Vec<T::ProposalId>,
         ) {
-            ensure!(balance != BalanceOfMint::<T>::zero(), Error::<T>::InvalidSpendingProposalBalance);
-            ensure!(
-                balance <= <BalanceOfMint<T>>::from(MAX_SPENDING_PROPOSAL_VALUE),
-                Error::<T>::InvalidSpendingProposalBalance
-            );
-
             let proposal_details = ProposalDetails::Spending(balance, destination);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                execution_schedule,
+                dependencies,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters: proposal_types::parameters::spending_proposal_for_balance::<T>(balance),
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
+
+            Self::create_proposal(params)?;
+        }
+
+        /// Create 'Recurring grant' proposal type.
+        /// This proposal uses `create_recurring_grant()` extrinsic from the `governance::council`  module.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_recurring_grant_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            recurring_grant_parameters: RecurringGrantParameters<T::AccountId, BalanceOfMint<T>, T::BlockNumber>,
+            execution_schedule: Option<ExecutionSchedule<T::BlockNumber>>,
+            dependencies: Vec<T::ProposalId>,
+        ) {
+            let proposal_details = ProposalDetails::RecurringGrant(recurring_grant_parameters.clone());
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                execution_schedule,
+                dependencies,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters: proposal_types::parameters::recurring_grant_proposal::<T>(&recurring_grant_parameters),
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
+
+            Self::create_proposal(params)?;
+        }
+
+        /// Create 'Cancel recurring grant' proposal type.
+        /// This proposal uses `cancel_recurring_grant()` extrinsic from the `governance::council`  module.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_cancel_recurring_grant_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            grant_id: RecurringGrantId,
+            execution_schedule: Option<ExecutionSchedule<T::BlockNumber>>,
+            dependencies: Vec<T::ProposalId>,
+        ) {
+            let proposal_details = ProposalDetails::CancelRecurringGrant(grant_id);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
             let params = CreateProposalParameters{
                 origin,
                 member_id,
                 title,
                 description,
                 stake_balance,
+                execution_schedule,
+                dependencies,
                 proposal_details: proposal_details.clone(),
-                proposal_parameters: proposal_types::parameters::spending_proposal::<T>(),
+                proposal_parameters: proposal_types::parameters::proposal_parameters::<T>(CANCEL_RECURRING_GRANT_PROPOSAL_TYPE),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
             };
 
@@ -550,24 +825,23 @@ decl_module! {
             title: Vec<u8>,
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
-            new_lead: Option<(T::MemberId, T::AccountId)>
+            new_lead: Option<(T::MemberId, T::AccountId)>,
+            execution_schedule: Option<ExecutionSchedule<T::BlockNumber>>,
+            dependencies: Vec<T::ProposalId>,
         ) {
-            if let Some(lead) = new_lead.clone() {
-                let account_id = lead.1;
-                ensure!(
-                    !<governance::council::Module<T>>::is_councilor(&account_id),
-                    Error::<T>::InvalidSetLeadParameterCannotBeCouncilor
-                );
-            }
             let proposal_details = ProposalDetails::SetLead(new_lead);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
             let params = CreateProposalParameters{
                 origin,
                 member_id,
                 title,
                 description,
                 stake_balance,
+                execution_schedule,
+                dependencies,
                 proposal_details: proposal_details.clone(),
-                proposal_parameters: proposal_types::parameters::set_lead_proposal::<T>(),
+                proposal_parameters: proposal_types::parameters::proposal_parameters::<T>(SET_LEAD_PROPOSAL_TYPE),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
             };
 
@@ -584,26 +858,22 @@ decl_module! {
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
             new_validator_count: u32,
+            execution_schedule: Option<ExecutionSchedule<T::BlockNumber>>,
+            dependencies: Vec<T::ProposalId>,
         ) {
-            ensure!(
-                new_validator_count >= <staking::Module<T>>::minimum_validator_count(),
-                Error::<T>::InvalidValidatorCount
-            );
-
-            ensure!(
-                new_validator_count <= MAX_VALIDATOR_COUNT,
-                Error::<T>::InvalidValidatorCount
-            );
-
             let proposal_details = ProposalDetails::SetValidatorCount(new_validator_count);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
             let params = CreateProposalParameters{
                 origin,
                 member_id,
                 title,
                 description,
                 stake_balance,
+                execution_schedule,
+                dependencies,
                 proposal_details: proposal_details.clone(),
-                proposal_parameters: proposal_types::parameters::set_validator_count_proposal::<T>(),
+                proposal_parameters: proposal_types::parameters::proposal_parameters::<T>(SET_VALIDATOR_COUNT_PROPOSAL_TYPE),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
             };
 
@@ -620,17 +890,22 @@ decl_module! {
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
             add_opening_parameters: AddOpeningParameters<T::BlockNumber, BalanceOfGovernanceCurrency<T>>,
+            execution_schedule: Option<ExecutionSchedule<T::BlockNumber>>,
+            dependencies: Vec<T::ProposalId>,
         ) {
-
             let proposal_details = ProposalDetails::AddWorkingGroupLeaderOpening(add_opening_parameters);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
             let params = CreateProposalParameters{
                 origin,
                 member_id,
                 title,
                 description,
                 stake_balance,
+                execution_schedule,
+                dependencies,
                 proposal_details: proposal_details.clone(),
-                proposal_parameters: proposal_types::parameters::add_working_group_leader_opening_proposal::<T>(),
+                proposal_parameters: proposal_types::parameters::proposal_parameters::<T>(ADD_WORKING_GROUP_LEADER_OPENING_PROPOSAL_TYPE),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
             };
 
@@ -648,17 +923,22 @@ decl_module! {
             stake_balance: Option<BalanceOf<T>>,
             opening_id: working_group::OpeningId<T>,
             working_group: WorkingGroup,
+            execution_schedule: Option<ExecutionSchedule<T::BlockNumber>>,
+            dependencies: Vec<T::ProposalId>,
         ) {
-
             let proposal_details = ProposalDetails::BeginReviewWorkingGroupLeaderApplications(opening_id, working_group);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
             let params = CreateProposalParameters{
                 origin,
                 member_id,
                 title,
                 description,
                 stake_balance,
+                execution_schedule,
+                dependencies,
                 proposal_details: proposal_details.clone(),
-                proposal_parameters: proposal_types::parameters::begin_review_working_group_leader_applications_proposal::<T>(),
+                proposal_parameters: proposal_types::parameters::proposal_parameters::<T>(BEGIN_REVIEW_WORKING_GROUP_LEADER_APPLICATIONS_PROPOSAL_TYPE),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
             };
 
@@ -679,18 +959,23 @@ decl_module! {
                 BalanceOfMint<T>,
                 working_group::OpeningId<T>,
                 working_group::ApplicationId<T>
-            >
+            >,
+            execution_schedule: Option<ExecutionSchedule<T::BlockNumber>>,
+            dependencies: Vec<T::ProposalId>,
         ) {
-
             let proposal_details = ProposalDetails::FillWorkingGroupLeaderOpening(fill_opening_parameters);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
             let params = CreateProposalParameters{
                 origin,
                 member_id,
                 title,
                 description,
                 stake_balance,
+                execution_schedule,
+                dependencies,
                 proposal_details: proposal_details.clone(),
-                proposal_parameters: proposal_types::parameters::fill_working_group_leader_opening_proposal::<T>(),
+                proposal_parameters: proposal_types::parameters::proposal_parameters::<T>(FILL_WORKING_GROUP_LEADER_OPENING_PROPOSAL_TYPE),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
             };
 
@@ -708,21 +993,22 @@ decl_module! {
             stake_balance: Option<BalanceOf<T>>,
             mint_balance: BalanceOfMint<T>,
             working_group: WorkingGroup,
+            execution_schedule: Option<ExecutionSchedule<T::BlockNumber>>,
+            dependencies: Vec<T::ProposalId>,
         ) {
-            ensure!(
-                mint_balance <= <BalanceOfMint<T>>::from(WORKING_GROUP_MINT_CAPACITY_MAX_VALUE),
-                Error::<T>::InvalidWorkingGroupMintCapacity
-            );
-
             let proposal_details = ProposalDetails::SetWorkingGroupMintCapacity(mint_balance, working_group);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
             let params = CreateProposalParameters{
                 origin,
                 member_id,
                 title,
                 description,
                 stake_balance,
+                execution_schedule,
+                dependencies,
                 proposal_details: proposal_details.clone(),
-                proposal_parameters: proposal_types::parameters::set_working_group_mint_capacity_proposal::<T>(),
+                proposal_parameters: proposal_types::parameters::proposal_parameters::<T>(SET_WORKING_GROUP_MINT_CAPACITY_PROPOSAL_TYPE),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
             };
 
@@ -741,15 +1027,15 @@ decl_module! {
             worker_id: working_group::WorkerId<T>,
             decreasing_stake: BalanceOf<T>,
             working_group: WorkingGroup,
+            execution_schedule: Option<ExecutionSchedule<T::BlockNumber>>,
+            dependencies: Vec<T::ProposalId>,
         ) {
-
-            ensure!(decreasing_stake != Zero::zero(), Error::<T>::DecreasingStakeIsZero);
-
             let proposal_details = ProposalDetails::DecreaseWorkingGroupLeaderStake(
                 worker_id,
                 decreasing_stake,
                 working_group
             );
+            Self::ensure_proposal_details_valid(&proposal_details)?;
 
             let params = CreateProposalParameters{
                 origin,
@@ -757,8 +1043,10 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
+                execution_schedule,
+                dependencies,
                 proposal_details: proposal_details.clone(),
-                proposal_parameters: proposal_types::parameters::decrease_working_group_leader_stake_proposal::<T>(),
+                proposal_parameters: proposal_types::parameters::proposal_parameters::<T>(DECREASE_WORKING_GROUP_LEADER_STAKE_PROPOSAL_TYPE),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
             };
 
@@ -777,15 +1065,15 @@ decl_module! {
             worker_id: working_group::WorkerId<T>,
             slashing_stake: BalanceOf<T>,
             working_group: WorkingGroup,
+            execution_schedule: Option<ExecutionSchedule<T::BlockNumber>>,
+            dependencies: Vec<T::ProposalId>,
         ) {
-
-            ensure!(slashing_stake != Zero::zero(), Error::<T>::SlashingStakeIsZero);
-
             let proposal_details = ProposalDetails::SlashWorkingGroupLeaderStake(
                 worker_id,
                 slashing_stake,
                 working_group
             );
+            Self::ensure_proposal_details_valid(&proposal_details)?;
 
             let params = CreateProposalParameters{
                 origin,
@@ -793,8 +1081,10 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
+                execution_schedule,
+                dependencies,
                 proposal_details: proposal_details.clone(),
-                proposal_parameters: proposal_types::parameters::slash_working_group_leader_stake_proposal::<T>(),
+                proposal_parameters: proposal_types::parameters::proposal_parameters::<T>(SLASH_WORKING_GROUP_LEADER_STAKE_PROPOSAL_TYPE),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
             };
 
@@ -813,13 +1103,15 @@ decl_module! {
             worker_id: working_group::WorkerId<T>,
             reward_amount: BalanceOfMint<T>,
             working_group: WorkingGroup,
+            execution_schedule: Option<ExecutionSchedule<T::BlockNumber>>,
+            dependencies: Vec<T::ProposalId>,
         ) {
-
             let proposal_details = ProposalDetails::SetWorkingGroupLeaderReward(
                 worker_id,
                 reward_amount,
                 working_group
             );
+            Self::ensure_proposal_details_valid(&proposal_details)?;
 
             let params = CreateProposalParameters{
                 origin,
@@ -827,8 +1119,10 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
+                execution_schedule,
+                dependencies,
                 proposal_details: proposal_details.clone(),
-                proposal_parameters: proposal_types::parameters::set_working_group_leader_reward_proposal::<T>(),
+                proposal_parameters: proposal_types::parameters::proposal_parameters::<T>(SET_WORKING_GROUP_LEADER_REWARD_PROPOSAL_TYPE),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
             };
 
@@ -845,8 +1139,11 @@ decl_module! {
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
             terminate_role_parameters: TerminateRoleParameters<working_group::WorkerId<T>>,
+            execution_schedule: Option<ExecutionSchedule<T::BlockNumber>>,
+            dependencies: Vec<T::ProposalId>,
         ) {
             let proposal_details = ProposalDetails::TerminateWorkingGroupLeaderRole(terminate_role_parameters);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
 
             let params = CreateProposalParameters{
                 origin,
@@ -854,93 +1151,1204 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
+                execution_schedule,
+                dependencies,
                 proposal_details: proposal_details.clone(),
-                proposal_parameters: proposal_types::parameters::terminate_working_group_leader_role_proposal::<T>(),
+                proposal_parameters: proposal_types::parameters::proposal_parameters::<T>(TERMINATE_WORKING_GROUP_LEADER_ROLE_PROPOSAL_TYPE),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
             };
 
             Self::create_proposal(params)?;
         }
 
-
-// *************** Extrinsic to execute
-
-        /// Text proposal extrinsic. Should be used as callable object to pass to the `engine` module.
+        /// Create 'Set new memberships allowed' proposal type.
+        /// This proposal uses `set_new_memberships_allowed()` extrinsic from the `membership`  module.
         #[weight = 10_000_000] // TODO: adjust weight
-        pub fn execute_text_proposal(
+        pub fn create_set_new_memberships_allowed_proposal(
             origin,
-            text: Vec<u8>,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            new_memberships_allowed: bool,
+            execution_schedule: Option<ExecutionSchedule<T::BlockNumber>>,
+            dependencies: Vec<T::ProposalId>,
         ) {
-            ensure_root(origin)?;
-            print("Text proposal: ");
-            let text_string_result = from_utf8(text.as_slice());
-            if let Ok(text_string) = text_string_result{
-                print(text_string);
-            }
+            let proposal_details = ProposalDetails::SetNewMembershipsAllowed(new_memberships_allowed);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                execution_schedule,
+                dependencies,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters: proposal_types::parameters::proposal_parameters::<T>(SET_NEW_MEMBERSHIPS_ALLOWED_PROPOSAL_TYPE),
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
+
+            Self::create_proposal(params)?;
         }
 
-        /// Runtime upgrade proposal extrinsic.
-        /// Should be used as callable object to pass to the `engine` module.
+        /// Create 'Set membership user info constraints' proposal type.
+        /// This proposal uses `set_user_info_constraints()` extrinsic from the `membership`  module.
         #[weight = 10_000_000] // TODO: adjust weight
-        pub fn execute_runtime_upgrade_proposal(
+        pub fn create_set_membership_user_info_constraints_proposal(
             origin,
-            wasm: Vec<u8>,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            user_info_constraints: MembershipUserInfoConstraints,
+            execution_schedule: Option<ExecutionSchedule<T::BlockNumber>>,
+            dependencies: Vec<T::ProposalId>,
         ) {
-            let (cloned_origin1, cloned_origin2) = common::origin::double_origin::<T>(origin);
-            ensure_root(cloned_origin1)?;
-
-            print("Runtime upgrade proposal execution started.");
+            let proposal_details = ProposalDetails::SetMembershipUserInfoConstraints(user_info_constraints);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
 
-            <system::Module<T>>::set_code(cloned_origin2, wasm)?;
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                execution_schedule,
+                dependencies,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters: proposal_types::parameters::proposal_parameters::<T>(SET_MEMBERSHIP_USER_INFO_CONSTRAINTS_PROPOSAL_TYPE),
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
 
-            print("Runtime upgrade proposal execution finished.");
+            Self::create_proposal(params)?;
         }
-    }
-}
 
-impl<T: Trait> Module<T> {
-    // Generic template proposal builder
-    fn create_proposal(params: CreateProposalParameters<T>) -> DispatchResult {
-        let account_id =
-            T::MembershipOriginValidator::ensure_actor_origin(params.origin, params.member_id)?;
+        /// Create 'Set membership screening authority' proposal type.
+        /// This proposal uses `set_screening_authority()` extrinsic from the `membership`  module.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_set_membership_screening_authority_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            screening_authority: T::AccountId,
+            execution_schedule: Option<ExecutionSchedule<T::BlockNumber>>,
+            dependencies: Vec<T::ProposalId>,
+        ) {
+            let proposal_details = ProposalDetails::SetMembershipScreeningAuthority(screening_authority);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
 
-        <proposals_engine::Module<T>>::ensure_create_proposal_parameters_are_valid(
-            &params.proposal_parameters,
-            &params.title,
-            &params.description,
-            params.stake_balance,
-        )?;
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                execution_schedule,
+                dependencies,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters: proposal_types::parameters::proposal_parameters::<T>(SET_MEMBERSHIP_SCREENING_AUTHORITY_PROPOSAL_TYPE),
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
 
-        <proposals_discussion::Module<T>>::ensure_can_create_thread(
-            params.member_id,
-            &params.title,
-        )?;
+            Self::create_proposal(params)?;
+        }
 
-        let discussion_thread_id = <proposals_discussion::Module<T>>::create_thread(
-            params.member_id,
-            params.title.clone(),
-        )?;
+        /// Create 'Set forum sudo' proposal type.
+        /// This proposal uses `set_forum_sudo()` extrinsic from the `forum`  module.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_set_forum_sudo_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            forum_sudo: Option<T::AccountId>,
+            execution_schedule: Option<ExecutionSchedule<T::BlockNumber>>,
+            dependencies: Vec<T::ProposalId>,
+        ) {
+            let proposal_details = ProposalDetails::SetForumSudo(forum_sudo);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
 
-        let proposal_id = <proposals_engine::Module<T>>::create_proposal(
-            account_id,
-            params.member_id,
-            params.proposal_parameters,
-            params.title,
-            params.description,
-            params.stake_balance,
-            params.proposal_code,
-        )?;
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                execution_schedule,
+                dependencies,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters: proposal_types::parameters::proposal_parameters::<T>(SET_FORUM_SUDO_PROPOSAL_TYPE),
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
 
-        <ThreadIdByProposalId<T>>::insert(proposal_id, discussion_thread_id);
-        <ProposalDetailsByProposalId<T>>::insert(proposal_id, params.proposal_details);
+            Self::create_proposal(params)?;
+        }
 
-        Ok(())
-    }
+        /// Create 'Set forum input validation constraints' proposal type.
+        /// This proposal uses `set_input_validation_constraints()` extrinsic from the `forum`  module.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_set_forum_input_validation_constraints_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            input_validation_constraints: ForumInputValidationConstraints,
+            execution_schedule: Option<ExecutionSchedule<T::BlockNumber>>,
+            dependencies: Vec<T::ProposalId>,
+        ) {
+            let proposal_details = ProposalDetails::SetForumInputValidationConstraints(input_validation_constraints);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
 
-    // validates council election parameters for the 'Set election parameters' proposal
-    pub(crate) fn ensure_council_election_parameters_valid(
-        election_parameters: &ElectionParameters<BalanceOfGovernanceCurrency<T>, T::BlockNumber>,
-    ) -> DispatchResult {
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                execution_schedule,
+                dependencies,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters: proposal_types::parameters::proposal_parameters::<T>(SET_FORUM_INPUT_VALIDATION_CONSTRAINTS_PROPOSAL_TYPE),
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
+
+            Self::create_proposal(params)?;
+        }
+
+        /// Create 'Set max memo length' proposal type.
+        /// This proposal uses `set_max_memo_length()` extrinsic from the `memo`  module.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_set_max_memo_length_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            max_memo_length: u32,
+            execution_schedule: Option<ExecutionSchedule<T::BlockNumber>>,
+            dependencies: Vec<T::ProposalId>,
+        ) {
+            let proposal_details = ProposalDetails::SetMaxMemoLength(max_memo_length);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                execution_schedule,
+                dependencies,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters: proposal_types::parameters::proposal_parameters::<T>(SET_MAX_MEMO_LENGTH_PROPOSAL_TYPE),
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
+
+            Self::create_proposal(params)?;
+        }
+
+        /// Create 'Set service discovery default lifetime' proposal type.
+        /// This proposal uses `set_default_lifetime()` extrinsic from the `service_discovery`  module.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_set_service_discovery_default_lifetime_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            default_lifetime: T::BlockNumber,
+            execution_schedule: Option<ExecutionSchedule<T::BlockNumber>>,
+            dependencies: Vec<T::ProposalId>,
+        ) {
+            let proposal_details = ProposalDetails::SetServiceDiscoveryDefaultLifetime(default_lifetime);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                execution_schedule,
+                dependencies,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters: proposal_types::parameters::proposal_parameters::<T>(SET_SERVICE_DISCOVERY_DEFAULT_LIFETIME_PROPOSAL_TYPE),
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
+
+            Self::create_proposal(params)?;
+        }
+
+        /// Create 'Register data object type' proposal type.
+        /// This proposal uses `register_data_object_type()` extrinsic from the `data_object_type_registry`  module.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_register_data_object_type_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            data_object_type: DataObjectTypeParameters,
+            execution_schedule: Option<ExecutionSchedule<T::BlockNumber>>,
+            dependencies: Vec<T::ProposalId>,
+        ) {
+            let proposal_details = ProposalDetails::RegisterDataObjectType(data_object_type);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                execution_schedule,
+                dependencies,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters: proposal_types::parameters::proposal_parameters::<T>(REGISTER_DATA_OBJECT_TYPE_PROPOSAL_TYPE),
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
+
+            Self::create_proposal(params)?;
+        }
+
+        /// Create 'Update data object type' proposal type.
+        /// This proposal uses `update_data_object_type()` extrinsic from the `data_object_type_registry`  module.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_update_data_object_type_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            data_object_type_id: DataObjectTypeId,
+            data_object_type: DataObjectTypeParameters,
+            execution_schedule: Option<ExecutionSchedule<T::BlockNumber>>,
+            dependencies: Vec<T::ProposalId>,
+        ) {
+            let proposal_details = ProposalDetails::UpdateDataObjectType(data_object_type_id, data_object_type);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                execution_schedule,
+                dependencies,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters: proposal_types::parameters::proposal_parameters::<T>(UPDATE_DATA_OBJECT_TYPE_PROPOSAL_TYPE),
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
+
+            Self::create_proposal(params)?;
+        }
+
+        /// Create 'Activate data object type' proposal type.
+        /// This proposal uses `activate_data_object_type()` extrinsic from the `data_object_type_registry`  module.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_activate_data_object_type_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            data_object_type_id: DataObjectTypeId,
+            execution_schedule: Option<ExecutionSchedule<T::BlockNumber>>,
+            dependencies: Vec<T::ProposalId>,
+        ) {
+            let proposal_details = ProposalDetails::ActivateDataObjectType(data_object_type_id);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                execution_schedule,
+                dependencies,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters: proposal_types::parameters::proposal_parameters::<T>(ACTIVATE_DATA_OBJECT_TYPE_PROPOSAL_TYPE),
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
+
+            Self::create_proposal(params)?;
+        }
+
+        /// Create 'Deactivate data object type' proposal type.
+        /// This proposal uses `deactivate_data_object_type()` extrinsic from the `data_object_type_registry`  module.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_deactivate_data_object_type_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            data_object_type_id: DataObjectTypeId,
+            execution_schedule: Option<ExecutionSchedule<T::BlockNumber>>,
+            dependencies: Vec<T::ProposalId>,
+        ) {
+            let proposal_details = ProposalDetails::DeactivateDataObjectType(data_object_type_id);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                execution_schedule,
+                dependencies,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters: proposal_types::parameters::proposal_parameters::<T>(DEACTIVATE_DATA_OBJECT_TYPE_PROPOSAL_TYPE),
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
+
+            Self::create_proposal(params)?;
+        }
+
+        /// Create 'Set service discovery bootstrap endpoints' proposal type.
+        /// This proposal uses `set_bootstrap_endpoints()` extrinsic from the `service_discovery`  module.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_set_service_discovery_bootstrap_endpoints_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            endpoints: Vec<Vec<u8>>,
+            execution_schedule: Option<ExecutionSchedule<T::BlockNumber>>,
+            dependencies: Vec<T::ProposalId>,
+        ) {
+            let proposal_details = ProposalDetails::SetServiceDiscoveryBootstrapEndpoints(endpoints);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                execution_schedule,
+                dependencies,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters: proposal_types::parameters::proposal_parameters::<T>(SET_SERVICE_DISCOVERY_BOOTSTRAP_ENDPOINTS_PROPOSAL_TYPE),
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
+
+            Self::create_proposal(params)?;
+        }
+
+        /// Create 'Update proposal parameters' proposal type. This proposal uses `set()` for the
+        /// proposal parameters of the provided proposal type.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_update_proposal_parameters_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            proposal_type: ProposalType,
+            proposal_parameters: ProposalParameters<T::BlockNumber, BalanceOf<T>>,
+            execution_schedule: Option<ExecutionSchedule<T::BlockNumber>>,
+            dependencies: Vec<T::ProposalId>,
+        ) {
+            let proposal_details =
+                ProposalDetails::UpdateProposalParameters(proposal_type, proposal_parameters);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                execution_schedule,
+                dependencies,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters: proposal_types::parameters::proposal_parameters::<T>(UPDATE_PROPOSAL_PARAMETERS_PROPOSAL_TYPE),
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
+
+            Self::create_proposal(params)?;
+        }
+
+        /// Create 'Batch' proposal type. The batched proposals are executed atomically: all of
+        /// them or none. The strictest parameters of the batched proposals are used for voting.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_batch_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            batched_proposal_details: Vec<ProposalDetailsOf<T>>,
+            execution_schedule: Option<ExecutionSchedule<T::BlockNumber>>,
+            dependencies: Vec<T::ProposalId>,
+        ) {
+            let proposal_parameters =
+                proposal_types::parameters::batch_proposal::<T>(&batched_proposal_details);
+
+            let proposal_details = ProposalDetails::Batch(batched_proposal_details);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                execution_schedule,
+                dependencies,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters: proposal_parameters.ok_or(Error::<T>::UnsupportedBatchedProposal)?,
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
+
+            Self::create_proposal(params)?;
+        }
+
+        /// Amends the title, description and details of the active proposal by its proposer.
+        /// The amended details must be of the same proposal type with the same parameters.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn amend_proposal(
+            origin,
+            member_id: MemberId<T>,
+            proposal_id: T::ProposalId,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            proposal_details: ProposalDetailsOf<T>,
+        ) {
+            T::MembershipOriginValidator::ensure_actor_origin(origin, member_id)?;
+
+            <proposals_engine::Module<T>>::ensure_can_amend_proposal(
+                member_id,
+                proposal_id,
+                &title,
+                &description,
+            )?;
+
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
+            let previous_proposal_details = Self::proposal_details_by_proposal_id(proposal_id);
+            let proposal_parameters =
+                <proposals_engine::Module<T>>::proposals(proposal_id).parameters;
+
+            let same_proposal_type =
+                mem::discriminant(&previous_proposal_details) == mem::discriminant(&proposal_details);
+            ensure!(
+                same_proposal_type
+                    && Self::proposal_parameters(&proposal_details) == Some(proposal_parameters),
+                Error::<T>::InvalidProposalAmendment
+            );
+
+            let thread_id = Self::thread_id_by_proposal_id(proposal_id);
+            <proposals_discussion::Module<T>>::ensure_can_update_thread_title(thread_id, &title)?;
+
+            let proposal_code = T::ProposalEncoder::encode_proposal(proposal_details.clone());
+
+            <proposals_engine::Module<T>>::amend_proposal(
+                member_id,
+                proposal_id,
+                title.clone(),
+                description,
+                proposal_code,
+            )?;
+
+            <proposals_discussion::Module<T>>::update_thread_title(thread_id, title)?;
+
+            // Note the new code hashes before releasing the previous ones to keep the preimage of
+            // the code hash present in both versions.
+            Self::note_runtime_upgrade_code_hashes(&proposal_details);
+            Self::release_runtime_upgrade_code_hashes(&previous_proposal_details);
+
+            <ProposalDetailsByProposalId<T>>::insert(proposal_id, proposal_details);
+        }
+
+        /// Uploads the wasm code of the proposed runtime upgrade. Anyone can upload the code of
+        /// the code hash proposed by the 'runtime upgrade' proposal. The uploader reserves a
+        /// deposit proportional to the code length, it is refunded on the preimage removal.
+        #[weight = 10_000_000 + 1_000 * wasm.len() as Weight] // TODO: adjust weight
+        pub fn upload_runtime_upgrade_preimage(origin, wasm: Vec<u8>) {
+            let account_id = ensure_signed(origin)?;
+
+            let wasm_hash = T::Hashing::hash(&wasm);
+
+            ensure!(
+                <ProposedRuntimeUpgradeCodeHashes<T>>::contains_key(wasm_hash),
+                Error::<T>::RuntimeUpgradeCodeHashNotProposed
+            );
+
+            ensure!(
+                !<RuntimeUpgradePreimages<T>>::contains_key(wasm_hash),
+                Error::<T>::RuntimeUpgradePreimageAlreadyUploaded
+            );
+
+            Self::ensure_runtime_upgrade_code_valid(&wasm)?;
+
+            let deposit = T::RuntimeUpgradePreimageByteDeposit::get()
+                .saturating_mul((wasm.len() as u32).into());
+
+            ensure!(
+                <T as GovernanceCurrency>::Currency::can_reserve(&account_id, deposit),
+                Error::<T>::InsufficientBalanceForPreimageDeposit
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <T as GovernanceCurrency>::Currency::reserve(&account_id, deposit)?;
+
+            <RuntimeUpgradePreimageDeposits<T>>::insert(wasm_hash, (account_id, deposit));
+            <RuntimeUpgradePreimages<T>>::insert(wasm_hash, wasm);
+        }
+
+        /// Create 'Signal' proposal: a non-binding poll with multiple options. Signal proposals
+        /// don't require a stake, are not voted by the council and are not executed. The proposer
+        /// reserves a deposit refunded at the end of the voting period.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_signal_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            options: Vec<Vec<u8>>,
+        ) {
+            let account_id = T::MembershipOriginValidator::ensure_actor_origin(origin, member_id)?;
+
+            <proposals_engine::Module<T>>::ensure_title_and_description_valid(
+                &title,
+                &description,
+            )?;
+
+            <proposals_engine::Module<T>>::ensure_active_proposal_limits_not_exceeded(
+                member_id,
+                SIGNAL_PROPOSAL_TYPE,
+            )?;
+
+            Self::ensure_signal_proposal_options_valid(&options)?;
+
+            <proposals_discussion::Module<T>>::ensure_can_create_thread(member_id, &title)?;
+
+            let deposit = T::SignalProposalDeposit::get();
+            ensure!(
+                <T as GovernanceCurrency>::Currency::can_reserve(&account_id, deposit),
+                Error::<T>::InsufficientBalanceForSignalProposalDeposit
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <T as GovernanceCurrency>::Currency::reserve(&account_id, deposit)?;
+
+            let thread_id = <proposals_discussion::Module<T>>::create_thread(member_id, title.clone())?;
+
+            let voting_period =
+                proposal_types::parameters::proposal_parameters::<T>(SIGNAL_PROPOSAL_TYPE).voting_period;
+            let created_at = <system::Module<T>>::block_number();
+
+            let signal_proposal_id = Self::signal_proposal_count() + 1;
+            SignalProposalCount::put(signal_proposal_id);
+
+            <SignalProposals<T>>::insert(signal_proposal_id, SignalProposal {
+                proposer_id: member_id,
+                title,
+                description,
+                results: vec![0; options.len()],
+                options,
+                created_at,
+                voting_ends_at: created_at + voting_period,
+                thread_id,
+            });
+
+            <SignalProposalDeposits<T>>::insert(signal_proposal_id, (account_id, deposit));
+            <SignalProposalsByVotingEnd<T>>::insert(created_at + voting_period, signal_proposal_id, ());
+
+            <proposals_engine::Module<T>>::increase_active_proposal_counter(
+                member_id,
+                SIGNAL_PROPOSAL_TYPE,
+            );
+
+            Self::deposit_event(RawEvent::SignalProposalCreated(member_id, signal_proposal_id));
+        }
+
+        /// Vote for the option of the signal proposal. Any member can vote once during the voting
+        /// period.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn vote_on_signal_proposal(
+            origin,
+            member_id: MemberId<T>,
+            signal_proposal_id: SignalProposalId,
+            option_index: u32,
+        ) {
+            T::MembershipOriginValidator::ensure_actor_origin(origin, member_id)?;
+
+            ensure!(
+                <SignalProposals<T>>::contains_key(signal_proposal_id),
+                Error::<T>::SignalProposalNotFound
+            );
+
+            let mut signal_proposal = Self::signal_proposals(signal_proposal_id);
+
+            ensure!(
+                <system::Module<T>>::block_number() < signal_proposal.voting_ends_at,
+                Error::<T>::SignalProposalVotingPeriodExpired
+            );
+
+            ensure!(
+                (option_index as usize) < signal_proposal.options.len(),
+                Error::<T>::InvalidSignalProposalOption
+            );
+
+            ensure!(
+                !<SignalProposalVoteByMember<T>>::contains_key(signal_proposal_id, member_id),
+                Error::<T>::SignalProposalAlreadyVoted
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let votes = &mut signal_proposal.results[option_index as usize];
+            *votes = votes.saturating_add(1);
+
+            <SignalProposals<T>>::insert(signal_proposal_id, signal_proposal);
+            <SignalProposalVoteByMember<T>>::insert(signal_proposal_id, member_id, option_index);
+
+            Self::deposit_event(RawEvent::SignalProposalVoted(
+                member_id,
+                signal_proposal_id,
+                option_index,
+            ));
+        }
+
+// *************** Extrinsic to execute
+
+        /// Text proposal extrinsic. Should be used as callable object to pass to the `engine` module.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn execute_text_proposal(
+            origin,
+            text: Vec<u8>,
+        ) {
+            ensure_root(origin)?;
+            print("Text proposal: ");
+            let text_string_result = from_utf8(text.as_slice());
+            if let Ok(text_string) = text_string_result{
+                print(text_string);
+            }
+        }
+
+        /// Runtime upgrade proposal extrinsic.
+        /// Should be used as callable object to pass to the `engine` module.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn execute_runtime_upgrade_proposal(
+            origin,
+            wasm_hash: T::Hash,
+        ) {
+            let (cloned_origin1, cloned_origin2) = common::origin::double_origin::<T>(origin);
+            ensure_root(cloned_origin1)?;
+
+            ensure!(
+                <RuntimeUpgradePreimages<T>>::contains_key(wasm_hash),
+                Error::<T>::RuntimeUpgradePreimageNotFound
+            );
+
+            let wasm = Self::runtime_upgrade_preimage(wasm_hash);
+
+            print("Runtime upgrade proposal execution started.");
+
+            <system::Module<T>>::set_code(cloned_origin2, wasm)?;
+
+            print("Runtime upgrade proposal execution finished.");
+        }
+
+        /// Update proposal parameters proposal extrinsic.
+        /// Should be used as callable object to pass to the `engine` module.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn execute_update_proposal_parameters_proposal(
+            origin,
+            proposal_type: ProposalType,
+            proposal_parameters: ProposalParameters<T::BlockNumber, BalanceOf<T>>,
+        ) {
+            ensure_root(origin)?;
+
+            <ProposalParametersByType<T>>::insert(proposal_type, proposal_parameters);
+        }
+
+        /// Set election parameters proposal extrinsic. Revalidates the parameters against the
+        /// current state and defers them until the next election when an election is running.
+        /// Should be used as callable object to pass to the `engine` module.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn execute_set_election_parameters_proposal(
+            origin,
+            election_parameters: ElectionParameters<BalanceOfGovernanceCurrency<T>, T::BlockNumber>,
+            apply_at_next_election: bool,
+        ) {
+            let (cloned_origin1, cloned_origin2) = common::origin::double_origin::<T>(origin);
+            ensure_root(cloned_origin1)?;
+
+            election_parameters.ensure_valid()?;
+            Self::ensure_council_election_parameters_valid(&election_parameters)?;
+
+            if apply_at_next_election || <governance::election::Module<T>>::is_election_running() {
+                <governance::election::Module<T>>::set_election_parameters_at_next_election(
+                    cloned_origin2,
+                    election_parameters,
+                )?;
+            } else {
+                <governance::election::Module<T>>::set_election_parameters(
+                    cloned_origin2,
+                    election_parameters,
+                )?;
+            }
+        }
+    }
+}
+
+impl<T: Trait> Module<T> {
+    // Generic template proposal builder
+    fn create_proposal(params: CreateProposalParameters<T>) -> DispatchResult {
+        let account_id =
+            T::MembershipOriginValidator::ensure_actor_origin(params.origin, params.member_id)?;
+
+        let proposal_type = params.proposal_details.proposal_type();
+
+        <proposals_engine::Module<T>>::ensure_create_proposal_parameters_are_valid(
+            params.member_id,
+            proposal_type,
+            &params.proposal_parameters,
+            &params.title,
+            &params.description,
+            params.stake_balance,
+            params.execution_schedule,
+            &params.dependencies,
+        )?;
+
+        <proposals_discussion::Module<T>>::ensure_can_create_thread(
+            params.member_id,
+            &params.title,
+        )?;
+
+        let discussion_thread_id = <proposals_discussion::Module<T>>::create_thread(
+            params.member_id,
+            params.title.clone(),
+        )?;
+
+        let batch = matches!(params.proposal_details, ProposalDetails::Batch(..));
+
+        let proposal_id =
+            <proposals_engine::Module<T>>::create_proposal(ProposalCreationParameters {
+                account_id,
+                proposer_id: params.member_id,
+                proposal_type,
+                parameters: params.proposal_parameters,
+                title: params.title,
+                description: params.description,
+                stake_balance: params.stake_balance,
+                encoded_dispatchable_call_code: params.proposal_code,
+                execution_schedule: params.execution_schedule,
+                dependencies: params.dependencies,
+                batch,
+            })?;
+
+        Self::note_runtime_upgrade_code_hashes(&params.proposal_details);
+
+        <ThreadIdByProposalId<T>>::insert(proposal_id, discussion_thread_id);
+        <ProposalDetailsByProposalId<T>>::insert(proposal_id, params.proposal_details);
+
+        Ok(())
+    }
+
+    // Records the code hashes of the runtime upgrade proposals (including the batched ones) to
+    // allow uploading of their preimages. Increments the count of the proposals referencing them.
+    fn note_runtime_upgrade_code_hashes(proposal_details: &ProposalDetailsOf<T>) {
+        match proposal_details {
+            ProposalDetails::RuntimeUpgrade(wasm_hash) => {
+                <ProposedRuntimeUpgradeCodeHashes<T>>::mutate(wasm_hash, |count| {
+                    *count = count.saturating_add(1)
+                });
+            }
+            ProposalDetails::Batch(batched_proposal_details) => {
+                for details in batched_proposal_details.iter() {
+                    Self::note_runtime_upgrade_code_hashes(details);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Decrements the count of the proposals referencing the code hashes of the runtime upgrade
+    // proposals (including the batched ones). Removes the code hashes no longer referenced along
+    // with their preimages and refunds the preimage deposits.
+    fn release_runtime_upgrade_code_hashes(proposal_details: &ProposalDetailsOf<T>) {
+        match proposal_details {
+            ProposalDetails::RuntimeUpgrade(wasm_hash) => {
+                let count = Self::proposed_runtime_upgrade_code_hashes(wasm_hash).saturating_sub(1);
+
+                if count > 0 {
+                    <ProposedRuntimeUpgradeCodeHashes<T>>::insert(wasm_hash, count);
+                } else {
+                    <ProposedRuntimeUpgradeCodeHashes<T>>::remove(wasm_hash);
+                    <RuntimeUpgradePreimages<T>>::remove(wasm_hash);
+
+                    if let Some((account_id, deposit)) =
+                        <RuntimeUpgradePreimageDeposits<T>>::take(wasm_hash)
+                    {
+                        <T as GovernanceCurrency>::Currency::unreserve(&account_id, deposit);
+                    }
+                }
+            }
+            ProposalDetails::Batch(batched_proposal_details) => {
+                for details in batched_proposal_details.iter() {
+                    Self::release_runtime_upgrade_code_hashes(details);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Ends the voting of the signal proposal:
+    // - emits the voting results
+    // - refunds the deposit
+    // - releases the active proposal counters of the engine
+    // - prunes the signal proposal, its votes and discussion
+    fn end_signal_proposal_voting(
+        voting_ends_at: T::BlockNumber,
+        signal_proposal_id: SignalProposalId,
+    ) {
+        <SignalProposalsByVotingEnd<T>>::remove(voting_ends_at, signal_proposal_id);
+
+        let signal_proposal = <SignalProposals<T>>::take(signal_proposal_id);
+
+        if let Some((account_id, deposit)) = <SignalProposalDeposits<T>>::take(signal_proposal_id) {
+            <T as GovernanceCurrency>::Currency::unreserve(&account_id, deposit);
+        }
+
+        <proposals_engine::Module<T>>::decrease_active_proposal_counter(
+            signal_proposal.proposer_id,
+            SIGNAL_PROPOSAL_TYPE,
+        );
+
+        <SignalProposalVoteByMember<T>>::remove_prefix(signal_proposal_id);
+        <proposals_discussion::Module<T>>::remove_thread(signal_proposal.thread_id);
+
+        Self::deposit_event(RawEvent::SignalProposalVotingEnded(
+            signal_proposal_id,
+            signal_proposal.results,
+        ));
+    }
+
+    // Ensures the 'signal' proposal options count and the option lengths.
+    fn ensure_signal_proposal_options_valid(options: &[Vec<u8>]) -> DispatchResult {
+        ensure!(
+            options.len() >= SIGNAL_PROPOSAL_MIN_OPTIONS,
+            Error::<T>::InvalidSignalProposalOptions
+        );
+        ensure!(
+            options.len() <= SIGNAL_PROPOSAL_MAX_OPTIONS,
+            Error::<T>::InvalidSignalProposalOptions
+        );
+
+        for option in options.iter() {
+            ensure!(
+                !option.is_empty() && option.len() <= SIGNAL_PROPOSAL_OPTION_MAX_LENGTH,
+                Error::<T>::InvalidSignalProposalOptions
+            );
+        }
+
+        Ok(())
+    }
+
+    // Ensures the runtime upgrade code size and that the code is a runtime with the same
+    // `spec_name` as the current runtime.
+    fn ensure_runtime_upgrade_code_valid(wasm: &[u8]) -> DispatchResult {
+        ensure!(!wasm.is_empty(), Error::<T>::RuntimeProposalIsEmpty);
+        ensure!(
+            wasm.len() as u32 <= T::RuntimeUpgradeWasmProposalMaxLength::get(),
+            Error::<T>::RuntimeProposalSizeExceeded
+        );
+
+        let new_version = sp_io::misc::runtime_version(wasm)
+            .and_then(|version| RuntimeVersion::decode(&mut &version[..]).ok())
+            .ok_or(Error::<T>::InvalidRuntimeUpgradeCode)?;
+
+        ensure!(
+            new_version.spec_name == T::Version::get().spec_name,
+            Error::<T>::InvalidRuntimeUpgradeCode
+        );
+
+        Ok(())
+    }
+
+    // proposal parameters for all proposal types including the batch proposal
+    fn proposal_parameters(
+        proposal_details: &ProposalDetailsOf<T>,
+    ) -> Option<ProposalParameters<T::BlockNumber, BalanceOf<T>>> {
+        match proposal_details {
+            ProposalDetails::Batch(batched_proposal_details) => {
+                proposal_types::parameters::batch_proposal::<T>(batched_proposal_details)
+            }
+            _ => proposal_types::parameters::proposal_details_parameters::<T>(proposal_details),
+        }
+    }
+
+    // validates the proposal details for all proposal types
+    fn ensure_proposal_details_valid(proposal_details: &ProposalDetailsOf<T>) -> DispatchResult {
+        match proposal_details {
+            ProposalDetails::Text(text) => {
+                ensure!(!text.is_empty(), Error::<T>::TextProposalIsEmpty);
+                ensure!(
+                    text.len() as u32 <= T::TextProposalMaxLength::get(),
+                    Error::<T>::TextProposalSizeExceeded
+                );
+            }
+            ProposalDetails::SetElectionParameters(election_parameters, apply_at_next_election) => {
+                election_parameters.ensure_valid()?;
+
+                Self::ensure_council_election_parameters_valid(election_parameters)?;
+
+                ensure!(
+                    *apply_at_next_election
+                        || !<governance::election::Module<T>>::is_election_running(),
+                    Error::<T>::ElectionParametersChangeDuringElection
+                );
+            }
+            ProposalDetails::Spending(balance, _) => {
+                ensure!(
+                    *balance != BalanceOfMint::<T>::zero(),
+                    Error::<T>::InvalidSpendingProposalBalance
+                );
+                ensure!(
+                    *balance <= <BalanceOfMint<T>>::from(MAX_SPENDING_PROPOSAL_VALUE),
+                    Error::<T>::InvalidSpendingProposalBalance
+                );
+            }
+            ProposalDetails::SetLead(new_lead) => {
+                if let Some((_, account_id)) = new_lead {
+                    ensure!(
+                        !<governance::council::Module<T>>::is_councilor(account_id),
+                        Error::<T>::InvalidSetLeadParameterCannotBeCouncilor
+                    );
+                }
+            }
+            ProposalDetails::SetContentWorkingGroupMintCapacity(mint_balance) => {
+                ensure!(
+                    *mint_balance
+                        <= <BalanceOfMint<T>>::from(CONTENT_WORKING_GROUP_MINT_CAPACITY_MAX_VALUE),
+                    Error::<T>::InvalidContentWorkingGroupMintCapacity
+                );
+            }
+            ProposalDetails::SetValidatorCount(new_validator_count) => {
+                ensure!(
+                    *new_validator_count >= <staking::Module<T>>::minimum_validator_count(),
+                    Error::<T>::InvalidValidatorCount
+                );
+
+                ensure!(
+                    *new_validator_count <= MAX_VALIDATOR_COUNT,
+                    Error::<T>::InvalidValidatorCount
+                );
+            }
+            ProposalDetails::SetWorkingGroupMintCapacity(mint_balance, _) => {
+                ensure!(
+                    *mint_balance
+                        <= <BalanceOfMint<T>>::from(WORKING_GROUP_MINT_CAPACITY_MAX_VALUE),
+                    Error::<T>::InvalidWorkingGroupMintCapacity
+                );
+            }
+            ProposalDetails::DecreaseWorkingGroupLeaderStake(_, decreasing_stake, _) => {
+                ensure!(
+                    *decreasing_stake != Zero::zero(),
+                    Error::<T>::DecreasingStakeIsZero
+                );
+            }
+            ProposalDetails::SlashWorkingGroupLeaderStake(_, slashing_stake, _) => {
+                ensure!(
+                    *slashing_stake != Zero::zero(),
+                    Error::<T>::SlashingStakeIsZero
+                );
+            }
+            ProposalDetails::Batch(batched_proposal_details) => {
+                ensure!(
+                    !batched_proposal_details.is_empty()
+                        && batched_proposal_details.len() <= MAX_BATCH_PROPOSAL_SIZE,
+                    Error::<T>::InvalidBatchProposalSize
+                );
+
+                for details in batched_proposal_details.iter() {
+                    ensure!(
+                        proposal_types::parameters::proposal_details_parameters::<T>(details)
+                            .is_some(),
+                        Error::<T>::UnsupportedBatchedProposal
+                    );
+
+                    Self::ensure_proposal_details_valid(details)?;
+                }
+            }
+            ProposalDetails::UpdateProposalParameters(proposal_type, proposal_parameters) => {
+                ensure!(
+                    <ProposalParametersByType<T>>::contains_key(proposal_type),
+                    Error::<T>::InvalidProposalParametersProposalType
+                );
+
+                Self::ensure_proposal_parameters_valid(proposal_parameters)?;
+            }
+            ProposalDetails::RecurringGrant(recurring_grant_parameters) => {
+                ensure!(
+                    recurring_grant_parameters.amount_per_payout != BalanceOfMint::<T>::zero(),
+                    Error::<T>::InvalidRecurringGrantAmount
+                );
+                ensure!(
+                    recurring_grant_parameters.payout_interval != T::BlockNumber::zero(),
+                    Error::<T>::InvalidRecurringGrantPayoutInterval
+                );
+
+                let first_payout_at = <system::Module<T>>::block_number()
+                    + recurring_grant_parameters.payout_interval;
+                ensure!(
+                    recurring_grant_parameters.ends_at > first_payout_at,
+                    Error::<T>::InvalidRecurringGrantEndBlock
+                );
+
+                ensure!(
+                    Self::recurring_grant_total_amount(recurring_grant_parameters)
+                        <= <BalanceOfMint<T>>::from(MAX_SPENDING_PROPOSAL_VALUE),
+                    Error::<T>::InvalidRecurringGrantAmount
+                );
+            }
+            ProposalDetails::CancelRecurringGrant(grant_id) => {
+                ensure!(
+                    <governance::council::RecurringGrants<T>>::contains_key(grant_id),
+                    Error::<T>::InvalidRecurringGrantId
+                );
+            }
+            ProposalDetails::SetMembershipUserInfoConstraints(user_info_constraints) => {
+                ensure!(
+                    user_info_constraints.min_handle_length > 0
+                        && user_info_constraints.min_handle_length
+                            <= user_info_constraints.max_handle_length,
+                    Error::<T>::InvalidMembershipUserInfoConstraints
+                );
+
+                ensure!(
+                    user_info_constraints.max_avatar_uri_length > 0
+                        && user_info_constraints.max_about_text_length > 0,
+                    Error::<T>::InvalidMembershipUserInfoConstraints
+                );
+            }
+            ProposalDetails::SetForumInputValidationConstraints(input_validation_constraints) => {
+                let constraints = [
+                    input_validation_constraints.category_title,
+                    input_validation_constraints.category_description,
+                    input_validation_constraints.thread_title,
+                    input_validation_constraints.post_text,
+                    input_validation_constraints.thread_moderation_rationale,
+                    input_validation_constraints.post_moderation_rationale,
+                ];
+
+                for constraint in constraints.iter() {
+                    let max = constraint.min.checked_add(constraint.max_min_diff);
+
+                    ensure!(
+                        constraint.min > 0 && max.is_some(),
+                        Error::<T>::InvalidForumInputValidationConstraints
+                    );
+                }
+            }
+            ProposalDetails::SetMaxMemoLength(max_memo_length) => {
+                ensure!(*max_memo_length > 0, Error::<T>::InvalidMaxMemoLength);
+            }
+            ProposalDetails::SetServiceDiscoveryDefaultLifetime(default_lifetime) => {
+                ensure!(
+                    *default_lifetime
+                        >= T::BlockNumber::from(SERVICE_DISCOVERY_MIN_DEFAULT_LIFETIME),
+                    Error::<T>::InvalidServiceDiscoveryDefaultLifetime
+                );
+            }
+            ProposalDetails::RegisterDataObjectType(data_object_type)
+            | ProposalDetails::UpdateDataObjectType(_, data_object_type) => {
+                ensure!(
+                    !data_object_type.description.is_empty()
+                        && data_object_type.description.len()
+                            <= DATA_OBJECT_TYPE_DESCRIPTION_MAX_LENGTH,
+                    Error::<T>::InvalidDataObjectTypeDescription
+                );
+            }
+            ProposalDetails::SetServiceDiscoveryBootstrapEndpoints(endpoints) => {
+                ensure!(
+                    endpoints.len() <= MAX_BOOTSTRAP_ENDPOINTS,
+                    Error::<T>::InvalidBootstrapEndpoints
+                );
+
+                for endpoint in endpoints.iter() {
+                    ensure!(
+                        !endpoint.is_empty() && endpoint.len() <= BOOTSTRAP_ENDPOINT_MAX_LENGTH,
+                        Error::<T>::InvalidBootstrapEndpoints
+                    );
+                }
+            }
+            // No additional checks for the rest of the proposals. The deprecated proposals cannot
+            // be created.
+            ProposalDetails::AddWorkingGroupLeaderOpening(..)
+            | ProposalDetails::BeginReviewWorkingGroupLeaderApplications(..)
+            | ProposalDetails::FillWorkingGroupLeaderOpening(..)
+            | ProposalDetails::SetWorkingGroupLeaderReward(..)
+            | ProposalDetails::TerminateWorkingGroupLeaderRole(..)
+            | ProposalDetails::SetNewMembershipsAllowed(..)
+            | ProposalDetails::SetMembershipScreeningAuthority(..)
+            | ProposalDetails::SetForumSudo(..)
+            | ProposalDetails::RuntimeUpgrade(..)
+            | ProposalDetails::ActivateDataObjectType(..)
+            | ProposalDetails::DeactivateDataObjectType(..)
+            | ProposalDetails::EvictStorageProvider(..)
+            | ProposalDetails::SetStorageRoleParameters(..) => {}
+        }
+
+        Ok(())
+    }
+
+    // validates proposal parameters for the 'Update proposal parameters' proposal
+    fn ensure_proposal_parameters_valid(
+        proposal_parameters: &ProposalParameters<T::BlockNumber, BalanceOf<T>>,
+    ) -> DispatchResult {
+        ensure!(
+            proposal_parameters.voting_period != T::BlockNumber::zero(),
+            Error::<T>::InvalidProposalParameters
+        );
+
+        ensure!(
+            proposal_parameters.approval_quorum_percentage <= 100
+                && proposal_parameters.slashing_quorum_percentage <= 100,
+            Error::<T>::InvalidProposalParameters
+        );
+
+        ensure!(
+            (1..=100).contains(&proposal_parameters.approval_threshold_percentage)
+                && (1..=100).contains(&proposal_parameters.slashing_threshold_percentage),
+            Error::<T>::InvalidProposalParameters
+        );
+
+        ensure!(
+            proposal_parameters.constitutionality > 0,
+            Error::<T>::InvalidProposalParameters
+        );
+
+        Ok(())
+    }
+
+    // validates council election parameters for the 'Set election parameters' proposal
+    pub(crate) fn ensure_council_election_parameters_valid(
+        election_parameters: &ElectionParameters<BalanceOfGovernanceCurrency<T>, T::BlockNumber>,
+    ) -> DispatchResult {
         ensure!(
             election_parameters.council_size >= ELECTION_PARAMETERS_COUNCIL_SIZE_MIN_VALUE,
             Error::<T>::InvalidCouncilElectionParameterCouncilSize
@@ -1037,101 +2445,76 @@ impl<T: Trait> Module<T> {
             Error::<T>::InvalidCouncilElectionParameterMinCouncilStake
         );
 
+        // The members of the current council must be able to run for the reelection.
+        ensure!(
+            election_parameters.candidacy_limit as usize
+                >= <governance::council::Module<T>>::active_council().len(),
+            Error::<T>::CandidacyLimitLowerThanActiveCouncilSize
+        );
+
         Ok(())
     }
 
-    /// Sets config values for the proposals.
-    /// Should be called on the migration to the new runtime version.
-    pub fn set_config_values(p: ProposalsConfigParameters) {
-        <SetValidatorCountProposalVotingPeriod<T>>::put(T::BlockNumber::from(
-            p.set_validator_count_proposal_voting_period,
-        ));
-        <SetValidatorCountProposalGracePeriod<T>>::put(T::BlockNumber::from(
-            p.set_validator_count_proposal_grace_period,
-        ));
-        <RuntimeUpgradeProposalVotingPeriod<T>>::put(T::BlockNumber::from(
-            p.runtime_upgrade_proposal_voting_period,
-        ));
-        <RuntimeUpgradeProposalGracePeriod<T>>::put(T::BlockNumber::from(
-            p.runtime_upgrade_proposal_grace_period,
-        ));
-        <TextProposalVotingPeriod<T>>::put(T::BlockNumber::from(p.text_proposal_voting_period));
-        <TextProposalGracePeriod<T>>::put(T::BlockNumber::from(p.text_proposal_grace_period));
-        <SetElectionParametersProposalVotingPeriod<T>>::put(T::BlockNumber::from(
-            p.set_election_parameters_proposal_voting_period,
-        ));
-        <SetElectionParametersProposalGracePeriod<T>>::put(T::BlockNumber::from(
-            p.set_election_parameters_proposal_grace_period,
-        ));
-        <SetContentWorkingGroupMintCapacityProposalVotingPeriod<T>>::put(T::BlockNumber::from(
-            p.set_content_working_group_mint_capacity_proposal_voting_period,
-        ));
-        <SetContentWorkingGroupMintCapacityProposalGracePeriod<T>>::put(T::BlockNumber::from(
-            p.set_content_working_group_mint_capacity_proposal_grace_period,
-        ));
-        <SetLeadProposalVotingPeriod<T>>::put(T::BlockNumber::from(
-            p.set_lead_proposal_voting_period,
-        ));
-        <SetLeadProposalGracePeriod<T>>::put(T::BlockNumber::from(
-            p.set_lead_proposal_grace_period,
-        ));
-        <SpendingProposalVotingPeriod<T>>::put(T::BlockNumber::from(
-            p.spending_proposal_voting_period,
-        ));
-        <SpendingProposalGracePeriod<T>>::put(T::BlockNumber::from(
-            p.spending_proposal_grace_period,
-        ));
-        <AddWorkingGroupOpeningProposalVotingPeriod<T>>::put(T::BlockNumber::from(
-            p.add_working_group_opening_proposal_voting_period,
-        ));
-        <AddWorkingGroupOpeningProposalGracePeriod<T>>::put(T::BlockNumber::from(
-            p.add_working_group_opening_proposal_grace_period,
-        ));
-        <BeginReviewWorkingGroupLeaderApplicationsProposalVotingPeriod<T>>::put(
-            T::BlockNumber::from(
-                p.begin_review_working_group_leader_applications_proposal_voting_period,
-            ),
-        );
-        <BeginReviewWorkingGroupLeaderApplicationsProposalGracePeriod<T>>::put(
-            T::BlockNumber::from(
-                p.begin_review_working_group_leader_applications_proposal_grace_period,
-            ),
-        );
-        <FillWorkingGroupLeaderOpeningProposalVotingPeriod<T>>::put(T::BlockNumber::from(
-            p.fill_working_group_leader_opening_proposal_voting_period,
-        ));
-        <FillWorkingGroupLeaderOpeningProposalGracePeriod<T>>::put(T::BlockNumber::from(
-            p.fill_working_group_leader_opening_proposal_grace_period,
-        ));
-        <SetWorkingGroupMintCapacityProposalVotingPeriod<T>>::put(T::BlockNumber::from(
-            p.set_working_group_mint_capacity_proposal_voting_period,
-        ));
-        <SetWorkingGroupMintCapacityProposalGracePeriod<T>>::put(T::BlockNumber::from(
-            p.set_working_group_mint_capacity_proposal_grace_period,
-        ));
-        <DecreaseWorkingGroupLeaderStakeProposalVotingPeriod<T>>::put(T::BlockNumber::from(
-            p.decrease_working_group_leader_stake_proposal_voting_period,
-        ));
-        <DecreaseWorkingGroupLeaderStakeProposalGracePeriod<T>>::put(T::BlockNumber::from(
-            p.decrease_working_group_leader_stake_proposal_grace_period,
-        ));
-        <SlashWorkingGroupLeaderStakeProposalVotingPeriod<T>>::put(T::BlockNumber::from(
-            p.slash_working_group_leader_stake_proposal_voting_period,
-        ));
-        <SlashWorkingGroupLeaderStakeProposalGracePeriod<T>>::put(T::BlockNumber::from(
-            p.slash_working_group_leader_stake_proposal_grace_period,
-        ));
-        <SetWorkingGroupLeaderRewardProposalVotingPeriod<T>>::put(T::BlockNumber::from(
-            p.set_working_group_leader_reward_proposal_voting_period,
-        ));
-        <SetWorkingGroupLeaderRewardProposalGracePeriod<T>>::put(T::BlockNumber::from(
-            p.set_working_group_leader_reward_proposal_grace_period,
-        ));
-        <TerminateWorkingGroupLeaderRoleProposalVotingPeriod<T>>::put(T::BlockNumber::from(
-            p.terminate_working_group_leader_role_proposal_voting_period,
-        ));
-        <TerminateWorkingGroupLeaderRoleProposalGracePeriod<T>>::put(T::BlockNumber::from(
-            p.terminate_working_group_leader_role_proposal_grace_period,
+    // Total amount of the recurring grant: the amount per payout multiplied by the number of
+    // payouts from the first payout after the current block to the grant end block. The grant
+    // created on the proposal execution cannot pay more than that.
+    pub(crate) fn recurring_grant_total_amount(
+        recurring_grant_parameters: &RecurringGrantParameters<
+            T::AccountId,
+            BalanceOfMint<T>,
+            T::BlockNumber,
+        >,
+    ) -> BalanceOfMint<T> {
+        let first_payout_at =
+            <system::Module<T>>::block_number() + recurring_grant_parameters.payout_interval;
+
+        if recurring_grant_parameters.payout_interval.is_zero()
+            || recurring_grant_parameters.ends_at < first_payout_at
+        {
+            return Zero::zero();
+        }
+
+        let payouts: u32 = ((recurring_grant_parameters.ends_at - first_payout_at)
+            / recurring_grant_parameters.payout_interval
+            + T::BlockNumber::from(1u32))
+        .unique_saturated_into();
+
+        recurring_grant_parameters
+            .amount_per_payout
+            .saturating_mul(<BalanceOfMint<T>>::from(payouts))
+    }
+
+    /// Sets proposal parameters for the provided proposal types. Overrides the existing
+    /// parameters, unlike the `migrate_proposal_parameters` migration.
+    pub fn set_proposal_parameters(
+        parameters: Vec<(
+            ProposalType,
+            ProposalParameters<T::BlockNumber, BalanceOf<T>>,
+        )>,
+    ) {
+        for (proposal_type, proposal_parameters) in parameters {
+            <ProposalParametersByType<T>>::insert(proposal_type, proposal_parameters);
+        }
+    }
+}
+
+impl<T: Trait> ProposalObserver<T::ProposalId> for Module<T> {
+    // Releases the runtime upgrade code hashes of the finalized proposal.
+    fn proposal_finalized(proposal_id: T::ProposalId) {
+        Self::release_runtime_upgrade_code_hashes(&Self::proposal_details_by_proposal_id(
+            proposal_id,
         ));
     }
+
+    // Prunes the proposal details and the discussion of the archived proposal.
+    fn proposal_archived(proposal_id: T::ProposalId) {
+        if <ThreadIdByProposalId<T>>::contains_key(proposal_id) {
+            let thread_id = Self::thread_id_by_proposal_id(proposal_id);
+
+            <proposals_discussion::Module<T>>::remove_thread(thread_id);
+            <ThreadIdByProposalId<T>>::remove(proposal_id);
+        }
+
+        <ProposalDetailsByProposalId<T>>::remove(proposal_id);
+    }
 }
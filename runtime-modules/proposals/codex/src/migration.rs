@@ -0,0 +1,204 @@
+//! Storage migrations of the voting and grace periods stored per proposal type to the proposal
+//! parameters map and of the proposal details stored with the previous layout.
+
+use codec::Decode;
+use frame_support::storage::migration::{take_storage_value, StorageIterator};
+use sp_runtime::traits::Hash;
+use sp_std::vec::Vec;
+
+use governance::election_params::ElectionParameters;
+
+use crate::proposal_types::parameters::default_proposal_parameters;
+use crate::{
+    BalanceOfGovernanceCurrency, Module, ProposalDetails, ProposalDetailsByProposalId,
+    ProposalEncoder, ProposalParametersByType, ProposalType, RuntimeUpgradePreimages, Trait,
+};
+use crate::{
+    ADD_WORKING_GROUP_LEADER_OPENING_PROPOSAL_TYPE,
+    BEGIN_REVIEW_WORKING_GROUP_LEADER_APPLICATIONS_PROPOSAL_TYPE,
+    DECREASE_WORKING_GROUP_LEADER_STAKE_PROPOSAL_TYPE,
+    FILL_WORKING_GROUP_LEADER_OPENING_PROPOSAL_TYPE, RUNTIME_UPGRADE_PROPOSAL_TYPE,
+    SET_CONTENT_WORKING_GROUP_MINT_CAPACITY_PROPOSAL_TYPE, SET_ELECTION_PARAMETERS_PROPOSAL_TYPE,
+    SET_LEAD_PROPOSAL_TYPE, SET_VALIDATOR_COUNT_PROPOSAL_TYPE,
+    SET_WORKING_GROUP_LEADER_REWARD_PROPOSAL_TYPE, SET_WORKING_GROUP_MINT_CAPACITY_PROPOSAL_TYPE,
+    SLASH_WORKING_GROUP_LEADER_STAKE_PROPOSAL_TYPE, SPENDING_PROPOSAL_TYPE,
+    TERMINATE_WORKING_GROUP_LEADER_ROLE_PROPOSAL_TYPE, TEXT_PROPOSAL_TYPE,
+};
+
+// Leading variants of the proposal details layout with the runtime upgrade code stored in the
+// proposal details and the election parameters without the deferring flag. Other proposal details
+// are not decoded and kept intact.
+#[derive(Decode)]
+enum OldProposalDetails<CurrencyBalance, BlockNumber> {
+    Text(Vec<u8>),
+    RuntimeUpgrade(Vec<u8>),
+    SetElectionParameters(ElectionParameters<CurrencyBalance, BlockNumber>),
+}
+
+type OldProposalDetailsOf<T> =
+    OldProposalDetails<BalanceOfGovernanceCurrency<T>, <T as system::Trait>::BlockNumber>;
+
+// Proposal types with the names of the removed storage items of their voting and grace periods.
+const PROPOSAL_PERIODS_STORAGE_ITEMS: [(ProposalType, &[u8], &[u8]); 15] = [
+    (
+        SET_VALIDATOR_COUNT_PROPOSAL_TYPE,
+        b"SetValidatorCountProposalVotingPeriod",
+        b"SetValidatorCountProposalGracePeriod",
+    ),
+    (
+        RUNTIME_UPGRADE_PROPOSAL_TYPE,
+        b"RuntimeUpgradeProposalVotingPeriod",
+        b"RuntimeUpgradeProposalGracePeriod",
+    ),
+    (
+        SET_ELECTION_PARAMETERS_PROPOSAL_TYPE,
+        b"SetElectionParametersProposalVotingPeriod",
+        b"SetElectionParametersProposalGracePeriod",
+    ),
+    (
+        TEXT_PROPOSAL_TYPE,
+        b"TextProposalVotingPeriod",
+        b"TextProposalGracePeriod",
+    ),
+    (
+        SET_CONTENT_WORKING_GROUP_MINT_CAPACITY_PROPOSAL_TYPE,
+        b"SetContentWorkingGroupMintCapacityProposalVotingPeriod",
+        b"SetContentWorkingGroupMintCapacityProposalGracePeriod",
+    ),
+    (
+        SET_LEAD_PROPOSAL_TYPE,
+        b"SetLeadProposalVotingPeriod",
+        b"SetLeadProposalGracePeriod",
+    ),
+    (
+        SPENDING_PROPOSAL_TYPE,
+        b"SpendingProposalVotingPeriod",
+        b"SpendingProposalGracePeriod",
+    ),
+    (
+        ADD_WORKING_GROUP_LEADER_OPENING_PROPOSAL_TYPE,
+        b"AddWorkingGroupOpeningProposalVotingPeriod",
+        b"AddWorkingGroupOpeningProposalGracePeriod",
+    ),
+    (
+        BEGIN_REVIEW_WORKING_GROUP_LEADER_APPLICATIONS_PROPOSAL_TYPE,
+        b"BeginReviewWorkingGroupLeaderApplicationsProposalVotingPeriod",
+        b"BeginReviewWorkingGroupLeaderApplicationsProposalGracePeriod",
+    ),
+    (
+        FILL_WORKING_GROUP_LEADER_OPENING_PROPOSAL_TYPE,
+        b"FillWorkingGroupLeaderOpeningProposalVotingPeriod",
+        b"FillWorkingGroupLeaderOpeningProposalGracePeriod",
+    ),
+    (
+        SET_WORKING_GROUP_MINT_CAPACITY_PROPOSAL_TYPE,
+        b"SetWorkingGroupMintCapacityProposalVotingPeriod",
+        b"SetWorkingGroupMintCapacityProposalGracePeriod",
+    ),
+    (
+        DECREASE_WORKING_GROUP_LEADER_STAKE_PROPOSAL_TYPE,
+        b"DecreaseWorkingGroupLeaderStakeProposalVotingPeriod",
+        b"DecreaseWorkingGroupLeaderStakeProposalGracePeriod",
+    ),
+    (
+        SLASH_WORKING_GROUP_LEADER_STAKE_PROPOSAL_TYPE,
+        b"SlashWorkingGroupLeaderStakeProposalVotingPeriod",
+        b"SlashWorkingGroupLeaderStakeProposalGracePeriod",
+    ),
+    (
+        SET_WORKING_GROUP_LEADER_REWARD_PROPOSAL_TYPE,
+        b"SetWorkingGroupLeaderRewardProposalVotingPeriod",
+        b"SetWorkingGroupLeaderRewardProposalGracePeriod",
+    ),
+    (
+        TERMINATE_WORKING_GROUP_LEADER_ROLE_PROPOSAL_TYPE,
+        b"TerminateWorkingGroupLeaderRoleProposalVotingPeriod",
+        b"TerminateWorkingGroupLeaderRoleProposalGracePeriod",
+    ),
+];
+
+impl<T: Trait> Module<T> {
+    /// Seeds the default parameters of the proposal types missing from the proposal parameters
+    /// map and moves the voting and grace periods stored per proposal type to the map. The
+    /// default periods are kept only for the missing period storage items. Should be called once
+    /// on the runtime upgrade.
+    pub fn migrate_proposal_parameters() {
+        for (proposal_type, parameters) in default_proposal_parameters() {
+            if !<ProposalParametersByType<T>>::contains_key(proposal_type) {
+                <ProposalParametersByType<T>>::insert(proposal_type, parameters);
+            }
+        }
+
+        for (proposal_type, voting_period_item, grace_period_item) in
+            PROPOSAL_PERIODS_STORAGE_ITEMS.iter()
+        {
+            let voting_period =
+                take_storage_value::<T::BlockNumber>(b"ProposalCodex", voting_period_item, &[]);
+            let grace_period =
+                take_storage_value::<T::BlockNumber>(b"ProposalCodex", grace_period_item, &[]);
+
+            <ProposalParametersByType<T>>::mutate(proposal_type, |parameters| {
+                if let Some(voting_period) = voting_period {
+                    parameters.voting_period = voting_period;
+                }
+
+                if let Some(grace_period) = grace_period {
+                    parameters.grace_period = grace_period;
+                }
+            });
+        }
+    }
+
+    /// Migrates the proposal details stored with the previous layout:
+    /// - the runtime upgrade code is moved to the preimages, the proposal details keep its hash
+    /// - the election parameters are applied on the execution, they are not deferred
+    ///
+    /// Updates the code of the migrated proposals in the proposals engine. The code hashes of the
+    /// active and approved proposals are noted as proposed. Should be called once on the runtime
+    /// upgrade after the proposals engine migration.
+    pub fn migrate_proposal_details() {
+        let old_proposal_details: Vec<(Vec<u8>, OldProposalDetailsOf<T>)> =
+            StorageIterator::new(b"ProposalCodex", b"ProposalDetailsByProposalId").collect();
+
+        for (key, old_details) in old_proposal_details {
+            // The key is the Blake2 128 hash of the proposal id concatenated with the proposal id.
+            let proposal_id = match T::ProposalId::decode(&mut &key[16..]) {
+                Ok(proposal_id) => proposal_id,
+                Err(_) => continue,
+            };
+
+            let proposal_details = match old_details {
+                OldProposalDetails::Text(_) => continue,
+                OldProposalDetails::RuntimeUpgrade(wasm) => {
+                    let wasm_hash = T::Hashing::hash(&wasm);
+
+                    if Self::is_proposal_pending(proposal_id) {
+                        <RuntimeUpgradePreimages<T>>::insert(wasm_hash, wasm);
+                    }
+
+                    ProposalDetails::RuntimeUpgrade(wasm_hash)
+                }
+                OldProposalDetails::SetElectionParameters(election_parameters) => {
+                    ProposalDetails::SetElectionParameters(election_parameters, false)
+                }
+            };
+
+            if Self::is_proposal_pending(proposal_id) {
+                Self::note_runtime_upgrade_code_hashes(&proposal_details);
+
+                <proposals_engine::DispatchableCallCode<T>>::insert(
+                    proposal_id,
+                    T::ProposalEncoder::encode_proposal(proposal_details.clone()),
+                );
+            }
+
+            <ProposalDetailsByProposalId<T>>::insert(proposal_id, proposal_details);
+        }
+    }
+
+    // Checks whether the proposal is active or approved and waits for the execution.
+    fn is_proposal_pending(proposal_id: T::ProposalId) -> bool {
+        <proposals_engine::ActiveProposalIds<T>>::contains_key(proposal_id)
+            || <proposals_engine::PendingExecutionProposalIds<T>>::contains_key(proposal_id)
+    }
+}
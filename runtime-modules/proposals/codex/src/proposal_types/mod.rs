@@ -8,7 +8,10 @@ use serde::{Deserialize, Serialize};
 use sp_std::vec::Vec;
 
 use crate::ElectionParameters;
+use common::constraints::InputValidationLengthConstraint;
 use common::working_group::WorkingGroup;
+use governance::council::RecurringGrantId;
+use proposals_engine::{ProposalParameters, ProposalType};
 
 /// Encodes proposal using its details information.
 pub trait ProposalEncoder<T: crate::Trait> {
@@ -27,11 +30,131 @@ pub type ProposalDetailsOf<T> = ProposalDetails<
     working_group::ApplicationId<T>,
     crate::BalanceOf<T>,
     working_group::WorkerId<T>,
+    <T as system::Trait>::Hash,
+>;
+
+/// Proposal type of the 'text' proposal.
+pub const TEXT_PROPOSAL_TYPE: ProposalType = 0;
+
+/// Proposal type of the 'runtime upgrade' proposal.
+pub const RUNTIME_UPGRADE_PROPOSAL_TYPE: ProposalType = 1;
+
+/// Proposal type of the 'set election parameters' proposal.
+pub const SET_ELECTION_PARAMETERS_PROPOSAL_TYPE: ProposalType = 2;
+
+/// Proposal type of the 'spending' proposal.
+pub const SPENDING_PROPOSAL_TYPE: ProposalType = 3;
+
+/// Proposal type of the 'set lead' proposal.
+pub const SET_LEAD_PROPOSAL_TYPE: ProposalType = 4;
+
+/// Proposal type of the 'set content working group mint capacity' proposal.
+pub const SET_CONTENT_WORKING_GROUP_MINT_CAPACITY_PROPOSAL_TYPE: ProposalType = 5;
+
+/// Proposal type of the 'evict storage provider' proposal.
+pub const EVICT_STORAGE_PROVIDER_PROPOSAL_TYPE: ProposalType = 6;
+
+/// Proposal type of the 'set validator count' proposal.
+pub const SET_VALIDATOR_COUNT_PROPOSAL_TYPE: ProposalType = 7;
+
+/// Proposal type of the 'set storage role parameters' proposal.
+pub const SET_STORAGE_ROLE_PARAMETERS_PROPOSAL_TYPE: ProposalType = 8;
+
+/// Proposal type of the 'add working group leader opening' proposal.
+pub const ADD_WORKING_GROUP_LEADER_OPENING_PROPOSAL_TYPE: ProposalType = 9;
+
+/// Proposal type of the 'begin review working group leader applications' proposal.
+pub const BEGIN_REVIEW_WORKING_GROUP_LEADER_APPLICATIONS_PROPOSAL_TYPE: ProposalType = 10;
+
+/// Proposal type of the 'fill working group leader opening' proposal.
+pub const FILL_WORKING_GROUP_LEADER_OPENING_PROPOSAL_TYPE: ProposalType = 11;
+
+/// Proposal type of the 'set working group mint capacity' proposal.
+pub const SET_WORKING_GROUP_MINT_CAPACITY_PROPOSAL_TYPE: ProposalType = 12;
+
+/// Proposal type of the 'decrease working group leader stake' proposal.
+pub const DECREASE_WORKING_GROUP_LEADER_STAKE_PROPOSAL_TYPE: ProposalType = 13;
+
+/// Proposal type of the 'slash working group leader stake' proposal.
+pub const SLASH_WORKING_GROUP_LEADER_STAKE_PROPOSAL_TYPE: ProposalType = 14;
+
+/// Proposal type of the 'set working group leader reward' proposal.
+pub const SET_WORKING_GROUP_LEADER_REWARD_PROPOSAL_TYPE: ProposalType = 15;
+
+/// Proposal type of the 'terminate working group leader role' proposal.
+pub const TERMINATE_WORKING_GROUP_LEADER_ROLE_PROPOSAL_TYPE: ProposalType = 16;
+
+/// Proposal type of the 'batch' proposal.
+pub const BATCH_PROPOSAL_TYPE: ProposalType = 17;
+
+/// Proposal type of the 'update proposal parameters' proposal.
+pub const UPDATE_PROPOSAL_PARAMETERS_PROPOSAL_TYPE: ProposalType = 18;
+
+/// Proposal type of the 'recurring grant' proposal.
+pub const RECURRING_GRANT_PROPOSAL_TYPE: ProposalType = 19;
+
+/// Proposal type of the 'cancel recurring grant' proposal.
+pub const CANCEL_RECURRING_GRANT_PROPOSAL_TYPE: ProposalType = 20;
+
+/// Proposal type of the 'set new memberships allowed' proposal.
+pub const SET_NEW_MEMBERSHIPS_ALLOWED_PROPOSAL_TYPE: ProposalType = 21;
+
+/// Proposal type of the 'set membership user info constraints' proposal.
+pub const SET_MEMBERSHIP_USER_INFO_CONSTRAINTS_PROPOSAL_TYPE: ProposalType = 22;
+
+/// Proposal type of the 'set membership screening authority' proposal.
+pub const SET_MEMBERSHIP_SCREENING_AUTHORITY_PROPOSAL_TYPE: ProposalType = 23;
+
+/// Proposal type of the 'set forum sudo' proposal.
+pub const SET_FORUM_SUDO_PROPOSAL_TYPE: ProposalType = 24;
+
+/// Proposal type of the 'set forum input validation constraints' proposal.
+pub const SET_FORUM_INPUT_VALIDATION_CONSTRAINTS_PROPOSAL_TYPE: ProposalType = 25;
+
+/// Proposal type of the 'set max memo length' proposal.
+pub const SET_MAX_MEMO_LENGTH_PROPOSAL_TYPE: ProposalType = 26;
+
+/// Proposal type of the 'set service discovery default lifetime' proposal.
+pub const SET_SERVICE_DISCOVERY_DEFAULT_LIFETIME_PROPOSAL_TYPE: ProposalType = 27;
+
+/// Proposal type of the 'register data object type' proposal.
+pub const REGISTER_DATA_OBJECT_TYPE_PROPOSAL_TYPE: ProposalType = 28;
+
+/// Proposal type of the 'update data object type' proposal.
+pub const UPDATE_DATA_OBJECT_TYPE_PROPOSAL_TYPE: ProposalType = 29;
+
+/// Proposal type of the 'activate data object type' proposal.
+pub const ACTIVATE_DATA_OBJECT_TYPE_PROPOSAL_TYPE: ProposalType = 30;
+
+/// Proposal type of the 'deactivate data object type' proposal.
+pub const DEACTIVATE_DATA_OBJECT_TYPE_PROPOSAL_TYPE: ProposalType = 31;
+
+/// Proposal type of the 'set service discovery bootstrap endpoints' proposal.
+pub const SET_SERVICE_DISCOVERY_BOOTSTRAP_ENDPOINTS_PROPOSAL_TYPE: ProposalType = 32;
+
+/// Proposal type of the 'signal' proposal. Signal proposals are not passed to the proposals
+/// engine: only the voting period of their proposal parameters and the active proposal limits
+/// of the engine are used.
+pub const SIGNAL_PROPOSAL_TYPE: ProposalType = 33;
+
+/// Data object type id of the data object type proposals. Matches the `DataObjectTypeId` of the
+/// storage `data_object_type_registry` module in the runtime.
+pub type DataObjectTypeId = u64;
+
+/// Signal proposal id type.
+pub type SignalProposalId = u64;
+
+/// _SignalProposal_ alias for type simplification
+pub type SignalProposalOf<T> = SignalProposal<
+    <T as system::Trait>::BlockNumber,
+    crate::MemberId<T>,
+    <T as proposals_discussion::Trait>::ThreadId,
 >;
 
 /// Proposal details provide voters the information required for the perceived voting.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Debug)]
+#[codec(dumb_trait_bound)]
 pub enum ProposalDetails<
     MintedBalance,
     CurrencyBalance,
@@ -42,15 +165,18 @@ pub enum ProposalDetails<
     ApplicationId,
     StakeBalance,
     WorkerId,
+    Hash,
 > {
     /// The text of the `text` proposal
     Text(Vec<u8>),
 
-    /// The wasm code for the `runtime upgrade` proposal
-    RuntimeUpgrade(Vec<u8>),
+    /// The wasm code hash for the `runtime upgrade` proposal. The wasm code is uploaded
+    /// separately as the runtime upgrade preimage.
+    RuntimeUpgrade(Hash),
 
-    /// Election parameters for the `set election parameters` proposal
-    SetElectionParameters(ElectionParameters<CurrencyBalance, BlockNumber>),
+    /// Election parameters for the `set election parameters` proposal and the flag
+    /// deferring them until the start of the next election
+    SetElectionParameters(ElectionParameters<CurrencyBalance, BlockNumber>, bool),
 
     /// Balance and destination account for the `spending` proposal
     Spending(MintedBalance, AccountId),
@@ -99,6 +225,72 @@ pub enum ProposalDetails<
 
     /// Fire the working group leader with possible slashing.
     TerminateWorkingGroupLeaderRole(TerminateRoleParameters<WorkerId>),
+
+    /// Details of the proposals executed atomically by the `batch` proposal
+    Batch(
+        Vec<
+            ProposalDetails<
+                MintedBalance,
+                CurrencyBalance,
+                BlockNumber,
+                AccountId,
+                MemberId,
+                OpeningId,
+                ApplicationId,
+                StakeBalance,
+                WorkerId,
+                Hash,
+            >,
+        >,
+    ),
+
+    /// New parameters of the proposal type for the `update proposal parameters` proposal
+    UpdateProposalParameters(ProposalType, ProposalParameters<BlockNumber, StakeBalance>),
+
+    /// Destination account, payout amount, interval and end block for the `recurring grant`
+    /// proposal
+    RecurringGrant(RecurringGrantParameters<AccountId, MintedBalance, BlockNumber>),
+
+    /// Recurring grant id for the `cancel recurring grant` proposal
+    CancelRecurringGrant(RecurringGrantId),
+
+    /// Registering of the new members flag for the `set new memberships allowed` proposal
+    SetNewMembershipsAllowed(bool),
+
+    /// User info length constraints for the `set membership user info constraints` proposal
+    SetMembershipUserInfoConstraints(MembershipUserInfoConstraints),
+
+    /// AccountId for the `set membership screening authority` proposal
+    SetMembershipScreeningAuthority(AccountId),
+
+    /// Forum sudo AccountId for the `set forum sudo` proposal. None unsets the forum sudo.
+    SetForumSudo(Option<AccountId>),
+
+    /// Text constraints for the `set forum input validation constraints` proposal
+    SetForumInputValidationConstraints(ForumInputValidationConstraints),
+
+    /// Max memo length for the `set max memo length` proposal
+    SetMaxMemoLength(u32),
+
+    /// Default lifetime of the storage provider records for the
+    /// `set service discovery default lifetime` proposal
+    SetServiceDiscoveryDefaultLifetime(BlockNumber),
+
+    /// Description and active flag for the `register data object type` proposal
+    RegisterDataObjectType(DataObjectTypeParameters),
+
+    /// Data object type id, its new description and active flag for the
+    /// `update data object type` proposal
+    UpdateDataObjectType(DataObjectTypeId, DataObjectTypeParameters),
+
+    /// Data object type id for the `activate data object type` proposal
+    ActivateDataObjectType(DataObjectTypeId),
+
+    /// Data object type id for the `deactivate data object type` proposal
+    DeactivateDataObjectType(DataObjectTypeId),
+
+    /// Endpoint urls for the `set service discovery bootstrap endpoints` proposal
+    SetServiceDiscoveryBootstrapEndpoints(Vec<Vec<u8>>),
 }
 
 impl<
@@ -111,6 +303,7 @@ impl<
         ApplicationId,
         StakeBalance,
         WorkerId,
+        Hash,
     > Default
     for ProposalDetails<
         MintedBalance,
@@ -122,6 +315,7 @@ impl<
         ApplicationId,
         StakeBalance,
         WorkerId,
+        Hash,
     >
 {
     fn default() -> Self {
@@ -129,6 +323,206 @@ impl<
     }
 }
 
+impl<
+        MintedBalance,
+        CurrencyBalance,
+        BlockNumber,
+        AccountId,
+        MemberId,
+        OpeningId,
+        ApplicationId,
+        StakeBalance,
+        WorkerId,
+        Hash,
+    >
+    ProposalDetails<
+        MintedBalance,
+        CurrencyBalance,
+        BlockNumber,
+        AccountId,
+        MemberId,
+        OpeningId,
+        ApplicationId,
+        StakeBalance,
+        WorkerId,
+        Hash,
+    >
+{
+    /// Proposal type used by the proposals engine for the per-type active proposal limits and by
+    /// the proposal parameters map. Equals to the encoded variant index.
+    pub fn proposal_type(&self) -> ProposalType {
+        match self {
+            ProposalDetails::Text(..) => TEXT_PROPOSAL_TYPE,
+            ProposalDetails::RuntimeUpgrade(..) => RUNTIME_UPGRADE_PROPOSAL_TYPE,
+            ProposalDetails::SetElectionParameters(..) => SET_ELECTION_PARAMETERS_PROPOSAL_TYPE,
+            ProposalDetails::Spending(..) => SPENDING_PROPOSAL_TYPE,
+            ProposalDetails::SetLead(..) => SET_LEAD_PROPOSAL_TYPE,
+            ProposalDetails::SetContentWorkingGroupMintCapacity(..) => {
+                SET_CONTENT_WORKING_GROUP_MINT_CAPACITY_PROPOSAL_TYPE
+            }
+            ProposalDetails::EvictStorageProvider(..) => EVICT_STORAGE_PROVIDER_PROPOSAL_TYPE,
+            ProposalDetails::SetValidatorCount(..) => SET_VALIDATOR_COUNT_PROPOSAL_TYPE,
+            ProposalDetails::SetStorageRoleParameters(..) => {
+                SET_STORAGE_ROLE_PARAMETERS_PROPOSAL_TYPE
+            }
+            ProposalDetails::AddWorkingGroupLeaderOpening(..) => {
+                ADD_WORKING_GROUP_LEADER_OPENING_PROPOSAL_TYPE
+            }
+            ProposalDetails::BeginReviewWorkingGroupLeaderApplications(..) => {
+                BEGIN_REVIEW_WORKING_GROUP_LEADER_APPLICATIONS_PROPOSAL_TYPE
+            }
+            ProposalDetails::FillWorkingGroupLeaderOpening(..) => {
+                FILL_WORKING_GROUP_LEADER_OPENING_PROPOSAL_TYPE
+            }
+            ProposalDetails::SetWorkingGroupMintCapacity(..) => {
+                SET_WORKING_GROUP_MINT_CAPACITY_PROPOSAL_TYPE
+            }
+            ProposalDetails::DecreaseWorkingGroupLeaderStake(..) => {
+                DECREASE_WORKING_GROUP_LEADER_STAKE_PROPOSAL_TYPE
+            }
+            ProposalDetails::SlashWorkingGroupLeaderStake(..) => {
+                SLASH_WORKING_GROUP_LEADER_STAKE_PROPOSAL_TYPE
+            }
+            ProposalDetails::SetWorkingGroupLeaderReward(..) => {
+                SET_WORKING_GROUP_LEADER_REWARD_PROPOSAL_TYPE
+            }
+            ProposalDetails::TerminateWorkingGroupLeaderRole(..) => {
+                TERMINATE_WORKING_GROUP_LEADER_ROLE_PROPOSAL_TYPE
+            }
+            ProposalDetails::Batch(..) => BATCH_PROPOSAL_TYPE,
+            ProposalDetails::UpdateProposalParameters(..) => {
+                UPDATE_PROPOSAL_PARAMETERS_PROPOSAL_TYPE
+            }
+            ProposalDetails::RecurringGrant(..) => RECURRING_GRANT_PROPOSAL_TYPE,
+            ProposalDetails::CancelRecurringGrant(..) => CANCEL_RECURRING_GRANT_PROPOSAL_TYPE,
+            ProposalDetails::SetNewMembershipsAllowed(..) => {
+                SET_NEW_MEMBERSHIPS_ALLOWED_PROPOSAL_TYPE
+            }
+            ProposalDetails::SetMembershipUserInfoConstraints(..) => {
+                SET_MEMBERSHIP_USER_INFO_CONSTRAINTS_PROPOSAL_TYPE
+            }
+            ProposalDetails::SetMembershipScreeningAuthority(..) => {
+                SET_MEMBERSHIP_SCREENING_AUTHORITY_PROPOSAL_TYPE
+            }
+            ProposalDetails::SetForumSudo(..) => SET_FORUM_SUDO_PROPOSAL_TYPE,
+            ProposalDetails::SetForumInputValidationConstraints(..) => {
+                SET_FORUM_INPUT_VALIDATION_CONSTRAINTS_PROPOSAL_TYPE
+            }
+            ProposalDetails::SetMaxMemoLength(..) => SET_MAX_MEMO_LENGTH_PROPOSAL_TYPE,
+            ProposalDetails::SetServiceDiscoveryDefaultLifetime(..) => {
+                SET_SERVICE_DISCOVERY_DEFAULT_LIFETIME_PROPOSAL_TYPE
+            }
+            ProposalDetails::RegisterDataObjectType(..) => REGISTER_DATA_OBJECT_TYPE_PROPOSAL_TYPE,
+            ProposalDetails::UpdateDataObjectType(..) => UPDATE_DATA_OBJECT_TYPE_PROPOSAL_TYPE,
+            ProposalDetails::ActivateDataObjectType(..) => ACTIVATE_DATA_OBJECT_TYPE_PROPOSAL_TYPE,
+            ProposalDetails::DeactivateDataObjectType(..) => {
+                DEACTIVATE_DATA_OBJECT_TYPE_PROPOSAL_TYPE
+            }
+            ProposalDetails::SetServiceDiscoveryBootstrapEndpoints(..) => {
+                SET_SERVICE_DISCOVERY_BOOTSTRAP_ENDPOINTS_PROPOSAL_TYPE
+            }
+        }
+    }
+}
+
+/// Parameters for the 'recurring grant' proposal.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Debug)]
+pub struct RecurringGrantParameters<AccountId, Balance, BlockNumber> {
+    /// Account the council mint pays the grant to.
+    pub destination: AccountId,
+
+    /// Amount paid at each payout.
+    pub amount_per_payout: Balance,
+
+    /// Interval in blocks between the payouts.
+    pub payout_interval: BlockNumber,
+
+    /// Block at which the grant ends.
+    pub ends_at: BlockNumber,
+}
+
+/// Parameters for the 'set membership user info constraints' proposal.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Debug)]
+pub struct MembershipUserInfoConstraints {
+    /// Min length of the member handle.
+    pub min_handle_length: u32,
+
+    /// Max length of the member handle.
+    pub max_handle_length: u32,
+
+    /// Max length of the member avatar uri.
+    pub max_avatar_uri_length: u32,
+
+    /// Max length of the member about text.
+    pub max_about_text_length: u32,
+}
+
+/// Parameters for the 'set forum input validation constraints' proposal.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Debug)]
+pub struct ForumInputValidationConstraints {
+    /// Category title length constraint.
+    pub category_title: InputValidationLengthConstraint,
+
+    /// Category description length constraint.
+    pub category_description: InputValidationLengthConstraint,
+
+    /// Thread title length constraint.
+    pub thread_title: InputValidationLengthConstraint,
+
+    /// Post text length constraint.
+    pub post_text: InputValidationLengthConstraint,
+
+    /// Thread moderation rationale length constraint.
+    pub thread_moderation_rationale: InputValidationLengthConstraint,
+
+    /// Post moderation rationale length constraint.
+    pub post_moderation_rationale: InputValidationLengthConstraint,
+}
+
+/// Parameters for the 'register data object type' and 'update data object type' proposals.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Debug)]
+pub struct DataObjectTypeParameters {
+    /// Data object type description.
+    pub description: Vec<u8>,
+
+    /// Active/Disabled flag.
+    pub active: bool,
+}
+
+/// Non-binding 'signal' proposal (opinion poll). Any member can vote for one of its options
+/// during the voting period. Signal proposals are not executed and don't require a stake.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Debug, Default)]
+pub struct SignalProposal<BlockNumber, MemberId, ThreadId> {
+    /// Member id of the proposer.
+    pub proposer_id: MemberId,
+
+    /// Signal proposal title.
+    pub title: Vec<u8>,
+
+    /// Signal proposal description.
+    pub description: Vec<u8>,
+
+    /// Options to vote for.
+    pub options: Vec<Vec<u8>>,
+
+    /// Vote count per option. Has the same order as the options.
+    pub results: Vec<u32>,
+
+    /// Signal proposal creation block.
+    pub created_at: BlockNumber,
+
+    /// Votes are accepted until this block.
+    pub voting_ends_at: BlockNumber,
+
+    /// Discussion thread id of the signal proposal.
+    pub thread_id: ThreadId,
+}
+
 /// Parameters for the 'terminate the leader position' proposal.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Debug)]
@@ -218,174 +612,3 @@ pub struct RoleParameters<Balance, BlockNumber> {
     /// Small fee burned to make a request to enter role.
     pub entry_request_fee: Balance,
 }
-
-/// Contains proposal config parameters. Default values are used by migration and genesis config.
-#[derive(Copy, Clone)]
-pub struct ProposalsConfigParameters {
-    /// 'Set validator count' proposal voting period
-    pub set_validator_count_proposal_voting_period: u32,
-
-    /// 'Set validator count' proposal grace period
-    pub set_validator_count_proposal_grace_period: u32,
-
-    /// 'Runtime upgrade' proposal voting period
-    pub runtime_upgrade_proposal_voting_period: u32,
-
-    /// 'Runtime upgrade' proposal grace period
-    pub runtime_upgrade_proposal_grace_period: u32,
-
-    /// 'Text' proposal voting period
-    pub text_proposal_voting_period: u32,
-
-    /// 'Text' proposal grace period
-    pub text_proposal_grace_period: u32,
-
-    /// 'Set election parameters' proposal voting period
-    pub set_election_parameters_proposal_voting_period: u32,
-
-    /// 'Set election parameters' proposal grace period
-    pub set_election_parameters_proposal_grace_period: u32,
-
-    /// 'Set content working group mint capacity' proposal voting period
-    pub set_content_working_group_mint_capacity_proposal_voting_period: u32,
-
-    /// 'Set content working group mint capacity' proposal grace period
-    pub set_content_working_group_mint_capacity_proposal_grace_period: u32,
-
-    /// 'Set lead' proposal voting period
-    pub set_lead_proposal_voting_period: u32,
-
-    /// 'Set lead' proposal grace period
-    pub set_lead_proposal_grace_period: u32,
-
-    /// 'Spending' proposal voting period
-    pub spending_proposal_voting_period: u32,
-
-    /// 'Spending' proposal grace period
-    pub spending_proposal_grace_period: u32,
-
-    /// 'Add working group opening' proposal voting period
-    pub add_working_group_opening_proposal_voting_period: u32,
-
-    /// 'Add working group opening' proposal grace period
-    pub add_working_group_opening_proposal_grace_period: u32,
-
-    /// 'Begin review working group leader applications' proposal voting period
-    pub begin_review_working_group_leader_applications_proposal_voting_period: u32,
-
-    /// 'Begin review working group leader applications' proposal grace period
-    pub begin_review_working_group_leader_applications_proposal_grace_period: u32,
-
-    /// 'Fill working group leader opening' proposal voting period
-    pub fill_working_group_leader_opening_proposal_voting_period: u32,
-
-    /// 'Fill working group leader opening' proposal grace period
-    pub fill_working_group_leader_opening_proposal_grace_period: u32,
-
-    /// 'Set working group mint capacity' proposal voting period
-    pub set_working_group_mint_capacity_proposal_voting_period: u32,
-
-    /// 'Set working group mint capacity' proposal grace period
-    pub set_working_group_mint_capacity_proposal_grace_period: u32,
-
-    /// 'Decrease working group leader stake' proposal voting period
-    pub decrease_working_group_leader_stake_proposal_voting_period: u32,
-
-    /// 'Decrease working group leader stake' proposal grace period
-    pub decrease_working_group_leader_stake_proposal_grace_period: u32,
-
-    /// 'Slash working group leader stake' proposal voting period
-    pub slash_working_group_leader_stake_proposal_voting_period: u32,
-
-    /// 'Slash working group leader stake' proposal grace period
-    pub slash_working_group_leader_stake_proposal_grace_period: u32,
-
-    /// 'Set working group leader reward' proposal voting period
-    pub set_working_group_leader_reward_proposal_voting_period: u32,
-
-    /// 'Set working group leader reward' proposal grace period
-    pub set_working_group_leader_reward_proposal_grace_period: u32,
-
-    /// 'Terminate working group leader role' proposal voting period
-    pub terminate_working_group_leader_role_proposal_voting_period: u32,
-
-    /// 'Terminate working group leader role' proposal grace period
-    pub terminate_working_group_leader_role_proposal_grace_period: u32,
-}
-
-impl Default for ProposalsConfigParameters {
-    fn default() -> Self {
-        ProposalsConfigParameters {
-            set_validator_count_proposal_voting_period: 43200u32,
-            set_validator_count_proposal_grace_period: 0u32,
-            runtime_upgrade_proposal_voting_period: 72000u32,
-            runtime_upgrade_proposal_grace_period: 72000u32,
-            text_proposal_voting_period: 72000u32,
-            text_proposal_grace_period: 0u32,
-            set_election_parameters_proposal_voting_period: 72000u32,
-            set_election_parameters_proposal_grace_period: 201_601_u32,
-            set_content_working_group_mint_capacity_proposal_voting_period: 43200u32,
-            set_content_working_group_mint_capacity_proposal_grace_period: 0u32,
-            set_lead_proposal_voting_period: 43200u32,
-            set_lead_proposal_grace_period: 0u32,
-            spending_proposal_voting_period: 72000u32,
-            spending_proposal_grace_period: 14400u32,
-            add_working_group_opening_proposal_voting_period: 72000u32,
-            add_working_group_opening_proposal_grace_period: 0u32,
-            begin_review_working_group_leader_applications_proposal_voting_period: 43200u32,
-            begin_review_working_group_leader_applications_proposal_grace_period: 14400u32,
-            fill_working_group_leader_opening_proposal_voting_period: 43200u32,
-            fill_working_group_leader_opening_proposal_grace_period: 0u32,
-            set_working_group_mint_capacity_proposal_voting_period: 43200u32,
-            set_working_group_mint_capacity_proposal_grace_period: 0u32,
-            decrease_working_group_leader_stake_proposal_voting_period: 43200u32,
-            decrease_working_group_leader_stake_proposal_grace_period: 0u32,
-            slash_working_group_leader_stake_proposal_voting_period: 43200u32,
-            slash_working_group_leader_stake_proposal_grace_period: 0u32,
-            set_working_group_leader_reward_proposal_voting_period: 43200u32,
-            set_working_group_leader_reward_proposal_grace_period: 0u32,
-            terminate_working_group_leader_role_proposal_voting_period: 72200u32,
-            terminate_working_group_leader_role_proposal_grace_period: 0u32,
-        }
-    }
-}
-
-impl ProposalsConfigParameters {
-    /// Set same voting_period for all proposals. For proposals
-    /// that by default have 0 grace period remain with 0 grace period.
-    /// All remaining proposals get assigned grace_period.
-    pub fn with_grace_and_voting_periods(grace_period: u32, voting_period: u32) -> Self {
-        ProposalsConfigParameters {
-            set_validator_count_proposal_voting_period: voting_period,
-            set_validator_count_proposal_grace_period: 0,
-            runtime_upgrade_proposal_voting_period: voting_period,
-            runtime_upgrade_proposal_grace_period: grace_period,
-            text_proposal_voting_period: voting_period,
-            text_proposal_grace_period: 0,
-            set_election_parameters_proposal_voting_period: voting_period,
-            set_election_parameters_proposal_grace_period: grace_period,
-            set_content_working_group_mint_capacity_proposal_voting_period: voting_period,
-            set_content_working_group_mint_capacity_proposal_grace_period: 0,
-            set_lead_proposal_voting_period: voting_period,
-            set_lead_proposal_grace_period: 0,
-            spending_proposal_voting_period: voting_period,
-            spending_proposal_grace_period: grace_period,
-            add_working_group_opening_proposal_voting_period: voting_period,
-            add_working_group_opening_proposal_grace_period: 0,
-            begin_review_working_group_leader_applications_proposal_voting_period: voting_period,
-            begin_review_working_group_leader_applications_proposal_grace_period: grace_period,
-            fill_working_group_leader_opening_proposal_voting_period: voting_period,
-            fill_working_group_leader_opening_proposal_grace_period: 0,
-            set_working_group_mint_capacity_proposal_voting_period: voting_period,
-            set_working_group_mint_capacity_proposal_grace_period: 0,
-            decrease_working_group_leader_stake_proposal_voting_period: voting_period,
-            decrease_working_group_leader_stake_proposal_grace_period: 0,
-            slash_working_group_leader_stake_proposal_voting_period: voting_period,
-            slash_working_group_leader_stake_proposal_grace_period: 0,
-            set_working_group_leader_reward_proposal_voting_period: voting_period,
-            set_working_group_leader_reward_proposal_grace_period: 0,
-            terminate_working_group_leader_role_proposal_voting_period: voting_period,
-            terminate_working_group_leader_role_proposal_grace_period: 0,
-        }
-    }
-}
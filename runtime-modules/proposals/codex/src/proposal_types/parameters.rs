@@ -1,213 +1,334 @@
-use crate::{BalanceOf, Module, ProposalParameters};
+use crate::{
+    BalanceOf, BalanceOfMint, Module, ProposalDetails, ProposalDetailsOf, ProposalParameters,
+};
+use proposals_engine::ProposalType;
+use sp_std::cmp::{max, min};
+use sp_std::vec::Vec;
 
-// Proposal parameters for the 'Set validator count' proposal
-pub(crate) fn set_validator_count_proposal<T: crate::Trait>(
-) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::set_validator_count_proposal_voting_period(),
-        grace_period: <Module<T>>::set_validator_count_proposal_grace_period(),
-        approval_quorum_percentage: 66,
-        approval_threshold_percentage: 80,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(100_000_u32)),
-    }
-}
+use super::*;
 
-// Proposal parameters for the upgrade runtime proposal
-pub(crate) fn runtime_upgrade_proposal<T: crate::Trait>(
-) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::runtime_upgrade_proposal_voting_period(),
-        grace_period: <Module<T>>::runtime_upgrade_proposal_grace_period(),
-        approval_quorum_percentage: 80,
-        approval_threshold_percentage: 100,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(1_000_000_u32)),
-    }
-}
+// Proposal parameters with the block numbers and balances as u32 values.
+type DefaultProposalParameters = ProposalParameters<u32, u32>;
 
-// Proposal parameters for the text proposal
-pub(crate) fn text_proposal<T: crate::Trait>() -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+// Creates default proposal parameters. Slashing quorum and threshold are the same for all
+// proposal types.
+fn default_parameters(
+    voting_period: u32,
+    grace_period: u32,
+    approval_quorum_percentage: u32,
+    approval_threshold_percentage: u32,
+    required_stake: u32,
+    constitutionality: u32,
+) -> DefaultProposalParameters {
     ProposalParameters {
-        voting_period: <Module<T>>::text_proposal_voting_period(),
-        grace_period: <Module<T>>::text_proposal_grace_period(),
-        approval_quorum_percentage: 60,
-        approval_threshold_percentage: 80,
+        voting_period,
+        grace_period,
+        approval_quorum_percentage,
+        approval_threshold_percentage,
         slashing_quorum_percentage: 60,
         slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(25000u32)),
+        required_stake: Some(required_stake),
+        weighted_voting: false,
+        constitutionality,
     }
 }
 
-// Proposal parameters for the 'Set Election Parameters' proposal
-pub(crate) fn set_election_parameters_proposal<T: crate::Trait>(
-) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+// Enables the voting weighted by the councilors stake for the proposal type.
+fn with_weighted_voting(parameters: DefaultProposalParameters) -> DefaultProposalParameters {
     ProposalParameters {
-        voting_period: <Module<T>>::set_election_parameters_proposal_voting_period(),
-        grace_period: <Module<T>>::set_election_parameters_proposal_grace_period(),
-        approval_quorum_percentage: 66,
-        approval_threshold_percentage: 80,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(200_000_u32)),
+        weighted_voting: true,
+        ..parameters
     }
 }
 
-// Proposal parameters for the 'Set content working group mint capacity' proposal
-pub(crate) fn set_content_working_group_mint_capacity_proposal<T: crate::Trait>(
-) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::set_content_working_group_mint_capacity_proposal_voting_period(
+// Default parameters of the proposal types that can be created.
+fn default_parameters_by_type() -> Vec<(ProposalType, DefaultProposalParameters)> {
+    vec![
+        (
+            TEXT_PROPOSAL_TYPE,
+            default_parameters(72000, 0, 60, 80, 25000, 1),
         ),
-        grace_period: <Module<T>>::set_content_working_group_mint_capacity_proposal_grace_period(),
-        approval_quorum_percentage: 60,
-        approval_threshold_percentage: 75,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(50000u32)),
-    }
+        (
+            RUNTIME_UPGRADE_PROPOSAL_TYPE,
+            default_parameters(72000, 72000, 80, 100, 1_000_000, 2),
+        ),
+        (
+            SET_ELECTION_PARAMETERS_PROPOSAL_TYPE,
+            default_parameters(72000, 201_601, 66, 80, 200_000, 1),
+        ),
+        (
+            SPENDING_PROPOSAL_TYPE,
+            default_parameters(72000, 14400, 60, 80, 25000, 1),
+        ),
+        (
+            SET_LEAD_PROPOSAL_TYPE,
+            default_parameters(43200, 0, 60, 75, 50000, 1),
+        ),
+        (
+            SET_CONTENT_WORKING_GROUP_MINT_CAPACITY_PROPOSAL_TYPE,
+            default_parameters(43200, 0, 60, 75, 50000, 1),
+        ),
+        (
+            SET_VALIDATOR_COUNT_PROPOSAL_TYPE,
+            default_parameters(43200, 0, 66, 80, 100_000, 1),
+        ),
+        (
+            ADD_WORKING_GROUP_LEADER_OPENING_PROPOSAL_TYPE,
+            default_parameters(72000, 0, 60, 80, 100_000, 1),
+        ),
+        (
+            BEGIN_REVIEW_WORKING_GROUP_LEADER_APPLICATIONS_PROPOSAL_TYPE,
+            default_parameters(43200, 14400, 60, 75, 25000, 1),
+        ),
+        (
+            FILL_WORKING_GROUP_LEADER_OPENING_PROPOSAL_TYPE,
+            default_parameters(43200, 0, 60, 75, 50000, 1),
+        ),
+        (
+            SET_WORKING_GROUP_MINT_CAPACITY_PROPOSAL_TYPE,
+            default_parameters(43200, 0, 60, 75, 50000, 1),
+        ),
+        (
+            DECREASE_WORKING_GROUP_LEADER_STAKE_PROPOSAL_TYPE,
+            default_parameters(43200, 0, 60, 75, 50000, 1),
+        ),
+        (
+            SLASH_WORKING_GROUP_LEADER_STAKE_PROPOSAL_TYPE,
+            default_parameters(43200, 0, 60, 75, 50000, 1),
+        ),
+        (
+            SET_WORKING_GROUP_LEADER_REWARD_PROPOSAL_TYPE,
+            default_parameters(43200, 0, 60, 75, 50000, 1),
+        ),
+        (
+            TERMINATE_WORKING_GROUP_LEADER_ROLE_PROPOSAL_TYPE,
+            default_parameters(72200, 0, 66, 80, 100_000, 1),
+        ),
+        (
+            UPDATE_PROPOSAL_PARAMETERS_PROPOSAL_TYPE,
+            default_parameters(72000, 14400, 66, 80, 200_000, 1),
+        ),
+        (
+            RECURRING_GRANT_PROPOSAL_TYPE,
+            with_weighted_voting(default_parameters(72000, 14400, 60, 80, 25000, 1)),
+        ),
+        (
+            CANCEL_RECURRING_GRANT_PROPOSAL_TYPE,
+            default_parameters(43200, 0, 60, 75, 25000, 1),
+        ),
+        (
+            SET_NEW_MEMBERSHIPS_ALLOWED_PROPOSAL_TYPE,
+            default_parameters(43200, 0, 60, 75, 25000, 1),
+        ),
+        (
+            SET_MEMBERSHIP_USER_INFO_CONSTRAINTS_PROPOSAL_TYPE,
+            default_parameters(43200, 0, 60, 75, 25000, 1),
+        ),
+        (
+            SET_MEMBERSHIP_SCREENING_AUTHORITY_PROPOSAL_TYPE,
+            default_parameters(43200, 0, 66, 80, 50000, 1),
+        ),
+        (
+            SET_FORUM_SUDO_PROPOSAL_TYPE,
+            default_parameters(43200, 0, 66, 80, 50000, 1),
+        ),
+        (
+            SET_FORUM_INPUT_VALIDATION_CONSTRAINTS_PROPOSAL_TYPE,
+            default_parameters(43200, 0, 60, 75, 25000, 1),
+        ),
+        (
+            SET_MAX_MEMO_LENGTH_PROPOSAL_TYPE,
+            default_parameters(43200, 0, 60, 75, 25000, 1),
+        ),
+        (
+            SET_SERVICE_DISCOVERY_DEFAULT_LIFETIME_PROPOSAL_TYPE,
+            default_parameters(43200, 0, 60, 75, 25000, 1),
+        ),
+        (
+            REGISTER_DATA_OBJECT_TYPE_PROPOSAL_TYPE,
+            default_parameters(43200, 0, 60, 75, 25000, 1),
+        ),
+        (
+            UPDATE_DATA_OBJECT_TYPE_PROPOSAL_TYPE,
+            default_parameters(43200, 0, 60, 75, 25000, 1),
+        ),
+        (
+            ACTIVATE_DATA_OBJECT_TYPE_PROPOSAL_TYPE,
+            default_parameters(43200, 0, 60, 75, 25000, 1),
+        ),
+        (
+            DEACTIVATE_DATA_OBJECT_TYPE_PROPOSAL_TYPE,
+            default_parameters(43200, 0, 60, 75, 25000, 1),
+        ),
+        (
+            SET_SERVICE_DISCOVERY_BOOTSTRAP_ENDPOINTS_PROPOSAL_TYPE,
+            default_parameters(43200, 0, 60, 75, 25000, 1),
+        ),
+        // Only the voting period is used by the signal proposals.
+        (
+            SIGNAL_PROPOSAL_TYPE,
+            default_parameters(72000, 0, 0, 0, 0, 1),
+        ),
+    ]
 }
 
-// Proposal parameters for the 'Spending' proposal
-pub(crate) fn spending_proposal<T: crate::Trait>(
-) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+// Converts the default proposal parameters to the runtime types.
+fn convert_parameters<BlockNumber: From<u32>, Balance: From<u32>>(
+    parameters: DefaultProposalParameters,
+) -> ProposalParameters<BlockNumber, Balance> {
     ProposalParameters {
-        voting_period: <Module<T>>::spending_proposal_voting_period(),
-        grace_period: <Module<T>>::spending_proposal_grace_period(),
-        approval_quorum_percentage: 60,
-        approval_threshold_percentage: 80,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(25000u32)),
+        voting_period: parameters.voting_period.into(),
+        grace_period: parameters.grace_period.into(),
+        approval_quorum_percentage: parameters.approval_quorum_percentage,
+        approval_threshold_percentage: parameters.approval_threshold_percentage,
+        slashing_quorum_percentage: parameters.slashing_quorum_percentage,
+        slashing_threshold_percentage: parameters.slashing_threshold_percentage,
+        required_stake: parameters.required_stake.map(Into::into),
+        weighted_voting: parameters.weighted_voting,
+        constitutionality: parameters.constitutionality,
     }
 }
 
-// Proposal parameters for the 'Set lead' proposal
-pub(crate) fn set_lead_proposal<T: crate::Trait>(
-) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::set_lead_proposal_voting_period(),
-        grace_period: <Module<T>>::set_lead_proposal_grace_period(),
-        approval_quorum_percentage: 60,
-        approval_threshold_percentage: 75,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(50000u32)),
-    }
+/// Default parameters for all proposal types that can be created. Used by the genesis config
+/// and the migration.
+pub fn default_proposal_parameters<BlockNumber: From<u32>, Balance: From<u32>>(
+) -> Vec<(ProposalType, ProposalParameters<BlockNumber, Balance>)> {
+    default_parameters_by_type()
+        .into_iter()
+        .map(|(proposal_type, parameters)| (proposal_type, convert_parameters(parameters)))
+        .collect()
 }
 
-// Proposal parameters for the 'Add working group leader' proposal
-pub(crate) fn add_working_group_leader_opening_proposal<T: crate::Trait>(
-) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::add_working_group_opening_proposal_voting_period(),
-        grace_period: <Module<T>>::add_working_group_opening_proposal_grace_period(),
-        approval_quorum_percentage: 60,
-        approval_threshold_percentage: 80,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(100_000_u32)),
-    }
-}
+/// Default parameters for all proposal types with the same voting period. Proposals that by
+/// default have zero grace period remain with zero grace period. All remaining proposals get
+/// the provided grace period.
+pub fn proposal_parameters_with_grace_and_voting_periods<
+    BlockNumber: From<u32>,
+    Balance: From<u32>,
+>(
+    grace_period: u32,
+    voting_period: u32,
+) -> Vec<(ProposalType, ProposalParameters<BlockNumber, Balance>)> {
+    default_parameters_by_type()
+        .into_iter()
+        .map(|(proposal_type, parameters)| {
+            let parameters = ProposalParameters {
+                voting_period,
+                grace_period: if parameters.grace_period == 0 {
+                    0
+                } else {
+                    grace_period
+                },
+                ..parameters
+            };
 
-// Proposal parameters for the 'Begin review working group leader applications' proposal
-pub(crate) fn begin_review_working_group_leader_applications_proposal<T: crate::Trait>(
-) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period:
-            <Module<T>>::begin_review_working_group_leader_applications_proposal_voting_period(),
-        grace_period:
-            <Module<T>>::begin_review_working_group_leader_applications_proposal_grace_period(),
-        approval_quorum_percentage: 60,
-        approval_threshold_percentage: 75,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(25000u32)),
-    }
+            (proposal_type, convert_parameters(parameters))
+        })
+        .collect()
 }
 
-// Proposal parameters for the 'Fill working group leader opening' proposal
-pub(crate) fn fill_working_group_leader_opening_proposal<T: crate::Trait>(
+// Proposal parameters of the proposal type from the proposal parameters map
+pub(crate) fn proposal_parameters<T: crate::Trait>(
+    proposal_type: ProposalType,
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::fill_working_group_leader_opening_proposal_voting_period(),
-        grace_period: <Module<T>>::fill_working_group_leader_opening_proposal_grace_period(),
-        approval_quorum_percentage: 60,
-        approval_threshold_percentage: 75,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(50000u32)),
-    }
+    <Module<T>>::proposal_parameters_by_type(proposal_type)
 }
 
-// Proposal parameters for the 'Set working group mint capacity' proposal
-pub(crate) fn set_working_group_mint_capacity_proposal<T: crate::Trait>(
+// Proposal parameters requiring two councils approval for spending the large balance.
+fn with_large_spending_constitutionality<T: crate::Trait>(
+    parameters: ProposalParameters<T::BlockNumber, BalanceOf<T>>,
+    balance: BalanceOfMint<T>,
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::set_working_group_mint_capacity_proposal_voting_period(),
-        grace_period: <Module<T>>::set_working_group_mint_capacity_proposal_grace_period(),
-        approval_quorum_percentage: 60,
-        approval_threshold_percentage: 75,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(50000u32)),
+    if balance >= <BalanceOfMint<T>>::from(crate::LARGE_SPENDING_PROPOSAL_VALUE) {
+        ProposalParameters {
+            constitutionality: max(parameters.constitutionality, 2),
+            ..parameters
+        }
+    } else {
+        parameters
     }
 }
 
-// Proposal parameters for the 'Decrease working group leader stake' proposal
-pub(crate) fn decrease_working_group_leader_stake_proposal<T: crate::Trait>(
+// Proposal parameters for the 'Spending' proposal depending on the spending balance: the
+// spending proposal with the large balance requires two councils approval.
+pub(crate) fn spending_proposal_for_balance<T: crate::Trait>(
+    balance: BalanceOfMint<T>,
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::decrease_working_group_leader_stake_proposal_voting_period(),
-        grace_period: <Module<T>>::decrease_working_group_leader_stake_proposal_grace_period(),
-        approval_quorum_percentage: 60,
-        approval_threshold_percentage: 75,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(50000u32)),
-    }
+    with_large_spending_constitutionality::<T>(
+        proposal_parameters::<T>(SPENDING_PROPOSAL_TYPE),
+        balance,
+    )
 }
 
-// Proposal parameters for the 'Slash working group leader stake' proposal
-pub(crate) fn slash_working_group_leader_stake_proposal<T: crate::Trait>(
+// Proposal parameters for the 'Recurring grant' proposal depending on the total grant amount: the
+// recurring grant with the large total amount requires two councils approval.
+pub(crate) fn recurring_grant_proposal<T: crate::Trait>(
+    recurring_grant_parameters: &RecurringGrantParameters<
+        T::AccountId,
+        BalanceOfMint<T>,
+        T::BlockNumber,
+    >,
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::slash_working_group_leader_stake_proposal_voting_period(),
-        grace_period: <Module<T>>::slash_working_group_leader_stake_proposal_grace_period(),
-        approval_quorum_percentage: 60,
-        approval_threshold_percentage: 75,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(50000u32)),
-    }
+    with_large_spending_constitutionality::<T>(
+        proposal_parameters::<T>(RECURRING_GRANT_PROPOSAL_TYPE),
+        <Module<T>>::recurring_grant_total_amount(recurring_grant_parameters),
+    )
 }
 
-// Proposal parameters for the 'Set working group leader reward' proposal
-pub(crate) fn set_working_group_leader_reward_proposal<T: crate::Trait>(
-) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::set_working_group_leader_reward_proposal_voting_period(),
-        grace_period: <Module<T>>::set_working_group_leader_reward_proposal_grace_period(),
-        approval_quorum_percentage: 60,
-        approval_threshold_percentage: 75,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(50000u32)),
+// Proposal parameters by the proposal details. Returns None for the proposals that cannot be
+// created.
+pub(crate) fn proposal_details_parameters<T: crate::Trait>(
+    proposal_details: &ProposalDetailsOf<T>,
+) -> Option<ProposalParameters<T::BlockNumber, BalanceOf<T>>> {
+    match proposal_details {
+        ProposalDetails::Spending(balance, ..) => {
+            Some(spending_proposal_for_balance::<T>(*balance))
+        }
+        ProposalDetails::RecurringGrant(recurring_grant_parameters) => {
+            Some(recurring_grant_proposal::<T>(recurring_grant_parameters))
+        }
+        ProposalDetails::EvictStorageProvider(..)
+        | ProposalDetails::SetStorageRoleParameters(..)
+        | ProposalDetails::Batch(..) => None,
+        _ => Some(proposal_parameters::<T>(proposal_details.proposal_type())),
     }
 }
 
-// Proposal parameters for the 'Terminate working group leader role' proposal
-pub(crate) fn terminate_working_group_leader_role_proposal<T: crate::Trait>(
-) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::terminate_working_group_leader_role_proposal_voting_period(),
-        grace_period: <Module<T>>::terminate_working_group_leader_role_proposal_grace_period(),
-        approval_quorum_percentage: 66,
-        approval_threshold_percentage: 80,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(100_000_u32)),
-    }
+// Proposal parameters for the 'batch' proposal: the strictest parameters of the batched proposals.
+// The lowest slashing quorum and threshold are the strictest ones for the proposer.
+// Returns None for the empty batch or if any of the batched proposals cannot be batched.
+pub(crate) fn batch_proposal<T: crate::Trait>(
+    batched_proposal_details: &[ProposalDetailsOf<T>],
+) -> Option<ProposalParameters<T::BlockNumber, BalanceOf<T>>> {
+    let mut batched_parameters = batched_proposal_details
+        .iter()
+        .map(proposal_details_parameters::<T>);
+
+    let first_parameters = batched_parameters.next()??;
+
+    batched_parameters.try_fold(first_parameters, |strictest, parameters| {
+        parameters.map(|parameters| ProposalParameters {
+            voting_period: max(strictest.voting_period, parameters.voting_period),
+            grace_period: max(strictest.grace_period, parameters.grace_period),
+            approval_quorum_percentage: max(
+                strictest.approval_quorum_percentage,
+                parameters.approval_quorum_percentage,
+            ),
+            approval_threshold_percentage: max(
+                strictest.approval_threshold_percentage,
+                parameters.approval_threshold_percentage,
+            ),
+            slashing_quorum_percentage: min(
+                strictest.slashing_quorum_percentage,
+                parameters.slashing_quorum_percentage,
+            ),
+            slashing_threshold_percentage: min(
+                strictest.slashing_threshold_percentage,
+                parameters.slashing_threshold_percentage,
+            ),
+            required_stake: max(strictest.required_stake, parameters.required_stake),
+            weighted_voting: strictest.weighted_voting || parameters.weighted_voting,
+            constitutionality: max(strictest.constitutionality, parameters.constitutionality),
+        })
+    })
 }
@@ -1,22 +1,28 @@
 #![cfg(test)]
 
 use frame_support::{impl_outer_dispatch, impl_outer_origin, parameter_types};
+use sp_core::traits::CallInWasmExt;
 use sp_core::H256;
 use sp_runtime::curve::PiecewiseLinear;
 use sp_runtime::{
     testing::Header,
     traits::{BlakeTwo256, IdentityLookup},
-    Perbill,
+    BuildStorage, Perbill,
 };
 use sp_staking::SessionIndex;
+use sp_version::RuntimeVersion;
 pub use system;
 
-use crate::{ProposalDetailsOf, ProposalEncoder};
-use proposals_engine::VotersParameters;
+use crate::{ProposalDetails, ProposalDetailsOf, ProposalEncoder};
+use codec::Encode;
+use governance::council;
+use proposals_engine::{VotersParameters, VotingWeightProvider};
 use sp_runtime::testing::TestXt;
 
 impl_outer_origin! {
-    pub enum Origin for Test {}
+    pub enum Origin for Test {
+        council
+    }
 }
 
 // Workaround for https://github.com/rust-lang/rust/issues/26925 . Remove when sorted.
@@ -29,6 +35,7 @@ parameter_types! {
     pub const AvailableBlockRatio: Perbill = Perbill::one();
     pub const MinimumPeriod: u64 = 5;
     pub const StakePoolId: [u8; 8] = *b"joystake";
+    pub const Version: RuntimeVersion = substrate_test_runtime_client::runtime::VERSION;
 }
 
 impl_outer_dispatch! {
@@ -77,22 +84,36 @@ parameter_types! {
     pub const RejectionFee: u64 = 3;
     pub const TitleMaxLength: u32 = 100;
     pub const DescriptionMaxLength: u32 = 10000;
+    pub const RationaleMaxLength: u32 = 100;
     pub const MaxActiveProposalLimit: u32 = 100;
+    pub const MaxProposalDependencies: u32 = 5;
+    pub const AmendmentPeriod: u64 = 5;
+    pub const MaxProposalAmendments: u32 = 2;
+    pub const ProposalRetentionPeriod: u64 = 20;
 }
 
 impl proposals_engine::Trait for Test {
     type Event = ();
     type ProposerOriginValidator = ();
     type VoterOriginValidator = ();
+    type VotingWeightProvider = MockVotingWeightProvider;
     type TotalVotersCounter = MockVotersParameters;
+    type VoteObserver = ();
+    type ProposalObserver = ProposalCodex;
     type ProposalId = u32;
     type StakeHandlerProvider = proposals_engine::DefaultStakeHandlerProvider;
     type CancellationFee = CancellationFee;
     type RejectionFee = RejectionFee;
     type TitleMaxLength = TitleMaxLength;
     type DescriptionMaxLength = DescriptionMaxLength;
+    type RationaleMaxLength = RationaleMaxLength;
     type MaxActiveProposalLimit = MaxActiveProposalLimit;
+    type MaxProposalDependencies = MaxProposalDependencies;
+    type AmendmentPeriod = AmendmentPeriod;
+    type MaxProposalAmendments = MaxProposalAmendments;
+    type ProposalRetentionPeriod = ProposalRetentionPeriod;
     type DispatchableCallCode = crate::Call<Test>;
+    type VetoOrigin = system::EnsureRoot<u64>;
 }
 
 impl Default for crate::Call<Test> {
@@ -106,9 +127,25 @@ impl minting::Trait for Test {
     type MintId = u64;
 }
 
+parameter_types! {
+    pub const CouncilSize: u32 = 4;
+    pub const MotionApprovalThresholdPercentage: u32 = 66;
+    pub const MaxMotionCallLength: u32 = 1000;
+    pub const MotionExpiryPeriod: u64 = 100;
+}
+
 impl governance::council::Trait for Test {
     type Event = ();
     type CouncilTermEnded = ();
+    type MotionOrigin = Origin;
+    type MotionCall = Call;
+    type MotionCallFilter = ();
+    type CouncilOrigin = council::EnsureRootOrCouncilMotion<u64>;
+    type MaxMotionCallLength = MaxMotionCallLength;
+    type MotionExpiryPeriod = MotionExpiryPeriod;
+    type CouncilSize = CouncilSize;
+    type MotionApprovalThresholdPercentage = MotionApprovalThresholdPercentage;
+    type ExecutingProposalId = ();
 }
 
 impl common::origin::ActorOriginValidator<Origin, u64, u64> for () {
@@ -129,6 +166,7 @@ parameter_types! {
 impl proposals_discussion::Trait for Test {
     type Event = ();
     type PostAuthorOriginValidator = ();
+    type CouncilOriginValidator = ();
     type ThreadId = u64;
     type PostId = u64;
     type MaxPostEditionNumber = MaxPostEditionNumber;
@@ -144,14 +182,30 @@ impl VotersParameters for MockVotersParameters {
     }
 }
 
+pub struct MockVotingWeightProvider;
+impl VotingWeightProvider<u64> for MockVotingWeightProvider {
+    fn voting_weight(_voter: &u64) -> u64 {
+        1
+    }
+
+    fn total_voting_weight() -> u64 {
+        4
+    }
+}
+
 parameter_types! {
     pub const TextProposalMaxLength: u32 = 20_000;
-    pub const RuntimeUpgradeWasmProposalMaxLength: u32 = 20_000;
+    pub const RuntimeUpgradeWasmProposalMaxLength: u32 = 5_000_000;
+    pub const RuntimeUpgradePreimageByteDeposit: u64 = 1;
+    pub const SignalProposalDeposit: u64 = 100;
 }
 
 impl governance::election::Trait for Test {
     type Event = ();
     type CouncilElected = ();
+    type RevealSubmitter = ();
+    type ManifestoContentId = u64;
+    type ManifestoContentValidator = ();
 }
 
 impl content_working_group::Trait for Test {
@@ -245,15 +299,25 @@ impl staking::SessionInterface<u64> for Test {
 }
 
 impl crate::Trait for Test {
+    type Event = ();
     type TextProposalMaxLength = TextProposalMaxLength;
     type RuntimeUpgradeWasmProposalMaxLength = RuntimeUpgradeWasmProposalMaxLength;
+    type RuntimeUpgradePreimageByteDeposit = RuntimeUpgradePreimageByteDeposit;
+    type SignalProposalDeposit = SignalProposalDeposit;
     type MembershipOriginValidator = ();
     type ProposalEncoder = ();
 }
 
 impl ProposalEncoder<Test> for () {
-    fn encode_proposal(_proposal_details: ProposalDetailsOf<Test>) -> Vec<u8> {
-        Vec::new()
+    fn encode_proposal(proposal_details: ProposalDetailsOf<Test>) -> Vec<u8> {
+        match proposal_details {
+            ProposalDetails::Batch(batched_proposal_details) => batched_proposal_details
+                .into_iter()
+                .map(Self::encode_proposal)
+                .collect::<Vec<Vec<u8>>>()
+                .encode(),
+            _ => Vec::new(),
+        }
     }
 }
 
@@ -277,7 +341,7 @@ impl system::Trait for Test {
     type MaximumExtrinsicWeight = ();
     type MaximumBlockLength = MaximumBlockLength;
     type AvailableBlockRatio = AvailableBlockRatio;
-    type Version = ();
+    type Version = Version;
     type ModuleToIndex = ();
     type AccountData = balances::AccountData<u64>;
     type OnNewAccount = ();
@@ -291,13 +355,30 @@ impl pallet_timestamp::Trait for Test {
 }
 
 pub fn initial_test_ext() -> sp_io::TestExternalities {
-    let t = system::GenesisConfig::default()
+    let mut t = system::GenesisConfig::default()
         .build_storage::<Test>()
         .unwrap();
 
+    crate::GenesisConfig::<Test> {
+        proposal_parameters: crate::proposal_parameters_with_grace_and_voting_periods(0, 0),
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
     t.into()
 }
 
+// Registers the executor of the test runtime to allow the runtime version checks of the uploaded
+// runtime upgrade code.
+pub fn initial_test_ext_with_executor() -> sp_io::TestExternalities {
+    let mut ext = initial_test_ext();
+    ext.register_extension(CallInWasmExt::new(
+        substrate_test_runtime_client::new_native_executor(),
+    ));
+
+    ext
+}
+
 pub type Staking = staking::Module<Test>;
 pub type ProposalCodex = crate::Module<Test>;
 pub type ProposalsEngine = proposals_engine::Module<Test>;
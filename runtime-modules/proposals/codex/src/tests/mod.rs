@@ -1,17 +1,23 @@
 mod mock;
 
+use codec::Encode;
 use frame_support::dispatch::{DispatchError, DispatchResult};
+use frame_support::storage::migration::{get_storage_value, put_storage_value};
 use frame_support::storage::StorageMap;
-use frame_support::traits::Currency;
+use frame_support::traits::{Currency, Get, OnFinalize, ReservableCurrency};
+use frame_support::{Blake2_128Concat, StorageHasher};
+use sp_core::H256;
+use sp_runtime::traits::{BlakeTwo256, Hash};
 use system::RawOrigin;
 
+use common::constraints::InputValidationLengthConstraint;
 use common::working_group::WorkingGroup;
+use governance::council::RecurringGrant;
 use governance::election_params::ElectionParameters;
 use hiring::ActivateOpeningAt;
 use proposals_engine::ProposalParameters;
 use working_group::OpeningPolicyCommitment;
 
-use crate::proposal_types::ProposalsConfigParameters;
 use crate::*;
 use crate::{BalanceOf, Error, ProposalDetails};
 pub use mock::*;
@@ -40,7 +46,7 @@ where
     invalid_stake_call: InvalidStakeCall,
     successful_call: SuccessfulCall,
     proposal_parameters: ProposalParameters<u64, u64>,
-    proposal_details: ProposalDetails<u64, u64, u64, u64, u64, u64, u64, u64, u64>,
+    proposal_details: ProposalDetails<u64, u64, u64, u64, u64, u64, u64, u64, u64, H256>,
 }
 
 impl<InsufficientRightsCall, EmptyStakeCall, InvalidStakeCall, SuccessfulCall>
@@ -111,6 +117,8 @@ fn create_text_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     None,
                     b"text".to_vec(),
+                    None,
+                    Vec::new(),
                 )
             },
             empty_stake_call: || {
@@ -121,6 +129,8 @@ fn create_text_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     None,
                     b"text".to_vec(),
+                    None,
+                    Vec::new(),
                 )
             },
             invalid_stake_call: || {
@@ -131,6 +141,8 @@ fn create_text_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5000u32)),
                     b"text".to_vec(),
+                    None,
+                    Vec::new(),
                 )
             },
             successful_call: || {
@@ -141,9 +153,11 @@ fn create_text_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(25000u32)),
                     b"text".to_vec(),
+                    None,
+                    Vec::new(),
                 )
             },
-            proposal_parameters: crate::proposal_types::parameters::text_proposal::<Test>(),
+            proposal_parameters: ProposalCodex::proposal_parameters_by_type(TEXT_PROPOSAL_TYPE),
             proposal_details: ProposalDetails::Text(b"text".to_vec()),
         };
         proposal_fixture.check_all();
@@ -164,6 +178,8 @@ fn create_text_proposal_codex_call_fails_with_incorrect_text_size() {
                 b"body".to_vec(),
                 None,
                 long_text,
+                None,
+                Vec::new(),
             ),
             Err(Error::<Test>::TextProposalSizeExceeded.into())
         );
@@ -176,12 +192,35 @@ fn create_text_proposal_codex_call_fails_with_incorrect_text_size() {
                 b"body".to_vec(),
                 None,
                 Vec::new(),
+                None,
+                Vec::new(),
             ),
             Err(Error::<Test>::TextProposalIsEmpty.into())
         );
     });
 }
 
+fn wasm_hash() -> H256 {
+    BlakeTwo256::hash(b"wasm")
+}
+
+fn test_runtime_wasm() -> Vec<u8> {
+    substrate_test_runtime_client::runtime::WASM_BINARY.to_vec()
+}
+
+fn create_runtime_upgrade_proposal(wasm_hash: H256) -> DispatchResult {
+    ProposalCodex::create_runtime_upgrade_proposal(
+        RawOrigin::Signed(1).into(),
+        1,
+        b"title".to_vec(),
+        b"body".to_vec(),
+        Some(<BalanceOf<Test>>::from(1_000_000_u32)),
+        wasm_hash,
+        None,
+        Vec::new(),
+    )
+}
+
 #[test]
 fn create_runtime_upgrade_common_checks_succeed() {
     initial_test_ext().execute_with(|| {
@@ -195,7 +234,9 @@ fn create_runtime_upgrade_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
-                    b"wasm".to_vec(),
+                    wasm_hash(),
+                    None,
+                    Vec::new(),
                 )
             },
             empty_stake_call: || {
@@ -205,7 +246,9 @@ fn create_runtime_upgrade_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
-                    b"wasm".to_vec(),
+                    wasm_hash(),
+                    None,
+                    Vec::new(),
                 )
             },
             invalid_stake_call: || {
@@ -215,7 +258,9 @@ fn create_runtime_upgrade_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(500u32)),
-                    b"wasm".to_vec(),
+                    wasm_hash(),
+                    None,
+                    Vec::new(),
                 )
             },
             successful_call: || {
@@ -225,44 +270,203 @@ fn create_runtime_upgrade_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(1_000_000_u32)),
-                    b"wasm".to_vec(),
+                    wasm_hash(),
+                    None,
+                    Vec::new(),
                 )
             },
-            proposal_parameters: crate::proposal_types::parameters::runtime_upgrade_proposal::<Test>(),
-            proposal_details: ProposalDetails::RuntimeUpgrade(b"wasm".to_vec()),
+            proposal_parameters: ProposalCodex::proposal_parameters_by_type(
+                RUNTIME_UPGRADE_PROPOSAL_TYPE,
+            ),
+            proposal_details: ProposalDetails::RuntimeUpgrade(wasm_hash()),
         };
         proposal_fixture.check_all();
     });
 }
 
 #[test]
-fn create_upgrade_runtime_proposal_codex_call_fails_with_incorrect_wasm_size() {
-    initial_test_ext().execute_with(|| {
-        let origin = RawOrigin::Signed(1).into();
+fn upload_runtime_upgrade_preimage_succeeds() {
+    initial_test_ext_with_executor().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 5000000);
+
+        let wasm = test_runtime_wasm();
+        let wasm_hash = BlakeTwo256::hash(&wasm);
+        assert_eq!(create_runtime_upgrade_proposal(wasm_hash), Ok(()));
+
+        let uploader_account_id = 2;
+        let deposit = wasm.len() as u64 * RuntimeUpgradePreimageByteDeposit::get();
+        increase_total_balance_issuance_using_account_id(uploader_account_id, deposit);
 
-        let long_wasm = [0u8; 30000].to_vec();
         assert_eq!(
-            ProposalCodex::create_runtime_upgrade_proposal(
-                origin,
-                1,
-                b"title".to_vec(),
-                b"body".to_vec(),
-                None,
-                long_wasm,
+            ProposalCodex::upload_runtime_upgrade_preimage(
+                RawOrigin::Signed(uploader_account_id).into(),
+                wasm.clone()
+            ),
+            Ok(())
+        );
+
+        assert_eq!(ProposalCodex::runtime_upgrade_preimage(wasm_hash), wasm);
+        assert_eq!(
+            ProposalCodex::runtime_upgrade_preimage_deposit(wasm_hash),
+            Some((uploader_account_id, deposit))
+        );
+        assert_eq!(Balances::reserved_balance(uploader_account_id), deposit);
+
+        assert_eq!(
+            ProposalCodex::upload_runtime_upgrade_preimage(RawOrigin::Signed(1).into(), wasm),
+            Err(Error::<Test>::RuntimeUpgradePreimageAlreadyUploaded.into())
+        );
+    });
+}
+
+#[test]
+fn upload_runtime_upgrade_preimage_fails_with_insufficient_balance_for_deposit() {
+    initial_test_ext_with_executor().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 5000000);
+
+        let wasm = test_runtime_wasm();
+        let wasm_hash = BlakeTwo256::hash(&wasm);
+        assert_eq!(create_runtime_upgrade_proposal(wasm_hash), Ok(()));
+
+        let uploader_account_id = 2;
+        let deposit = wasm.len() as u64 * RuntimeUpgradePreimageByteDeposit::get();
+        increase_total_balance_issuance_using_account_id(uploader_account_id, deposit - 1);
+
+        assert_eq!(
+            ProposalCodex::upload_runtime_upgrade_preimage(
+                RawOrigin::Signed(uploader_account_id).into(),
+                wasm
             ),
+            Err(Error::<Test>::InsufficientBalanceForPreimageDeposit.into())
+        );
+
+        assert!(!<RuntimeUpgradePreimages<Test>>::contains_key(wasm_hash));
+        assert_eq!(Balances::reserved_balance(uploader_account_id), 0);
+    });
+}
+
+#[test]
+fn upload_runtime_upgrade_preimage_fails_with_invalid_parameters() {
+    initial_test_ext_with_executor().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 5000000);
+
+        let upload_preimage = |wasm| {
+            ProposalCodex::upload_runtime_upgrade_preimage(RawOrigin::Signed(1).into(), wasm)
+        };
+
+        assert_eq!(
+            upload_preimage(b"wasm".to_vec()),
+            Err(Error::<Test>::RuntimeUpgradeCodeHashNotProposed.into())
+        );
+
+        assert_eq!(create_runtime_upgrade_proposal(wasm_hash()), Ok(()));
+        assert!(<ProposedRuntimeUpgradeCodeHashes<Test>>::contains_key(
+            wasm_hash()
+        ));
+
+        assert_eq!(
+            upload_preimage(b"wasm".to_vec()),
+            Err(Error::<Test>::InvalidRuntimeUpgradeCode.into())
+        );
+
+        let long_wasm = vec![0u8; RuntimeUpgradeWasmProposalMaxLength::get() as usize + 1];
+        <ProposedRuntimeUpgradeCodeHashes<Test>>::insert(BlakeTwo256::hash(&long_wasm), 1);
+
+        assert_eq!(
+            upload_preimage(long_wasm),
             Err(Error::<Test>::RuntimeProposalSizeExceeded.into())
         );
 
+        <ProposedRuntimeUpgradeCodeHashes<Test>>::insert(BlakeTwo256::hash(&[]), 1);
+
+        assert_eq!(
+            upload_preimage(Vec::new()),
+            Err(Error::<Test>::RuntimeProposalIsEmpty.into())
+        );
+    });
+}
+
+#[test]
+fn runtime_upgrade_preimage_is_removed_after_the_last_referencing_proposal_is_released() {
+    initial_test_ext_with_executor().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 5000000);
+
+        let wasm = test_runtime_wasm();
+        let wasm_hash = BlakeTwo256::hash(&wasm);
+
+        assert_eq!(create_runtime_upgrade_proposal(wasm_hash), Ok(()));
+        assert_eq!(create_runtime_upgrade_proposal(wasm_hash), Ok(()));
+        assert_eq!(
+            ProposalCodex::proposed_runtime_upgrade_code_hashes(wasm_hash),
+            2
+        );
+
+        let uploader_account_id = 2;
+        let deposit = wasm.len() as u64 * RuntimeUpgradePreimageByteDeposit::get();
+        increase_total_balance_issuance_using_account_id(uploader_account_id, deposit);
+        assert_eq!(
+            ProposalCodex::upload_runtime_upgrade_preimage(
+                RawOrigin::Signed(uploader_account_id).into(),
+                wasm
+            ),
+            Ok(())
+        );
+
+        assert_eq!(
+            ProposalsEngine::veto_proposal(RawOrigin::Root.into(), 1),
+            Ok(())
+        );
+
+        assert_eq!(
+            ProposalCodex::proposed_runtime_upgrade_code_hashes(wasm_hash),
+            1
+        );
+        assert!(<RuntimeUpgradePreimages<Test>>::contains_key(wasm_hash));
+        assert_eq!(Balances::reserved_balance(uploader_account_id), deposit);
+
+        let amended_wasm_hash = BlakeTwo256::hash(b"amended wasm");
         assert_eq!(
-            ProposalCodex::create_runtime_upgrade_proposal(
+            ProposalCodex::amend_proposal(
                 RawOrigin::Signed(1).into(),
                 1,
+                2,
                 b"title".to_vec(),
                 b"body".to_vec(),
-                None,
-                Vec::new(),
+                ProposalDetails::RuntimeUpgrade(amended_wasm_hash),
             ),
-            Err(Error::<Test>::RuntimeProposalIsEmpty.into())
+            Ok(())
+        );
+
+        assert!(!<ProposedRuntimeUpgradeCodeHashes<Test>>::contains_key(
+            wasm_hash
+        ));
+        assert!(!<RuntimeUpgradePreimages<Test>>::contains_key(wasm_hash));
+        assert!(!<RuntimeUpgradePreimageDeposits<Test>>::contains_key(
+            wasm_hash
+        ));
+        assert_eq!(Balances::reserved_balance(uploader_account_id), 0);
+        assert_eq!(
+            ProposalCodex::proposed_runtime_upgrade_code_hashes(amended_wasm_hash),
+            1
+        );
+
+        assert_eq!(
+            ProposalsEngine::veto_proposal(RawOrigin::Root.into(), 2),
+            Ok(())
+        );
+
+        assert!(!<ProposedRuntimeUpgradeCodeHashes<Test>>::contains_key(
+            amended_wasm_hash
+        ));
+    });
+}
+
+#[test]
+fn execute_runtime_upgrade_proposal_fails_without_preimage() {
+    initial_test_ext().execute_with(|| {
+        assert_eq!(
+            ProposalCodex::execute_runtime_upgrade_proposal(RawOrigin::Root.into(), wasm_hash()),
+            Err(Error::<Test>::RuntimeUpgradePreimageNotFound.into())
         );
     });
 }
@@ -281,6 +485,9 @@ fn create_set_election_parameters_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     None,
                     get_valid_election_parameters(),
+                    false,
+                    None,
+                    Vec::new(),
                 )
             },
             empty_stake_call: || {
@@ -291,6 +498,9 @@ fn create_set_election_parameters_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     None,
                     get_valid_election_parameters(),
+                    false,
+                    None,
+                    Vec::new(),
                 )
             },
             invalid_stake_call: || {
@@ -301,6 +511,9 @@ fn create_set_election_parameters_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(50000u32)),
                     get_valid_election_parameters(),
+                    false,
+                    None,
+                    Vec::new(),
                 )
             },
             successful_call: || {
@@ -311,12 +524,17 @@ fn create_set_election_parameters_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(200_000_u32)),
                     get_valid_election_parameters(),
+                    false,
+                    None,
+                    Vec::new(),
                 )
             },
-            proposal_parameters:
-                crate::proposal_types::parameters::set_election_parameters_proposal::<Test>(),
+            proposal_parameters: ProposalCodex::proposal_parameters_by_type(
+                SET_ELECTION_PARAMETERS_PROPOSAL_TYPE,
+            ),
             proposal_details: ProposalDetails::SetElectionParameters(
                 get_valid_election_parameters(),
+                false,
             ),
         };
         proposal_fixture.check_all();
@@ -335,6 +553,9 @@ fn assert_failed_election_parameters_call(
             b"body".to_vec(),
             Some(<BalanceOf<Test>>::from(3750u32)),
             election_parameters,
+            false,
+            None,
+            Vec::new(),
         ),
         Err(error)
     );
@@ -471,6 +692,101 @@ fn create_set_election_parameters_call_fails_with_incorrect_parameters() {
     });
 }
 
+#[test]
+fn create_set_election_parameters_proposal_fails_during_election_unless_deferred() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        assert_eq!(
+            governance::election::Module::<Test>::force_start_election(RawOrigin::Root.into()),
+            Ok(())
+        );
+
+        assert_failed_election_parameters_call(
+            get_valid_election_parameters(),
+            Error::<Test>::ElectionParametersChangeDuringElection.into(),
+        );
+
+        assert_eq!(
+            ProposalCodex::create_set_election_parameters_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(200_000_u32)),
+                get_valid_election_parameters(),
+                true,
+                None,
+                Vec::new(),
+            ),
+            Ok(())
+        );
+    });
+}
+
+#[test]
+fn create_set_election_parameters_proposal_fails_with_candidacy_limit_lower_than_active_council() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let election_parameters = get_valid_election_parameters();
+        let active_council = (0..=election_parameters.candidacy_limit as u64)
+            .map(|member| governance::election::Seat {
+                member,
+                stake: 0,
+                backers: Vec::new(),
+            })
+            .collect::<Vec<_>>();
+        <governance::council::ActiveCouncil<Test>>::put(active_council);
+
+        assert_failed_election_parameters_call(
+            election_parameters,
+            Error::<Test>::CandidacyLimitLowerThanActiveCouncilSize.into(),
+        );
+    });
+}
+
+#[test]
+fn execute_set_election_parameters_proposal_revalidates_and_defers_parameters_during_election() {
+    initial_test_ext().execute_with(|| {
+        let mut election_parameters = get_valid_election_parameters();
+        election_parameters.council_size = 2;
+
+        assert_eq!(
+            ProposalCodex::execute_set_election_parameters_proposal(
+                RawOrigin::Root.into(),
+                election_parameters,
+                false,
+            ),
+            Err(Error::<Test>::InvalidCouncilElectionParameterCouncilSize.into())
+        );
+
+        assert_eq!(
+            governance::election::Module::<Test>::force_start_election(RawOrigin::Root.into()),
+            Ok(())
+        );
+
+        let election_parameters = get_valid_election_parameters();
+        assert_eq!(
+            ProposalCodex::execute_set_election_parameters_proposal(
+                RawOrigin::Root.into(),
+                election_parameters,
+                false,
+            ),
+            Ok(())
+        );
+
+        assert_eq!(
+            governance::election::Module::<Test>::pending_election_parameters(),
+            Some(election_parameters)
+        );
+        assert_ne!(
+            governance::election::Module::<Test>::council_size(),
+            election_parameters.council_size
+        );
+    });
+}
+
 #[test]
 fn create_content_working_group_mint_capacity_proposal_fails_with_invalid_parameters() {
     initial_test_ext().execute_with(|| {
@@ -484,6 +800,8 @@ fn create_content_working_group_mint_capacity_proposal_fails_with_invalid_parame
                 b"body".to_vec(),
                 Some(<BalanceOf<Test>>::from(50000u32)),
                 (crate::CONTENT_WORKING_GROUP_MINT_CAPACITY_MAX_VALUE + 1) as u64,
+                None,
+                Vec::new(),
             ),
             Err(Error::<Test>::InvalidContentWorkingGroupMintCapacity.into())
         );
@@ -504,6 +822,8 @@ fn create_set_content_working_group_mint_capacity_proposal_common_checks_succeed
                     b"body".to_vec(),
                     None,
                     0,
+                    None,
+                    Vec::new(),
                 )
             },
             empty_stake_call: || {
@@ -514,6 +834,8 @@ fn create_set_content_working_group_mint_capacity_proposal_common_checks_succeed
                     b"body".to_vec(),
                     None,
                     0,
+                    None,
+                    Vec::new(),
                 )
             },
             invalid_stake_call: || {
@@ -524,6 +846,8 @@ fn create_set_content_working_group_mint_capacity_proposal_common_checks_succeed
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5000u32)),
                     0,
+                    None,
+                    Vec::new(),
                 )
             },
             successful_call: || {
@@ -534,9 +858,13 @@ fn create_set_content_working_group_mint_capacity_proposal_common_checks_succeed
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(50000u32)),
                     10,
+                    None,
+                    Vec::new(),
                 )
             },
-            proposal_parameters: crate::proposal_types::parameters::set_content_working_group_mint_capacity_proposal::<Test>(),
+            proposal_parameters: ProposalCodex::proposal_parameters_by_type(
+                SET_CONTENT_WORKING_GROUP_MINT_CAPACITY_PROPOSAL_TYPE,
+            ),
             proposal_details: ProposalDetails::SetContentWorkingGroupMintCapacity(10),
         };
         proposal_fixture.check_all();
@@ -558,6 +886,8 @@ fn create_spending_proposal_common_checks_succeed() {
                     None,
                     20,
                     10,
+                    None,
+                    Vec::new(),
                 )
             },
             empty_stake_call: || {
@@ -569,6 +899,8 @@ fn create_spending_proposal_common_checks_succeed() {
                     None,
                     20,
                     10,
+                    None,
+                    Vec::new(),
                 )
             },
             invalid_stake_call: || {
@@ -580,6 +912,8 @@ fn create_spending_proposal_common_checks_succeed() {
                     Some(<BalanceOf<Test>>::from(5000u32)),
                     20,
                     10,
+                    None,
+                    Vec::new(),
                 )
             },
             successful_call: || {
@@ -591,15 +925,50 @@ fn create_spending_proposal_common_checks_succeed() {
                     Some(<BalanceOf<Test>>::from(25000u32)),
                     100,
                     2,
+                    None,
+                    Vec::new(),
                 )
             },
-            proposal_parameters: crate::proposal_types::parameters::spending_proposal::<Test>(),
+            proposal_parameters: ProposalCodex::proposal_parameters_by_type(SPENDING_PROPOSAL_TYPE),
             proposal_details: ProposalDetails::Spending(100, 2),
         };
         proposal_fixture.check_all();
     });
 }
 
+#[test]
+fn create_spending_proposal_requires_two_councils_for_large_balance() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        assert_eq!(
+            ProposalCodex::create_spending_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(25000u32)),
+                1_000_000,
+                2,
+                None,
+                Vec::new(),
+            ),
+            Ok(())
+        );
+
+        let proposal = ProposalsEngine::proposals(1);
+
+        assert_eq!(
+            proposal.parameters,
+            ProposalParameters {
+                constitutionality: 2,
+                ..ProposalCodex::proposal_parameters_by_type(SPENDING_PROPOSAL_TYPE)
+            }
+        );
+        assert_eq!(proposal.parameters.constitutionality, 2);
+    });
+}
+
 #[test]
 fn create_spending_proposal_call_fails_with_incorrect_balance() {
     initial_test_ext().execute_with(|| {
@@ -614,6 +983,8 @@ fn create_spending_proposal_call_fails_with_incorrect_balance() {
                 Some(<BalanceOf<Test>>::from(1250u32)),
                 0,
                 2,
+                None,
+                Vec::new(),
             ),
             Err(Error::<Test>::InvalidSpendingProposalBalance.into())
         );
@@ -627,6 +998,8 @@ fn create_spending_proposal_call_fails_with_incorrect_balance() {
                 Some(<BalanceOf<Test>>::from(1250u32)),
                 5000001,
                 2,
+                None,
+                Vec::new(),
             ),
             Err(Error::<Test>::InvalidSpendingProposalBalance.into())
         );
@@ -653,6 +1026,8 @@ fn create_set_lead_proposal_fails_with_proposed_councilor() {
                 b"body".to_vec(),
                 Some(<BalanceOf<Test>>::from(1250u32)),
                 Some((20, lead_account_id)),
+                None,
+                Vec::new(),
             ),
             Err(Error::<Test>::InvalidSetLeadParameterCannotBeCouncilor.into())
         );
@@ -673,6 +1048,8 @@ fn create_set_lead_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     None,
                     Some((20, 10)),
+                    None,
+                    Vec::new(),
                 )
             },
             empty_stake_call: || {
@@ -683,6 +1060,8 @@ fn create_set_lead_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     None,
                     Some((20, 10)),
+                    None,
+                    Vec::new(),
                 )
             },
             invalid_stake_call: || {
@@ -693,6 +1072,8 @@ fn create_set_lead_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5000u32)),
                     Some((20, 10)),
+                    None,
+                    Vec::new(),
                 )
             },
             successful_call: || {
@@ -703,9 +1084,11 @@ fn create_set_lead_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(50000u32)),
                     Some((20, 10)),
+                    None,
+                    Vec::new(),
                 )
             },
-            proposal_parameters: crate::proposal_types::parameters::set_lead_proposal::<Test>(),
+            proposal_parameters: ProposalCodex::proposal_parameters_by_type(SET_LEAD_PROPOSAL_TYPE),
             proposal_details: ProposalDetails::SetLead(Some((20, 10))),
         };
         proposal_fixture.check_all();
@@ -726,6 +1109,8 @@ fn create_set_validator_count_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     None,
                     4,
+                    None,
+                    Vec::new(),
                 )
             },
             empty_stake_call: || {
@@ -736,6 +1121,8 @@ fn create_set_validator_count_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     None,
                     4,
+                    None,
+                    Vec::new(),
                 )
             },
             invalid_stake_call: || {
@@ -746,6 +1133,8 @@ fn create_set_validator_count_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5000u32)),
                     4,
+                    None,
+                    Vec::new(),
                 )
             },
             successful_call: || {
@@ -756,11 +1145,13 @@ fn create_set_validator_count_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(100_000_u32)),
                     4,
+                    None,
+                    Vec::new(),
                 )
             },
-            proposal_parameters: crate::proposal_types::parameters::set_validator_count_proposal::<
-                Test,
-            >(),
+            proposal_parameters: ProposalCodex::proposal_parameters_by_type(
+                SET_VALIDATOR_COUNT_PROPOSAL_TYPE,
+            ),
             proposal_details: ProposalDetails::SetValidatorCount(4),
         };
         proposal_fixture.check_all();
@@ -778,6 +1169,8 @@ fn create_set_validator_count_proposal_failed_with_invalid_validator_count() {
                 b"body".to_vec(),
                 Some(<BalanceOf<Test>>::from(500u32)),
                 3,
+                None,
+                Vec::new(),
             ),
             Err(Error::<Test>::InvalidValidatorCount.into())
         );
@@ -790,6 +1183,8 @@ fn create_set_validator_count_proposal_failed_with_invalid_validator_count() {
                 b"body".to_vec(),
                 Some(<BalanceOf<Test>>::from(1001u32)),
                 3,
+                None,
+                Vec::new(),
             ),
             Err(Error::<Test>::InvalidValidatorCount.into())
         );
@@ -799,132 +1194,128 @@ fn create_set_validator_count_proposal_failed_with_invalid_validator_count() {
 #[test]
 fn set_default_proposal_parameters_succeeded() {
     initial_test_ext().execute_with(|| {
-        let p = ProposalsConfigParameters::default();
+        let text_parameters = ProposalCodex::proposal_parameters_by_type(TEXT_PROPOSAL_TYPE);
+        assert_eq!(text_parameters.voting_period, 0);
 
-        // nothing is set
-        assert_eq!(<SetValidatorCountProposalVotingPeriod<Test>>::get(), 0);
+        let proposal_parameters = default_proposal_parameters::<u64, u64>();
 
-        ProposalCodex::set_config_values(p);
+        ProposalCodex::set_proposal_parameters(proposal_parameters.clone());
 
+        for (proposal_type, parameters) in proposal_parameters {
+            assert_eq!(
+                ProposalCodex::proposal_parameters_by_type(proposal_type),
+                parameters
+            );
+        }
+
+        let text_parameters = ProposalCodex::proposal_parameters_by_type(TEXT_PROPOSAL_TYPE);
+        assert_eq!(text_parameters.voting_period, 72000);
+    });
+}
+
+#[test]
+fn migrate_proposal_parameters_keeps_stored_periods_and_seeds_missing_parameters() {
+    initial_test_ext().execute_with(|| {
+        <ProposalParametersByType<Test>>::remove(TEXT_PROPOSAL_TYPE);
+        <ProposalParametersByType<Test>>::remove(SPENDING_PROPOSAL_TYPE);
+        put_storage_value(b"ProposalCodex", b"TextProposalVotingPeriod", &[], 100u64);
+        put_storage_value(b"ProposalCodex", b"TextProposalGracePeriod", &[], 5u64);
+
+        ProposalCodex::migrate_proposal_parameters();
+
+        let text_parameters = ProposalCodex::proposal_parameters_by_type(TEXT_PROPOSAL_TYPE);
+        assert_eq!(text_parameters.voting_period, 100);
+        assert_eq!(text_parameters.grace_period, 5);
+
+        let (_, spending_parameters) = default_proposal_parameters::<u64, u64>()
+            .into_iter()
+            .find(|(proposal_type, _)| *proposal_type == SPENDING_PROPOSAL_TYPE)
+            .unwrap();
         assert_eq!(
-            <SetValidatorCountProposalVotingPeriod<Test>>::get(),
-            p.set_validator_count_proposal_voting_period as u64
-        );
-        assert_eq!(
-            <SetValidatorCountProposalGracePeriod<Test>>::get(),
-            p.set_validator_count_proposal_grace_period as u64
-        );
-        assert_eq!(
-            <RuntimeUpgradeProposalVotingPeriod<Test>>::get(),
-            p.runtime_upgrade_proposal_voting_period as u64
-        );
-        assert_eq!(
-            <RuntimeUpgradeProposalGracePeriod<Test>>::get(),
-            p.runtime_upgrade_proposal_grace_period as u64
-        );
-        assert_eq!(
-            <TextProposalVotingPeriod<Test>>::get(),
-            p.text_proposal_voting_period as u64
+            ProposalCodex::proposal_parameters_by_type(SPENDING_PROPOSAL_TYPE),
+            spending_parameters
         );
+
         assert_eq!(
-            <TextProposalGracePeriod<Test>>::get(),
-            p.text_proposal_grace_period as u64
+            get_storage_value::<u64>(b"ProposalCodex", b"TextProposalVotingPeriod", &[]),
+            None
         );
         assert_eq!(
-            <SetElectionParametersProposalVotingPeriod<Test>>::get(),
-            p.set_election_parameters_proposal_voting_period as u64
+            get_storage_value::<u64>(b"ProposalCodex", b"TextProposalGracePeriod", &[]),
+            None
         );
-        assert_eq!(
-            <SetElectionParametersProposalGracePeriod<Test>>::get(),
-            p.set_election_parameters_proposal_grace_period as u64
+    });
+}
+
+#[test]
+fn migrate_proposal_details_moves_pending_runtime_upgrade_code_to_preimages() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 5000000);
+
+        assert_eq!(create_runtime_upgrade_proposal(wasm_hash()), Ok(()));
+
+        // Emulates the proposal stored with the previous layout: the runtime upgrade code is kept
+        // in the proposal details (variant index 1) and in the proposal call code.
+        let proposal_id = 1u32;
+        let details_key = Blake2_128Concat::hash(&proposal_id.encode());
+        put_storage_value(
+            b"ProposalCodex",
+            b"ProposalDetailsByProposalId",
+            &details_key,
+            (1u8, b"wasm".to_vec()),
         );
+        <proposals_engine::DispatchableCallCode<Test>>::insert(proposal_id, b"old call".to_vec());
+        <ProposedRuntimeUpgradeCodeHashes<Test>>::remove(wasm_hash());
+
+        ProposalCodex::migrate_proposal_details();
+
         assert_eq!(
-            <SetContentWorkingGroupMintCapacityProposalVotingPeriod<Test>>::get(),
-            p.set_content_working_group_mint_capacity_proposal_voting_period as u64
+            ProposalCodex::proposal_details_by_proposal_id(proposal_id),
+            ProposalDetails::RuntimeUpgrade(wasm_hash())
         );
         assert_eq!(
-            <SetContentWorkingGroupMintCapacityProposalGracePeriod<Test>>::get(),
-            p.set_content_working_group_mint_capacity_proposal_grace_period as u64
+            ProposalCodex::runtime_upgrade_preimage(wasm_hash()),
+            b"wasm".to_vec()
         );
         assert_eq!(
-            <SetLeadProposalVotingPeriod<Test>>::get(),
-            p.set_lead_proposal_voting_period as u64
+            ProposalCodex::proposed_runtime_upgrade_code_hashes(wasm_hash()),
+            1
         );
         assert_eq!(
-            <SetLeadProposalGracePeriod<Test>>::get(),
-            p.set_lead_proposal_grace_period as u64
+            <proposals_engine::Module<Test>>::proposal_codes(proposal_id),
+            <() as ProposalEncoder<Test>>::encode_proposal(ProposalDetails::RuntimeUpgrade(
+                wasm_hash()
+            ))
         );
-        assert_eq!(
-            <SpendingProposalVotingPeriod<Test>>::get(),
-            p.spending_proposal_voting_period as u64
+    });
+}
+
+#[test]
+fn migrate_proposal_details_applies_election_parameters_on_execution() {
+    initial_test_ext().execute_with(|| {
+        // Emulates the active proposal stored with the previous layout: the election parameters
+        // without the deferring flag (variant index 2).
+        let proposal_id = 1u32;
+        put_storage_value(
+            b"ProposalCodex",
+            b"ProposalDetailsByProposalId",
+            &Blake2_128Concat::hash(&proposal_id.encode()),
+            (2u8, get_valid_election_parameters()),
         );
+        <proposals_engine::ActiveProposalIds<Test>>::insert(proposal_id, ());
+        <proposals_engine::DispatchableCallCode<Test>>::insert(proposal_id, b"old call".to_vec());
+
+        ProposalCodex::migrate_proposal_details();
+
+        let proposal_details =
+            ProposalDetails::SetElectionParameters(get_valid_election_parameters(), false);
         assert_eq!(
-            <SpendingProposalGracePeriod<Test>>::get(),
-            p.spending_proposal_grace_period as u64
+            ProposalCodex::proposal_details_by_proposal_id(proposal_id),
+            proposal_details
         );
         assert_eq!(
-            <AddWorkingGroupOpeningProposalVotingPeriod<Test>>::get(),
-            p.add_working_group_opening_proposal_voting_period as u64
-        );
-        assert_eq!(
-            <AddWorkingGroupOpeningProposalGracePeriod<Test>>::get(),
-            p.add_working_group_opening_proposal_grace_period as u64
-        );
-        assert_eq!(
-            <BeginReviewWorkingGroupLeaderApplicationsProposalVotingPeriod<Test>>::get(),
-            p.begin_review_working_group_leader_applications_proposal_voting_period as u64
-        );
-        assert_eq!(
-            <BeginReviewWorkingGroupLeaderApplicationsProposalGracePeriod<Test>>::get(),
-            p.begin_review_working_group_leader_applications_proposal_grace_period as u64
-        );
-        assert_eq!(
-            <FillWorkingGroupLeaderOpeningProposalVotingPeriod<Test>>::get(),
-            p.fill_working_group_leader_opening_proposal_voting_period as u64
-        );
-        assert_eq!(
-            <FillWorkingGroupLeaderOpeningProposalGracePeriod<Test>>::get(),
-            p.fill_working_group_leader_opening_proposal_grace_period as u64
-        );
-        assert_eq!(
-            <SetWorkingGroupMintCapacityProposalVotingPeriod<Test>>::get(),
-            p.set_working_group_mint_capacity_proposal_voting_period as u64
-        );
-        assert_eq!(
-            <SetWorkingGroupMintCapacityProposalGracePeriod<Test>>::get(),
-            p.set_working_group_mint_capacity_proposal_grace_period as u64
-        );
-        assert_eq!(
-            <DecreaseWorkingGroupLeaderStakeProposalVotingPeriod<Test>>::get(),
-            p.decrease_working_group_leader_stake_proposal_voting_period as u64
-        );
-        assert_eq!(
-            <DecreaseWorkingGroupLeaderStakeProposalGracePeriod<Test>>::get(),
-            p.decrease_working_group_leader_stake_proposal_grace_period as u64
-        );
-        assert_eq!(
-            <SlashWorkingGroupLeaderStakeProposalVotingPeriod<Test>>::get(),
-            p.slash_working_group_leader_stake_proposal_voting_period as u64
-        );
-        assert_eq!(
-            <SlashWorkingGroupLeaderStakeProposalGracePeriod<Test>>::get(),
-            p.slash_working_group_leader_stake_proposal_grace_period as u64
-        );
-        assert_eq!(
-            <SetWorkingGroupLeaderRewardProposalVotingPeriod<Test>>::get(),
-            p.set_working_group_leader_reward_proposal_voting_period as u64
-        );
-        assert_eq!(
-            <SetWorkingGroupLeaderRewardProposalGracePeriod<Test>>::get(),
-            p.set_working_group_leader_reward_proposal_grace_period as u64
-        );
-        assert_eq!(
-            <TerminateWorkingGroupLeaderRoleProposalVotingPeriod<Test>>::get(),
-            p.terminate_working_group_leader_role_proposal_voting_period as u64
-        );
-        assert_eq!(
-            <TerminateWorkingGroupLeaderRoleProposalGracePeriod<Test>>::get(),
-            p.terminate_working_group_leader_role_proposal_grace_period as u64
+            <proposals_engine::Module<Test>>::proposal_codes(proposal_id),
+            <() as ProposalEncoder<Test>>::encode_proposal(proposal_details)
         );
     });
 }
@@ -950,6 +1341,8 @@ fn create_add_working_group_leader_opening_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     None,
                     add_opening_parameters.clone(),
+                    None,
+                    Vec::new(),
                 )
             },
             empty_stake_call: || {
@@ -960,6 +1353,8 @@ fn create_add_working_group_leader_opening_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     None,
                     add_opening_parameters.clone(),
+                    None,
+                    Vec::new(),
                 )
             },
             invalid_stake_call: || {
@@ -970,6 +1365,8 @@ fn create_add_working_group_leader_opening_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5000u32)),
                     add_opening_parameters.clone(),
+                    None,
+                    Vec::new(),
                 )
             },
             successful_call: || {
@@ -980,12 +1377,16 @@ fn create_add_working_group_leader_opening_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(100_000_u32)),
                     add_opening_parameters.clone(),
+                    None,
+                    Vec::new(),
                 )
             },
-            proposal_parameters: crate::proposal_types::parameters::add_working_group_leader_opening_proposal::<
-                Test,
-            >(),
-            proposal_details: ProposalDetails::AddWorkingGroupLeaderOpening(add_opening_parameters.clone()),
+            proposal_parameters: ProposalCodex::proposal_parameters_by_type(
+                ADD_WORKING_GROUP_LEADER_OPENING_PROPOSAL_TYPE,
+            ),
+            proposal_details: ProposalDetails::AddWorkingGroupLeaderOpening(
+                add_opening_parameters.clone(),
+            ),
         };
         proposal_fixture.check_all();
     });
@@ -1007,7 +1408,9 @@ fn create_begin_review_working_group_leader_applications_proposal_common_checks_
                     b"body".to_vec(),
                     None,
                     opening_id,
-                    WorkingGroup::Storage
+                    WorkingGroup::Storage,
+                    None,
+                    Vec::new(),
                 )
             },
             empty_stake_call: || {
@@ -1018,7 +1421,9 @@ fn create_begin_review_working_group_leader_applications_proposal_common_checks_
                     b"body".to_vec(),
                     None,
                     opening_id,
-                    WorkingGroup::Storage
+                    WorkingGroup::Storage,
+                    None,
+                    Vec::new(),
                 )
             },
             invalid_stake_call: || {
@@ -1029,7 +1434,9 @@ fn create_begin_review_working_group_leader_applications_proposal_common_checks_
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5000u32)),
                     opening_id,
-                    WorkingGroup::Storage
+                    WorkingGroup::Storage,
+                    None,
+                    Vec::new(),
                 )
             },
             successful_call: || {
@@ -1040,14 +1447,18 @@ fn create_begin_review_working_group_leader_applications_proposal_common_checks_
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(25000u32)),
                     opening_id,
-                    WorkingGroup::Storage
+                    WorkingGroup::Storage,
+                    None,
+                    Vec::new(),
                 )
             },
-            proposal_parameters: crate::proposal_types::parameters::begin_review_working_group_leader_applications_proposal::<
-                Test,
-            >(),
-            proposal_details: ProposalDetails::BeginReviewWorkingGroupLeaderApplications(opening_id,
-                WorkingGroup::Storage),
+            proposal_parameters: ProposalCodex::proposal_parameters_by_type(
+                BEGIN_REVIEW_WORKING_GROUP_LEADER_APPLICATIONS_PROPOSAL_TYPE,
+            ),
+            proposal_details: ProposalDetails::BeginReviewWorkingGroupLeaderApplications(
+                opening_id,
+                WorkingGroup::Storage,
+            ),
         };
         proposal_fixture.check_all();
     });
@@ -1075,7 +1486,9 @@ fn create_fill_working_group_leader_opening_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
-                    fill_opening_parameters.clone()
+                    fill_opening_parameters.clone(),
+                    None,
+                    Vec::new(),
                 )
             },
             empty_stake_call: || {
@@ -1085,7 +1498,9 @@ fn create_fill_working_group_leader_opening_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
-                    fill_opening_parameters.clone()
+                    fill_opening_parameters.clone(),
+                    None,
+                    Vec::new(),
                 )
             },
             invalid_stake_call: || {
@@ -1095,7 +1510,9 @@ fn create_fill_working_group_leader_opening_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5000u32)),
-                    fill_opening_parameters.clone()
+                    fill_opening_parameters.clone(),
+                    None,
+                    Vec::new(),
                 )
             },
             successful_call: || {
@@ -1105,13 +1522,17 @@ fn create_fill_working_group_leader_opening_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(50000u32)),
-                    fill_opening_parameters.clone()
+                    fill_opening_parameters.clone(),
+                    None,
+                    Vec::new(),
                 )
             },
-            proposal_parameters: crate::proposal_types::parameters::fill_working_group_leader_opening_proposal::<
-                Test,
-            >(),
-            proposal_details: ProposalDetails::FillWorkingGroupLeaderOpening(fill_opening_parameters.clone()),
+            proposal_parameters: ProposalCodex::proposal_parameters_by_type(
+                FILL_WORKING_GROUP_LEADER_OPENING_PROPOSAL_TYPE,
+            ),
+            proposal_details: ProposalDetails::FillWorkingGroupLeaderOpening(
+                fill_opening_parameters.clone(),
+            ),
         };
         proposal_fixture.check_all();
     });
@@ -1131,6 +1552,8 @@ fn create_working_group_mint_capacity_proposal_fails_with_invalid_parameters() {
                 Some(<BalanceOf<Test>>::from(50000u32)),
                 (crate::WORKING_GROUP_MINT_CAPACITY_MAX_VALUE + 1) as u64,
                 WorkingGroup::Storage,
+                None,
+                Vec::new(),
             ),
             Err(Error::<Test>::InvalidWorkingGroupMintCapacity.into())
         );
@@ -1152,6 +1575,8 @@ fn create_set_working_group_mint_capacity_proposal_common_checks_succeed() {
                     None,
                     0,
                     WorkingGroup::Storage,
+                    None,
+                    Vec::new(),
                 )
             },
             empty_stake_call: || {
@@ -1163,6 +1588,8 @@ fn create_set_working_group_mint_capacity_proposal_common_checks_succeed() {
                     None,
                     0,
                     WorkingGroup::Storage,
+                    None,
+                    Vec::new(),
                 )
             },
             invalid_stake_call: || {
@@ -1174,6 +1601,8 @@ fn create_set_working_group_mint_capacity_proposal_common_checks_succeed() {
                     Some(<BalanceOf<Test>>::from(5000u32)),
                     0,
                     WorkingGroup::Storage,
+                    None,
+                    Vec::new(),
                 )
             },
             successful_call: || {
@@ -1185,11 +1614,13 @@ fn create_set_working_group_mint_capacity_proposal_common_checks_succeed() {
                     Some(<BalanceOf<Test>>::from(50000u32)),
                     10,
                     WorkingGroup::Storage,
+                    None,
+                    Vec::new(),
                 )
             },
-            proposal_parameters:
-                crate::proposal_types::parameters::set_working_group_mint_capacity_proposal::<Test>(
-                ),
+            proposal_parameters: ProposalCodex::proposal_parameters_by_type(
+                SET_WORKING_GROUP_MINT_CAPACITY_PROPOSAL_TYPE,
+            ),
             proposal_details: ProposalDetails::SetWorkingGroupMintCapacity(
                 10,
                 WorkingGroup::Storage,
@@ -1215,6 +1646,8 @@ fn create_decrease_working_group_leader_stake_proposal_common_checks_succeed() {
                     0,
                     10,
                     WorkingGroup::Storage,
+                    None,
+                    Vec::new(),
                 )
             },
             empty_stake_call: || {
@@ -1227,6 +1660,8 @@ fn create_decrease_working_group_leader_stake_proposal_common_checks_succeed() {
                     0,
                     10,
                     WorkingGroup::Storage,
+                    None,
+                    Vec::new(),
                 )
             },
             invalid_stake_call: || {
@@ -1239,6 +1674,8 @@ fn create_decrease_working_group_leader_stake_proposal_common_checks_succeed() {
                     0,
                     10,
                     WorkingGroup::Storage,
+                    None,
+                    Vec::new(),
                 )
             },
             successful_call: || {
@@ -1251,12 +1688,13 @@ fn create_decrease_working_group_leader_stake_proposal_common_checks_succeed() {
                     10,
                     10,
                     WorkingGroup::Storage,
+                    None,
+                    Vec::new(),
                 )
             },
-            proposal_parameters:
-                crate::proposal_types::parameters::decrease_working_group_leader_stake_proposal::<
-                    Test,
-                >(),
+            proposal_parameters: ProposalCodex::proposal_parameters_by_type(
+                DECREASE_WORKING_GROUP_LEADER_STAKE_PROPOSAL_TYPE,
+            ),
             proposal_details: ProposalDetails::DecreaseWorkingGroupLeaderStake(
                 10,
                 10,
@@ -1283,6 +1721,8 @@ fn create_slash_working_group_leader_stake_proposal_common_checks_succeed() {
                     0,
                     10,
                     WorkingGroup::Storage,
+                    None,
+                    Vec::new(),
                 )
             },
             empty_stake_call: || {
@@ -1295,6 +1735,8 @@ fn create_slash_working_group_leader_stake_proposal_common_checks_succeed() {
                     0,
                     10,
                     WorkingGroup::Storage,
+                    None,
+                    Vec::new(),
                 )
             },
             invalid_stake_call: || {
@@ -1307,6 +1749,8 @@ fn create_slash_working_group_leader_stake_proposal_common_checks_succeed() {
                     0,
                     10,
                     WorkingGroup::Storage,
+                    None,
+                    Vec::new(),
                 )
             },
             successful_call: || {
@@ -1319,12 +1763,13 @@ fn create_slash_working_group_leader_stake_proposal_common_checks_succeed() {
                     10,
                     10,
                     WorkingGroup::Storage,
+                    None,
+                    Vec::new(),
                 )
             },
-            proposal_parameters:
-                crate::proposal_types::parameters::slash_working_group_leader_stake_proposal::<
-                    Test,
-                >(),
+            proposal_parameters: ProposalCodex::proposal_parameters_by_type(
+                SLASH_WORKING_GROUP_LEADER_STAKE_PROPOSAL_TYPE,
+            ),
             proposal_details: ProposalDetails::SlashWorkingGroupLeaderStake(
                 10,
                 10,
@@ -1357,6 +1802,8 @@ fn slash_stake_with_zero_staking_balance_fails() {
                 10,
                 0,
                 WorkingGroup::Storage,
+                None,
+                Vec::new(),
             ),
             Err(Error::<Test>::SlashingStakeIsZero.into())
         );
@@ -1385,6 +1832,8 @@ fn decrease_stake_with_zero_staking_balance_fails() {
                 10,
                 0,
                 WorkingGroup::Storage,
+                None,
+                Vec::new(),
             ),
             Err(Error::<Test>::DecreasingStakeIsZero.into())
         );
@@ -1405,6 +1854,8 @@ fn create_set_working_group_leader_reward_proposal_common_checks_succeed() {
                     0,
                     10,
                     WorkingGroup::Storage,
+                    None,
+                    Vec::new(),
                 )
             },
             empty_stake_call: || {
@@ -1417,6 +1868,8 @@ fn create_set_working_group_leader_reward_proposal_common_checks_succeed() {
                     0,
                     10,
                     WorkingGroup::Storage,
+                    None,
+                    Vec::new(),
                 )
             },
             invalid_stake_call: || {
@@ -1429,6 +1882,8 @@ fn create_set_working_group_leader_reward_proposal_common_checks_succeed() {
                     0,
                     10,
                     WorkingGroup::Storage,
+                    None,
+                    Vec::new(),
                 )
             },
             successful_call: || {
@@ -1441,11 +1896,13 @@ fn create_set_working_group_leader_reward_proposal_common_checks_succeed() {
                     10,
                     10,
                     WorkingGroup::Storage,
+                    None,
+                    Vec::new(),
                 )
             },
-            proposal_parameters:
-                crate::proposal_types::parameters::set_working_group_leader_reward_proposal::<Test>(
-                ),
+            proposal_parameters: ProposalCodex::proposal_parameters_by_type(
+                SET_WORKING_GROUP_LEADER_REWARD_PROPOSAL_TYPE,
+            ),
             proposal_details: ProposalDetails::SetWorkingGroupLeaderReward(
                 10,
                 10,
@@ -1477,6 +1934,8 @@ fn create_terminate_working_group_leader_role_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     None,
                     terminate_role_parameters.clone(),
+                    None,
+                    Vec::new(),
                 )
             },
             empty_stake_call: || {
@@ -1487,6 +1946,8 @@ fn create_terminate_working_group_leader_role_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     None,
                     terminate_role_parameters.clone(),
+                    None,
+                    Vec::new(),
                 )
             },
             invalid_stake_call: || {
@@ -1497,6 +1958,8 @@ fn create_terminate_working_group_leader_role_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5000u32)),
                     terminate_role_parameters.clone(),
+                    None,
+                    Vec::new(),
                 )
             },
             successful_call: || {
@@ -1507,12 +1970,13 @@ fn create_terminate_working_group_leader_role_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(100_000_u32)),
                     terminate_role_parameters.clone(),
+                    None,
+                    Vec::new(),
                 )
             },
-            proposal_parameters:
-                crate::proposal_types::parameters::terminate_working_group_leader_role_proposal::<
-                    Test,
-                >(),
+            proposal_parameters: ProposalCodex::proposal_parameters_by_type(
+                TERMINATE_WORKING_GROUP_LEADER_ROLE_PROPOSAL_TYPE,
+            ),
             proposal_details: ProposalDetails::TerminateWorkingGroupLeaderRole(
                 terminate_role_parameters.clone(),
             ),
@@ -1520,3 +1984,1877 @@ fn create_terminate_working_group_leader_role_proposal_common_checks_succeed() {
         proposal_fixture.check_all();
     });
 }
+
+#[test]
+fn create_batch_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let batched_proposal_details = vec![
+            ProposalDetails::Text(b"text".to_vec()),
+            ProposalDetails::SetValidatorCount(10),
+        ];
+
+        <ProposalParametersByType<Test>>::mutate(TEXT_PROPOSAL_TYPE, |parameters| {
+            parameters.slashing_quorum_percentage = 50;
+            parameters.slashing_threshold_percentage = 70;
+        });
+
+        let text_parameters = ProposalCodex::proposal_parameters_by_type(TEXT_PROPOSAL_TYPE);
+        let validator_count_parameters =
+            ProposalCodex::proposal_parameters_by_type(SET_VALIDATOR_COUNT_PROPOSAL_TYPE);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_batch_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    batched_proposal_details.clone(),
+                    None,
+                    Vec::new(),
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_batch_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    batched_proposal_details.clone(),
+                    None,
+                    Vec::new(),
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_batch_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(25000u32)),
+                    batched_proposal_details.clone(),
+                    None,
+                    Vec::new(),
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_batch_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(100_000_u32)),
+                    batched_proposal_details.clone(),
+                    None,
+                    Vec::new(),
+                )
+            },
+            proposal_parameters: ProposalParameters {
+                voting_period: text_parameters
+                    .voting_period
+                    .max(validator_count_parameters.voting_period),
+                grace_period: text_parameters
+                    .grace_period
+                    .max(validator_count_parameters.grace_period),
+                slashing_quorum_percentage: 50,
+                slashing_threshold_percentage: 70,
+                ..validator_count_parameters
+            },
+            proposal_details: ProposalDetails::Batch(batched_proposal_details.clone()),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_batch_proposal_fails_with_invalid_batch() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let create_batch_proposal = |batched_proposal_details| {
+            ProposalCodex::create_batch_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(25000u32)),
+                batched_proposal_details,
+                None,
+                Vec::new(),
+            )
+        };
+
+        assert_eq!(
+            create_batch_proposal(Vec::new()),
+            Err(Error::<Test>::InvalidBatchProposalSize.into())
+        );
+
+        assert_eq!(
+            create_batch_proposal(vec![
+                ProposalDetails::Text(b"text".to_vec());
+                MAX_BATCH_PROPOSAL_SIZE + 1
+            ]),
+            Err(Error::<Test>::InvalidBatchProposalSize.into())
+        );
+
+        assert_eq!(
+            create_batch_proposal(vec![ProposalDetails::Batch(vec![ProposalDetails::Text(
+                b"text".to_vec()
+            )])]),
+            Err(Error::<Test>::UnsupportedBatchedProposal.into())
+        );
+
+        assert_eq!(
+            create_batch_proposal(vec![ProposalDetails::EvictStorageProvider(1)]),
+            Err(Error::<Test>::UnsupportedBatchedProposal.into())
+        );
+
+        assert_eq!(
+            create_batch_proposal(vec![
+                ProposalDetails::Text(b"text".to_vec()),
+                ProposalDetails::Text(Vec::new()),
+            ]),
+            Err(Error::<Test>::TextProposalIsEmpty.into())
+        );
+    });
+}
+
+#[test]
+fn create_text_proposal_with_dependencies_succeeds() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let create_text_proposal = |dependencies| {
+            ProposalCodex::create_text_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(25000u32)),
+                b"text".to_vec(),
+                None,
+                dependencies,
+            )
+        };
+
+        assert_eq!(
+            create_text_proposal(vec![1]),
+            Err(proposals_engine::Error::<Test>::DependencyNotFound.into())
+        );
+
+        assert_eq!(create_text_proposal(Vec::new()), Ok(()));
+        assert_eq!(create_text_proposal(vec![1]), Ok(()));
+
+        assert_eq!(ProposalsEngine::proposal_dependencies(2), vec![1]);
+    });
+}
+
+#[test]
+fn amend_text_proposal_succeeds_and_fails_with_invalid_amendment() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        assert_eq!(
+            ProposalCodex::create_text_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(25000u32)),
+                b"text".to_vec(),
+                None,
+                Vec::new(),
+            ),
+            Ok(())
+        );
+
+        let amend_proposal = |proposal_details| {
+            ProposalCodex::amend_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                1,
+                b"amended title".to_vec(),
+                b"amended body".to_vec(),
+                proposal_details,
+            )
+        };
+
+        assert_eq!(
+            amend_proposal(ProposalDetails::RuntimeUpgrade(wasm_hash())),
+            Err(Error::<Test>::InvalidProposalAmendment.into())
+        );
+
+        assert_eq!(
+            amend_proposal(ProposalDetails::Text(Vec::new())),
+            Err(Error::<Test>::TextProposalIsEmpty.into())
+        );
+
+        assert_eq!(
+            amend_proposal(ProposalDetails::Text(b"amended text".to_vec())),
+            Ok(())
+        );
+
+        assert_eq!(
+            ProposalCodex::proposal_details_by_proposal_id(1),
+            ProposalDetails::Text(b"amended text".to_vec())
+        );
+
+        let proposal = ProposalsEngine::proposals(1);
+        assert_eq!(proposal.title, b"amended title".to_vec());
+        assert_eq!(proposal.description, b"amended body".to_vec());
+        assert_eq!(ProposalsEngine::proposal_versions(1).len(), 1);
+
+        let thread_id = ProposalCodex::thread_id_by_proposal_id(1);
+        assert_eq!(
+            <proposals_discussion::ThreadById<Test>>::get(thread_id).title,
+            b"amended title".to_vec()
+        );
+    });
+}
+
+#[test]
+fn archived_proposal_details_and_discussion_get_pruned() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        assert_eq!(
+            ProposalCodex::create_text_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(25000u32)),
+                b"text".to_vec(),
+                None,
+                Vec::new(),
+            ),
+            Ok(())
+        );
+
+        let thread_id = ProposalCodex::thread_id_by_proposal_id(1);
+
+        assert_eq!(
+            ProposalsEngine::veto_proposal(RawOrigin::Root.into(), 1),
+            Ok(())
+        );
+
+        let archiving_block = System::block_number() + ProposalRetentionPeriod::get();
+        System::set_block_number(archiving_block);
+        <ProposalsEngine as OnFinalize<u64>>::on_finalize(archiving_block);
+
+        assert!(ProposalsEngine::archived_proposals(1).is_some());
+        assert!(!<ProposalDetailsByProposalId<Test>>::contains_key(1));
+        assert!(!<ThreadIdByProposalId<Test>>::contains_key(1));
+        assert!(!<proposals_discussion::ThreadById<Test>>::contains_key(
+            thread_id
+        ));
+    });
+}
+
+#[test]
+fn create_text_proposal_fails_with_exceeded_per_type_limit() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let text_proposal_type = ProposalDetailsOf::<Test>::Text(Vec::new()).proposal_type();
+
+        assert_eq!(
+            ProposalsEngine::set_max_active_proposals_per_type(
+                RawOrigin::Root.into(),
+                text_proposal_type,
+                Some(1)
+            ),
+            Ok(())
+        );
+
+        let create_text_proposal = || {
+            ProposalCodex::create_text_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(25000u32)),
+                b"text".to_vec(),
+                None,
+                Vec::new(),
+            )
+        };
+
+        assert_eq!(create_text_proposal(), Ok(()));
+        assert_eq!(
+            create_text_proposal(),
+            Err(proposals_engine::Error::<Test>::MaxActiveProposalsPerTypeExceeded.into())
+        );
+        assert_eq!(
+            ProposalsEngine::proposal_type_by_proposal_id(1),
+            text_proposal_type
+        );
+    });
+}
+
+#[test]
+fn create_update_proposal_parameters_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let text_parameters = ProposalParameters {
+            voting_period: 100,
+            ..ProposalCodex::proposal_parameters_by_type(TEXT_PROPOSAL_TYPE)
+        };
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_update_proposal_parameters_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    TEXT_PROPOSAL_TYPE,
+                    text_parameters,
+                    None,
+                    Vec::new(),
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_update_proposal_parameters_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    TEXT_PROPOSAL_TYPE,
+                    text_parameters,
+                    None,
+                    Vec::new(),
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_update_proposal_parameters_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    TEXT_PROPOSAL_TYPE,
+                    text_parameters,
+                    None,
+                    Vec::new(),
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_update_proposal_parameters_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(200_000_u32)),
+                    TEXT_PROPOSAL_TYPE,
+                    text_parameters,
+                    None,
+                    Vec::new(),
+                )
+            },
+            proposal_parameters: ProposalCodex::proposal_parameters_by_type(
+                UPDATE_PROPOSAL_PARAMETERS_PROPOSAL_TYPE,
+            ),
+            proposal_details: ProposalDetails::UpdateProposalParameters(
+                TEXT_PROPOSAL_TYPE,
+                text_parameters,
+            ),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_update_proposal_parameters_proposal_fails_with_invalid_parameters() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let text_parameters = ProposalParameters {
+            voting_period: 100,
+            ..ProposalCodex::proposal_parameters_by_type(TEXT_PROPOSAL_TYPE)
+        };
+
+        let create_proposal = |proposal_type, proposal_parameters| {
+            ProposalCodex::create_update_proposal_parameters_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(200_000_u32)),
+                proposal_type,
+                proposal_parameters,
+                None,
+                Vec::new(),
+            )
+        };
+
+        assert_eq!(
+            create_proposal(100, text_parameters),
+            Err(Error::<Test>::InvalidProposalParametersProposalType.into())
+        );
+
+        let invalid_parameters = vec![
+            ProposalParameters {
+                voting_period: 0,
+                ..text_parameters
+            },
+            ProposalParameters {
+                approval_quorum_percentage: 101,
+                ..text_parameters
+            },
+            ProposalParameters {
+                approval_threshold_percentage: 0,
+                ..text_parameters
+            },
+            ProposalParameters {
+                slashing_threshold_percentage: 101,
+                ..text_parameters
+            },
+            ProposalParameters {
+                constitutionality: 0,
+                ..text_parameters
+            },
+        ];
+
+        for parameters in invalid_parameters {
+            assert_eq!(
+                create_proposal(TEXT_PROPOSAL_TYPE, parameters),
+                Err(Error::<Test>::InvalidProposalParameters.into())
+            );
+        }
+    });
+}
+
+#[test]
+fn execute_update_proposal_parameters_proposal_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let text_parameters = ProposalParameters {
+            voting_period: 100,
+            ..ProposalCodex::proposal_parameters_by_type(TEXT_PROPOSAL_TYPE)
+        };
+
+        assert_eq!(
+            ProposalCodex::execute_update_proposal_parameters_proposal(
+                RawOrigin::Signed(1).into(),
+                TEXT_PROPOSAL_TYPE,
+                text_parameters,
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+
+        assert_eq!(
+            ProposalCodex::execute_update_proposal_parameters_proposal(
+                RawOrigin::Root.into(),
+                TEXT_PROPOSAL_TYPE,
+                text_parameters,
+            ),
+            Ok(())
+        );
+
+        assert_eq!(
+            ProposalCodex::proposal_parameters_by_type(TEXT_PROPOSAL_TYPE),
+            text_parameters
+        );
+    });
+}
+
+fn recurring_grant_parameters() -> RecurringGrantParameters<u64, u64, u64> {
+    RecurringGrantParameters {
+        destination: 2,
+        amount_per_payout: 100,
+        payout_interval: 10,
+        ends_at: 1000,
+    }
+}
+
+#[test]
+fn create_recurring_grant_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance(500000);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_recurring_grant_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    recurring_grant_parameters(),
+                    None,
+                    Vec::new(),
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_recurring_grant_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    recurring_grant_parameters(),
+                    None,
+                    Vec::new(),
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_recurring_grant_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    recurring_grant_parameters(),
+                    None,
+                    Vec::new(),
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_recurring_grant_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(25000u32)),
+                    recurring_grant_parameters(),
+                    None,
+                    Vec::new(),
+                )
+            },
+            proposal_parameters: ProposalCodex::proposal_parameters_by_type(
+                RECURRING_GRANT_PROPOSAL_TYPE,
+            ),
+            proposal_details: ProposalDetails::RecurringGrant(recurring_grant_parameters()),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_recurring_grant_proposal_fails_with_invalid_parameters() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let create_proposal = |parameters| {
+            ProposalCodex::create_recurring_grant_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(25000u32)),
+                parameters,
+                None,
+                Vec::new(),
+            )
+        };
+
+        assert_eq!(
+            create_proposal(RecurringGrantParameters {
+                amount_per_payout: 0,
+                ..recurring_grant_parameters()
+            }),
+            Err(Error::<Test>::InvalidRecurringGrantAmount.into())
+        );
+
+        assert_eq!(
+            create_proposal(RecurringGrantParameters {
+                amount_per_payout: 5_000_001,
+                ..recurring_grant_parameters()
+            }),
+            Err(Error::<Test>::InvalidRecurringGrantAmount.into())
+        );
+
+        // 100 payouts of 50_001 exceed the max spending proposal value
+        assert_eq!(
+            create_proposal(RecurringGrantParameters {
+                amount_per_payout: 50_001,
+                ..recurring_grant_parameters()
+            }),
+            Err(Error::<Test>::InvalidRecurringGrantAmount.into())
+        );
+
+        assert_eq!(
+            create_proposal(RecurringGrantParameters {
+                payout_interval: 0,
+                ..recurring_grant_parameters()
+            }),
+            Err(Error::<Test>::InvalidRecurringGrantPayoutInterval.into())
+        );
+
+        assert_eq!(
+            create_proposal(RecurringGrantParameters {
+                ends_at: 5,
+                ..recurring_grant_parameters()
+            }),
+            Err(Error::<Test>::InvalidRecurringGrantEndBlock.into())
+        );
+    });
+}
+
+#[test]
+fn create_recurring_grant_proposal_requires_two_councils_for_large_total_amount() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        // 100 payouts of 10_000 reach the large spending proposal value
+        assert_eq!(
+            ProposalCodex::create_recurring_grant_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(25000u32)),
+                RecurringGrantParameters {
+                    amount_per_payout: 10_000,
+                    ..recurring_grant_parameters()
+                },
+                None,
+                Vec::new(),
+            ),
+            Ok(())
+        );
+
+        assert_eq!(
+            ProposalsEngine::proposals(1).parameters,
+            ProposalParameters {
+                constitutionality: 2,
+                ..ProposalCodex::proposal_parameters_by_type(RECURRING_GRANT_PROPOSAL_TYPE)
+            }
+        );
+    });
+}
+
+#[test]
+fn create_cancel_recurring_grant_proposal_succeeds_and_fails_with_invalid_grant_id() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let create_proposal = || {
+            ProposalCodex::create_cancel_recurring_grant_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(25000u32)),
+                1,
+                None,
+                Vec::new(),
+            )
+        };
+
+        assert_eq!(
+            create_proposal(),
+            Err(Error::<Test>::InvalidRecurringGrantId.into())
+        );
+
+        <governance::council::RecurringGrants<Test>>::insert(1, RecurringGrant::default());
+
+        assert_eq!(create_proposal(), Ok(()));
+    });
+}
+
+fn membership_user_info_constraints() -> MembershipUserInfoConstraints {
+    MembershipUserInfoConstraints {
+        min_handle_length: 5,
+        max_handle_length: 40,
+        max_avatar_uri_length: 1024,
+        max_about_text_length: 2048,
+    }
+}
+
+fn forum_input_validation_constraints() -> ForumInputValidationConstraints {
+    let constraint = InputValidationLengthConstraint {
+        min: 10,
+        max_min_diff: 140,
+    };
+
+    ForumInputValidationConstraints {
+        category_title: constraint,
+        category_description: constraint,
+        thread_title: constraint,
+        post_text: constraint,
+        thread_moderation_rationale: constraint,
+        post_moderation_rationale: constraint,
+    }
+}
+
+#[test]
+fn create_set_new_memberships_allowed_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance(500000);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_set_new_memberships_allowed_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    false,
+                    None,
+                    Vec::new(),
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_set_new_memberships_allowed_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    false,
+                    None,
+                    Vec::new(),
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_set_new_memberships_allowed_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    false,
+                    None,
+                    Vec::new(),
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_set_new_memberships_allowed_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(25000u32)),
+                    false,
+                    None,
+                    Vec::new(),
+                )
+            },
+            proposal_parameters: ProposalCodex::proposal_parameters_by_type(
+                SET_NEW_MEMBERSHIPS_ALLOWED_PROPOSAL_TYPE,
+            ),
+            proposal_details: ProposalDetails::SetNewMembershipsAllowed(false),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_set_membership_user_info_constraints_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance(500000);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_set_membership_user_info_constraints_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    membership_user_info_constraints(),
+                    None,
+                    Vec::new(),
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_set_membership_user_info_constraints_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    membership_user_info_constraints(),
+                    None,
+                    Vec::new(),
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_set_membership_user_info_constraints_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    membership_user_info_constraints(),
+                    None,
+                    Vec::new(),
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_set_membership_user_info_constraints_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(25000u32)),
+                    membership_user_info_constraints(),
+                    None,
+                    Vec::new(),
+                )
+            },
+            proposal_parameters: ProposalCodex::proposal_parameters_by_type(
+                SET_MEMBERSHIP_USER_INFO_CONSTRAINTS_PROPOSAL_TYPE,
+            ),
+            proposal_details: ProposalDetails::SetMembershipUserInfoConstraints(
+                membership_user_info_constraints(),
+            ),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_set_membership_screening_authority_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance(500000);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_set_membership_screening_authority_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    10,
+                    None,
+                    Vec::new(),
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_set_membership_screening_authority_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    10,
+                    None,
+                    Vec::new(),
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_set_membership_screening_authority_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    10,
+                    None,
+                    Vec::new(),
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_set_membership_screening_authority_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(50000u32)),
+                    10,
+                    None,
+                    Vec::new(),
+                )
+            },
+            proposal_parameters: ProposalCodex::proposal_parameters_by_type(
+                SET_MEMBERSHIP_SCREENING_AUTHORITY_PROPOSAL_TYPE,
+            ),
+            proposal_details: ProposalDetails::SetMembershipScreeningAuthority(10),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_set_forum_sudo_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance(500000);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_set_forum_sudo_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    Some(10),
+                    None,
+                    Vec::new(),
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_set_forum_sudo_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    Some(10),
+                    None,
+                    Vec::new(),
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_set_forum_sudo_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    Some(10),
+                    None,
+                    Vec::new(),
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_set_forum_sudo_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(50000u32)),
+                    Some(10),
+                    None,
+                    Vec::new(),
+                )
+            },
+            proposal_parameters: ProposalCodex::proposal_parameters_by_type(
+                SET_FORUM_SUDO_PROPOSAL_TYPE,
+            ),
+            proposal_details: ProposalDetails::SetForumSudo(Some(10)),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_set_forum_input_validation_constraints_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance(500000);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_set_forum_input_validation_constraints_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    forum_input_validation_constraints(),
+                    None,
+                    Vec::new(),
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_set_forum_input_validation_constraints_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    forum_input_validation_constraints(),
+                    None,
+                    Vec::new(),
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_set_forum_input_validation_constraints_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    forum_input_validation_constraints(),
+                    None,
+                    Vec::new(),
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_set_forum_input_validation_constraints_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(25000u32)),
+                    forum_input_validation_constraints(),
+                    None,
+                    Vec::new(),
+                )
+            },
+            proposal_parameters: ProposalCodex::proposal_parameters_by_type(
+                SET_FORUM_INPUT_VALIDATION_CONSTRAINTS_PROPOSAL_TYPE,
+            ),
+            proposal_details: ProposalDetails::SetForumInputValidationConstraints(
+                forum_input_validation_constraints(),
+            ),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_set_max_memo_length_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance(500000);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_set_max_memo_length_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    4096,
+                    None,
+                    Vec::new(),
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_set_max_memo_length_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    4096,
+                    None,
+                    Vec::new(),
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_set_max_memo_length_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    4096,
+                    None,
+                    Vec::new(),
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_set_max_memo_length_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(25000u32)),
+                    4096,
+                    None,
+                    Vec::new(),
+                )
+            },
+            proposal_parameters: ProposalCodex::proposal_parameters_by_type(
+                SET_MAX_MEMO_LENGTH_PROPOSAL_TYPE,
+            ),
+            proposal_details: ProposalDetails::SetMaxMemoLength(4096),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_set_service_discovery_default_lifetime_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance(500000);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_set_service_discovery_default_lifetime_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    14400,
+                    None,
+                    Vec::new(),
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_set_service_discovery_default_lifetime_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    14400,
+                    None,
+                    Vec::new(),
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_set_service_discovery_default_lifetime_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    14400,
+                    None,
+                    Vec::new(),
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_set_service_discovery_default_lifetime_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(25000u32)),
+                    14400,
+                    None,
+                    Vec::new(),
+                )
+            },
+            proposal_parameters: ProposalCodex::proposal_parameters_by_type(
+                SET_SERVICE_DISCOVERY_DEFAULT_LIFETIME_PROPOSAL_TYPE,
+            ),
+            proposal_details: ProposalDetails::SetServiceDiscoveryDefaultLifetime(14400),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_platform_parameters_proposals_fail_with_invalid_parameters() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let stake = || Some(<BalanceOf<Test>>::from(25000u32));
+
+        let invalid_user_info_constraints = vec![
+            MembershipUserInfoConstraints {
+                min_handle_length: 0,
+                ..membership_user_info_constraints()
+            },
+            MembershipUserInfoConstraints {
+                min_handle_length: 50,
+                ..membership_user_info_constraints()
+            },
+            MembershipUserInfoConstraints {
+                max_about_text_length: 0,
+                ..membership_user_info_constraints()
+            },
+        ];
+
+        for user_info_constraints in invalid_user_info_constraints {
+            assert_eq!(
+                ProposalCodex::create_set_membership_user_info_constraints_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    stake(),
+                    user_info_constraints,
+                    None,
+                    Vec::new(),
+                ),
+                Err(Error::<Test>::InvalidMembershipUserInfoConstraints.into())
+            );
+        }
+
+        let invalid_forum_constraints = vec![
+            InputValidationLengthConstraint {
+                min: 0,
+                max_min_diff: 0,
+            },
+            InputValidationLengthConstraint {
+                min: 0,
+                max_min_diff: 100,
+            },
+            InputValidationLengthConstraint {
+                min: u16::max_value(),
+                max_min_diff: 1,
+            },
+        ];
+
+        for constraint in invalid_forum_constraints {
+            assert_eq!(
+                ProposalCodex::create_set_forum_input_validation_constraints_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    stake(),
+                    ForumInputValidationConstraints {
+                        post_text: constraint,
+                        ..forum_input_validation_constraints()
+                    },
+                    None,
+                    Vec::new(),
+                ),
+                Err(Error::<Test>::InvalidForumInputValidationConstraints.into())
+            );
+        }
+
+        assert_eq!(
+            ProposalCodex::create_set_max_memo_length_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                stake(),
+                0,
+                None,
+                Vec::new(),
+            ),
+            Err(Error::<Test>::InvalidMaxMemoLength.into())
+        );
+
+        assert_eq!(
+            ProposalCodex::create_set_service_discovery_default_lifetime_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                stake(),
+                599,
+                None,
+                Vec::new(),
+            ),
+            Err(Error::<Test>::InvalidServiceDiscoveryDefaultLifetime.into())
+        );
+    });
+}
+
+fn data_object_type_parameters() -> DataObjectTypeParameters {
+    DataObjectTypeParameters {
+        description: b"description".to_vec(),
+        active: true,
+    }
+}
+
+#[test]
+fn create_register_data_object_type_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance(500000);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_register_data_object_type_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    data_object_type_parameters(),
+                    None,
+                    Vec::new(),
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_register_data_object_type_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    data_object_type_parameters(),
+                    None,
+                    Vec::new(),
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_register_data_object_type_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    data_object_type_parameters(),
+                    None,
+                    Vec::new(),
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_register_data_object_type_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(25000u32)),
+                    data_object_type_parameters(),
+                    None,
+                    Vec::new(),
+                )
+            },
+            proposal_parameters: ProposalCodex::proposal_parameters_by_type(REGISTER_DATA_OBJECT_TYPE_PROPOSAL_TYPE),
+            proposal_details: ProposalDetails::RegisterDataObjectType(data_object_type_parameters()),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_update_data_object_type_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance(500000);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_update_data_object_type_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    1,
+                    data_object_type_parameters(),
+                    None,
+                    Vec::new(),
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_update_data_object_type_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    1,
+                    data_object_type_parameters(),
+                    None,
+                    Vec::new(),
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_update_data_object_type_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    1,
+                    data_object_type_parameters(),
+                    None,
+                    Vec::new(),
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_update_data_object_type_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(25000u32)),
+                    1,
+                    data_object_type_parameters(),
+                    None,
+                    Vec::new(),
+                )
+            },
+            proposal_parameters: ProposalCodex::proposal_parameters_by_type(
+                UPDATE_DATA_OBJECT_TYPE_PROPOSAL_TYPE,
+            ),
+            proposal_details: ProposalDetails::UpdateDataObjectType(
+                1,
+                data_object_type_parameters(),
+            ),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_activate_data_object_type_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance(500000);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_activate_data_object_type_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    1,
+                    None,
+                    Vec::new(),
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_activate_data_object_type_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    1,
+                    None,
+                    Vec::new(),
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_activate_data_object_type_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    1,
+                    None,
+                    Vec::new(),
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_activate_data_object_type_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(25000u32)),
+                    1,
+                    None,
+                    Vec::new(),
+                )
+            },
+            proposal_parameters: ProposalCodex::proposal_parameters_by_type(
+                ACTIVATE_DATA_OBJECT_TYPE_PROPOSAL_TYPE,
+            ),
+            proposal_details: ProposalDetails::ActivateDataObjectType(1),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_deactivate_data_object_type_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance(500000);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_deactivate_data_object_type_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    1,
+                    None,
+                    Vec::new(),
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_deactivate_data_object_type_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    1,
+                    None,
+                    Vec::new(),
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_deactivate_data_object_type_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    1,
+                    None,
+                    Vec::new(),
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_deactivate_data_object_type_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(25000u32)),
+                    1,
+                    None,
+                    Vec::new(),
+                )
+            },
+            proposal_parameters: ProposalCodex::proposal_parameters_by_type(
+                DEACTIVATE_DATA_OBJECT_TYPE_PROPOSAL_TYPE,
+            ),
+            proposal_details: ProposalDetails::DeactivateDataObjectType(1),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_set_service_discovery_bootstrap_endpoints_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance(500000);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_set_service_discovery_bootstrap_endpoints_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    vec![b"http://endpoint".to_vec()],
+                    None,
+                    Vec::new(),
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_set_service_discovery_bootstrap_endpoints_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    vec![b"http://endpoint".to_vec()],
+                    None,
+                    Vec::new(),
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_set_service_discovery_bootstrap_endpoints_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    vec![b"http://endpoint".to_vec()],
+                    None,
+                    Vec::new(),
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_set_service_discovery_bootstrap_endpoints_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(25000u32)),
+                    vec![b"http://endpoint".to_vec()],
+                    None,
+                    Vec::new(),
+                )
+            },
+            proposal_parameters: ProposalCodex::proposal_parameters_by_type(
+                SET_SERVICE_DISCOVERY_BOOTSTRAP_ENDPOINTS_PROPOSAL_TYPE,
+            ),
+            proposal_details: ProposalDetails::SetServiceDiscoveryBootstrapEndpoints(vec![
+                b"http://endpoint".to_vec(),
+            ]),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_storage_proposals_fail_with_invalid_parameters() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let stake = || Some(<BalanceOf<Test>>::from(25000u32));
+
+        let invalid_descriptions = vec![Vec::new(), vec![b'x'; 1001]];
+
+        for description in invalid_descriptions {
+            let data_object_type = DataObjectTypeParameters {
+                description,
+                active: true,
+            };
+
+            assert_eq!(
+                ProposalCodex::create_register_data_object_type_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    stake(),
+                    data_object_type.clone(),
+                    None,
+                    Vec::new(),
+                ),
+                Err(Error::<Test>::InvalidDataObjectTypeDescription.into())
+            );
+
+            assert_eq!(
+                ProposalCodex::create_update_data_object_type_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    stake(),
+                    1,
+                    data_object_type,
+                    None,
+                    Vec::new(),
+                ),
+                Err(Error::<Test>::InvalidDataObjectTypeDescription.into())
+            );
+        }
+
+        let invalid_endpoints = vec![
+            vec![b"http://endpoint".to_vec(); 21],
+            vec![Vec::new()],
+            vec![vec![b'x'; 501]],
+        ];
+
+        for endpoints in invalid_endpoints {
+            assert_eq!(
+                ProposalCodex::create_set_service_discovery_bootstrap_endpoints_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    stake(),
+                    endpoints,
+                    None,
+                    Vec::new(),
+                ),
+                Err(Error::<Test>::InvalidBootstrapEndpoints.into())
+            );
+        }
+    });
+}
+
+fn create_signal_proposal(options: Vec<Vec<u8>>) -> DispatchResult {
+    ProposalCodex::create_signal_proposal(
+        RawOrigin::Signed(1).into(),
+        1,
+        b"title".to_vec(),
+        b"body".to_vec(),
+        options,
+    )
+}
+
+fn vote_on_signal_proposal(
+    member_id: u64,
+    signal_proposal_id: SignalProposalId,
+    option_index: u32,
+) -> DispatchResult {
+    ProposalCodex::vote_on_signal_proposal(
+        RawOrigin::Signed(member_id).into(),
+        member_id,
+        signal_proposal_id,
+        option_index,
+    )
+}
+
+#[test]
+fn create_signal_proposal_and_vote_succeeds() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let mut signal_parameters =
+            ProposalCodex::proposal_parameters_by_type(SIGNAL_PROPOSAL_TYPE);
+        signal_parameters.voting_period = 10;
+        ProposalCodex::set_proposal_parameters(vec![(SIGNAL_PROPOSAL_TYPE, signal_parameters)]);
+
+        let options = vec![b"yes".to_vec(), b"no".to_vec(), b"abstain".to_vec()];
+        assert_eq!(create_signal_proposal(options.clone()), Ok(()));
+
+        let signal_proposal_id = ProposalCodex::signal_proposal_count();
+        let signal_proposal = ProposalCodex::signal_proposals(signal_proposal_id);
+        assert_eq!(signal_proposal.proposer_id, 1);
+        assert_eq!(signal_proposal.options, options);
+        assert_eq!(signal_proposal.results, vec![0, 0, 0]);
+        assert_eq!(
+            signal_proposal.voting_ends_at,
+            signal_proposal.created_at + 10
+        );
+
+        assert_eq!(vote_on_signal_proposal(1, signal_proposal_id, 0), Ok(()));
+        assert_eq!(vote_on_signal_proposal(2, signal_proposal_id, 0), Ok(()));
+        assert_eq!(vote_on_signal_proposal(3, signal_proposal_id, 2), Ok(()));
+
+        assert_eq!(
+            ProposalCodex::signal_proposals(signal_proposal_id).results,
+            vec![2, 0, 1]
+        );
+        assert_eq!(
+            ProposalCodex::signal_proposal_vote_by_member(signal_proposal_id, 3),
+            Some(2)
+        );
+    });
+}
+
+#[test]
+fn vote_on_signal_proposal_fails_with_invalid_parameters() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let mut signal_parameters =
+            ProposalCodex::proposal_parameters_by_type(SIGNAL_PROPOSAL_TYPE);
+        signal_parameters.voting_period = 10;
+        ProposalCodex::set_proposal_parameters(vec![(SIGNAL_PROPOSAL_TYPE, signal_parameters)]);
+
+        assert_eq!(
+            create_signal_proposal(vec![b"yes".to_vec(), b"no".to_vec()]),
+            Ok(())
+        );
+        let signal_proposal_id = ProposalCodex::signal_proposal_count();
+
+        assert_eq!(
+            ProposalCodex::vote_on_signal_proposal(
+                RawOrigin::None.into(),
+                1,
+                signal_proposal_id,
+                0
+            ),
+            Err(DispatchError::Other("Bad origin"))
+        );
+
+        assert_eq!(
+            vote_on_signal_proposal(1, signal_proposal_id + 1, 0),
+            Err(Error::<Test>::SignalProposalNotFound.into())
+        );
+
+        assert_eq!(
+            vote_on_signal_proposal(1, signal_proposal_id, 2),
+            Err(Error::<Test>::InvalidSignalProposalOption.into())
+        );
+
+        assert_eq!(vote_on_signal_proposal(1, signal_proposal_id, 1), Ok(()));
+        assert_eq!(
+            vote_on_signal_proposal(1, signal_proposal_id, 0),
+            Err(Error::<Test>::SignalProposalAlreadyVoted.into())
+        );
+
+        let voting_ends_at = ProposalCodex::signal_proposals(signal_proposal_id).voting_ends_at;
+        System::set_block_number(voting_ends_at);
+
+        assert_eq!(
+            vote_on_signal_proposal(2, signal_proposal_id, 0),
+            Err(Error::<Test>::SignalProposalVotingPeriodExpired.into())
+        );
+        assert_eq!(
+            ProposalCodex::signal_proposals(signal_proposal_id).results,
+            vec![0, 1]
+        );
+    });
+}
+
+#[test]
+fn create_signal_proposal_fails_with_invalid_parameters() {
+    initial_test_ext().execute_with(|| {
+        assert_eq!(
+            create_signal_proposal(vec![b"yes".to_vec(), b"no".to_vec()]),
+            Err(Error::<Test>::InsufficientBalanceForSignalProposalDeposit.into())
+        );
+
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        assert_eq!(
+            ProposalCodex::create_signal_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                Vec::new(),
+                b"body".to_vec(),
+                vec![b"yes".to_vec(), b"no".to_vec()],
+            ),
+            Err(proposals_engine::Error::<Test>::EmptyTitleProvided.into())
+        );
+
+        let invalid_options = vec![
+            vec![b"yes".to_vec()],
+            vec![b"option".to_vec(); 11],
+            vec![b"yes".to_vec(), Vec::new()],
+            vec![b"yes".to_vec(), vec![b'x'; 201]],
+        ];
+
+        for options in invalid_options {
+            assert_eq!(
+                create_signal_proposal(options),
+                Err(Error::<Test>::InvalidSignalProposalOptions.into())
+            );
+        }
+
+        assert_eq!(ProposalCodex::signal_proposal_count(), 0);
+    });
+}
+
+#[test]
+fn signal_proposal_is_pruned_and_deposit_is_refunded_at_the_voting_end() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let mut signal_parameters =
+            ProposalCodex::proposal_parameters_by_type(SIGNAL_PROPOSAL_TYPE);
+        signal_parameters.voting_period = 10;
+        ProposalCodex::set_proposal_parameters(vec![(SIGNAL_PROPOSAL_TYPE, signal_parameters)]);
+
+        assert_eq!(
+            create_signal_proposal(vec![b"yes".to_vec(), b"no".to_vec()]),
+            Ok(())
+        );
+        let signal_proposal_id = ProposalCodex::signal_proposal_count();
+        let signal_proposal = ProposalCodex::signal_proposals(signal_proposal_id);
+
+        assert_eq!(Balances::reserved_balance(1), SignalProposalDeposit::get());
+        assert_eq!(ProposalsEngine::active_proposal_count(), 1);
+        assert_eq!(
+            ProposalsEngine::active_proposal_count_by_type(SIGNAL_PROPOSAL_TYPE),
+            1
+        );
+
+        assert_eq!(vote_on_signal_proposal(2, signal_proposal_id, 1), Ok(()));
+
+        let voting_ends_at = signal_proposal.voting_ends_at;
+        System::set_block_number(voting_ends_at);
+        <ProposalCodex as OnFinalize<u64>>::on_finalize(voting_ends_at);
+
+        assert!(!<SignalProposals<Test>>::contains_key(signal_proposal_id));
+        assert!(!<SignalProposalDeposits<Test>>::contains_key(
+            signal_proposal_id
+        ));
+        assert_eq!(
+            ProposalCodex::signal_proposal_vote_by_member(signal_proposal_id, 2),
+            None
+        );
+        assert!(!<proposals_discussion::ThreadById<Test>>::contains_key(
+            signal_proposal.thread_id
+        ));
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(ProposalsEngine::active_proposal_count(), 0);
+        assert_eq!(
+            ProposalsEngine::active_proposal_count_by_type(SIGNAL_PROPOSAL_TYPE),
+            0
+        );
+    });
+}
+
+#[test]
+fn create_signal_proposal_fails_with_exceeded_per_type_limit() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        assert_eq!(
+            ProposalsEngine::set_max_active_proposals_per_type(
+                RawOrigin::Root.into(),
+                SIGNAL_PROPOSAL_TYPE,
+                Some(1)
+            ),
+            Ok(())
+        );
+
+        let options = vec![b"yes".to_vec(), b"no".to_vec()];
+        assert_eq!(create_signal_proposal(options.clone()), Ok(()));
+        assert_eq!(
+            create_signal_proposal(options),
+            Err(proposals_engine::Error::<Test>::MaxActiveProposalsPerTypeExceeded.into())
+        );
+    });
+}
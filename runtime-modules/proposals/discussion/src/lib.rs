@@ -6,15 +6,22 @@
 //!
 //! The proposals discussion module is used by the codex module to provide a platform for discussions
 //! about different proposals. It allows to create discussion threads and then add and update related
-//! posts.
+//! posts. Posts can reply to other posts of the same thread, members can react to posts, and
+//! councilors or the designated moderator can hide abusive posts.
 //!
 //! ## Supported extrinsics
-//! - [add_post](./struct.Module.html#method.add_post) - adds a post to an existing discussion thread
+//! - [add_post](./struct.Module.html#method.add_post) - adds a post or a reply to an existing discussion thread
 //! - [update_post](./struct.Module.html#method.update_post) - updates existing post
+//! - [react_to_post](./struct.Module.html#method.react_to_post) - sets or removes the member reaction to a post
+//! - [moderate_post](./struct.Module.html#method.moderate_post) - hides a post with a rationale
+//! - [set_moderator](./struct.Module.html#method.set_moderator) - sets the designated moderator
 //!
 //! ## Public API methods
 //! - [create_thread](./struct.Module.html#method.create_thread) - creates a discussion thread
 //! - [ensure_can_create_thread](./struct.Module.html#method.ensure_can_create_thread) - ensures safe thread creation
+//! - [update_thread_title](./struct.Module.html#method.update_thread_title) - updates the thread title
+//! - [ensure_can_update_thread_title](./struct.Module.html#method.ensure_can_update_thread_title) - ensures safe thread title update
+//! - [remove_thread](./struct.Module.html#method.remove_thread) - removes the thread with its posts and reactions
 //!
 //! ## Usage
 //!
@@ -44,18 +51,23 @@
 // Do not delete! Cannot be uncommented by default, because of Parity decl_module! issue.
 //#![warn(missing_docs)]
 
+mod migration;
 #[cfg(test)]
 mod tests;
 mod types;
 
 use frame_support::dispatch::{DispatchError, DispatchResult};
+use frame_support::storage::IterableStorageDoubleMap;
 use frame_support::traits::Get;
 use frame_support::{decl_error, decl_event, decl_module, decl_storage, ensure, Parameter};
 use sp_std::clone::Clone;
 use sp_std::vec::Vec;
+use system::ensure_root;
 
 use common::origin::ActorOriginValidator;
-use types::{DiscussionPost, DiscussionThread, ThreadCounter};
+use types::{DiscussionPost, DiscussionThread, ModerationAction, ThreadCounter};
+
+pub use types::PostReactionId;
 
 type MemberId<T> = <T as membership::Trait>::MemberId;
 
@@ -75,6 +87,21 @@ decl_event!(
 
         /// Emits on post update.
         PostUpdated(PostId, MemberId),
+
+        /// Emits on the thread title update.
+        ThreadTitleUpdated(ThreadId),
+
+        /// Emits on the thread removal along with its posts and reactions.
+        ThreadRemoved(ThreadId),
+
+        /// Emits on post reaction change. None means the reaction was removed.
+        PostReactionUpdated(PostId, MemberId, Option<PostReactionId>),
+
+        /// Emits on post moderation. Contains the moderator member id.
+        PostModerated(PostId, MemberId),
+
+        /// Emits on the designated moderator change.
+        ModeratorUpdated(Option<MemberId>),
     }
 );
 
@@ -90,6 +117,9 @@ pub trait Trait: system::Trait + membership::Trait {
         Self::AccountId,
     >;
 
+    /// Validates councilor member id and origin combination. Councilors can moderate posts.
+    type CouncilOriginValidator: ActorOriginValidator<Self::Origin, MemberId<Self>, Self::AccountId>;
+
     /// Discussion thread Id type
     type ThreadId: From<u64> + Into<u64> + Parameter + Default + Copy;
 
@@ -102,7 +132,7 @@ pub trait Trait: system::Trait + membership::Trait {
     /// Defines thread title length limit.
     type ThreadTitleLengthLimit: Get<u32>;
 
-    /// Defines post length limit.
+    /// Defines post length limit. Also limits the moderation rationale length.
     type PostLengthLimit: Get<u32>;
 
     /// Defines max thread by same author in a row number limit.
@@ -141,6 +171,18 @@ decl_error! {
 
         /// Require root origin in extrinsics
         RequireRootOrigin,
+
+        /// Parent post doesn't exist in the thread
+        ParentPostDoesntExist,
+
+        /// Moderated post cannot be changed
+        PostIsModerated,
+
+        /// Moderation rationale cannot be empty
+        EmptyModerationRationaleProvided,
+
+        /// Moderation rationale is too long
+        ModerationRationaleIsTooLong,
     }
 }
 
@@ -157,7 +199,7 @@ decl_storage! {
         /// Map thread id and post id to corresponding post.
         pub PostThreadIdByPostId:
             double_map hasher(blake2_128_concat) T::ThreadId, hasher(blake2_128_concat) T::PostId =>
-                DiscussionPost<MemberId<T>, T::BlockNumber, T::ThreadId>;
+                DiscussionPost<MemberId<T>, T::BlockNumber, T::ThreadId, T::PostId>;
 
         /// Count of all posts that have been created.
         pub PostCount get(fn post_count): u64;
@@ -165,6 +207,14 @@ decl_storage! {
         /// Last author thread counter (part of the antispam mechanism)
         pub LastThreadAuthorCounter get(fn last_thread_author_counter):
             Option<ThreadCounter<MemberId<T>>>;
+
+        /// Map post id and member id to the member reaction to the post.
+        pub PostReactionByPostByMember get(fn post_reaction_by_post_by_member):
+            double_map hasher(blake2_128_concat) T::PostId, hasher(blake2_128_concat) MemberId<T> =>
+                Option<PostReactionId>;
+
+        /// Designated moderator. Can moderate posts along with the councilors.
+        pub Moderator get(fn moderator): Option<MemberId<T>>;
     }
 }
 
@@ -189,13 +239,14 @@ decl_module! {
         /// Exports max thread by same author in a row number limit const.
         const MaxThreadInARowNumber: u32 = T::MaxThreadInARowNumber::get();
 
-        /// Adds a post with author origin check.
+        /// Adds a post with author origin check. The post replies to the parent post if provided.
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn add_post(
             origin,
             post_author_id: MemberId<T>,
             thread_id : T::ThreadId,
-            text : Vec<u8>
+            text : Vec<u8>,
+            parent_post_id: Option<T::PostId>
         ) {
             T::PostAuthorOriginValidator::ensure_actor_origin(
                 origin,
@@ -209,6 +260,13 @@ decl_module! {
                 Error::<T>::PostIsTooLong
             );
 
+            if let Some(parent_post_id) = parent_post_id {
+                ensure!(
+                    <PostThreadIdByPostId<T>>::contains_key(thread_id, parent_post_id),
+                    Error::<T>::ParentPostDoesntExist
+                );
+            }
+
             // mutation
 
             let next_post_count_value = Self::post_count() + 1;
@@ -221,6 +279,8 @@ decl_module! {
                 author_id: post_author_id,
                 edition_number : 0,
                 thread_id,
+                parent_post_id,
+                moderation: None,
             };
 
             let post_id = T::PostId::from(new_post_id);
@@ -255,6 +315,7 @@ decl_module! {
             let post = <PostThreadIdByPostId<T>>::get(&thread_id, &post_id);
 
             ensure!(post.author_id == post_author_id, Error::<T>::NotAuthor);
+            ensure!(post.moderation.is_none(), Error::<T>::PostIsModerated);
             ensure!(post.edition_number < T::MaxPostEditionNumber::get(),
                 Error::<T>::PostEditionNumberExceeded);
 
@@ -270,6 +331,82 @@ decl_module! {
             <PostThreadIdByPostId<T>>::insert(thread_id, post_id, new_post);
             Self::deposit_event(RawEvent::PostUpdated(post_id, post_author_id));
        }
+
+        /// Sets the member reaction to a post. None removes the reaction.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn react_to_post(
+            origin,
+            member_id: MemberId<T>,
+            thread_id: T::ThreadId,
+            post_id: T::PostId,
+            reaction: Option<PostReactionId>
+        ) {
+            T::PostAuthorOriginValidator::ensure_actor_origin(
+                origin,
+                member_id,
+            )?;
+
+            let post = Self::ensure_post_exists(thread_id, post_id)?;
+
+            ensure!(post.moderation.is_none(), Error::<T>::PostIsModerated);
+
+            // mutation
+
+            if let Some(reaction) = reaction {
+                <PostReactionByPostByMember<T>>::insert(post_id, member_id, reaction);
+            } else {
+                <PostReactionByPostByMember<T>>::remove(post_id, member_id);
+            }
+            Self::deposit_event(RawEvent::PostReactionUpdated(post_id, member_id, reaction));
+        }
+
+        /// Hides an abusive post with the rationale. Can be called by councilors and the
+        /// designated moderator.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn moderate_post(
+            origin,
+            moderator_id: MemberId<T>,
+            thread_id: T::ThreadId,
+            post_id: T::PostId,
+            rationale: Vec<u8>
+        ) {
+            Self::ensure_moderator_origin(origin, moderator_id)?;
+
+            let post = Self::ensure_post_exists(thread_id, post_id)?;
+
+            ensure!(post.moderation.is_none(), Error::<T>::PostIsModerated);
+
+            ensure!(!rationale.is_empty(), Error::<T>::EmptyModerationRationaleProvided);
+            ensure!(
+                rationale.len() as u32 <= T::PostLengthLimit::get(),
+                Error::<T>::ModerationRationaleIsTooLong
+            );
+
+            let moderated_post = DiscussionPost {
+                moderation: Some(ModerationAction {
+                    moderated_at: Self::current_block(),
+                    moderator_id,
+                    rationale,
+                }),
+                ..post
+            };
+
+            // mutation
+
+            <PostThreadIdByPostId<T>>::insert(thread_id, post_id, moderated_post);
+            Self::deposit_event(RawEvent::PostModerated(post_id, moderator_id));
+        }
+
+        /// Sets the designated moderator. Requires root origin.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn set_moderator(origin, moderator_id: Option<MemberId<T>>) {
+            ensure_root(origin)?;
+
+            // mutation
+
+            <Moderator<T>>::set(moderator_id);
+            Self::deposit_event(RawEvent::ModeratorUpdated(moderator_id));
+        }
     }
 }
 
@@ -310,11 +447,7 @@ impl<T: Trait> Module<T> {
     /// - title is valid
     /// - max thread in a row by the same author
     pub fn ensure_can_create_thread(thread_author_id: MemberId<T>, title: &[u8]) -> DispatchResult {
-        ensure!(!title.is_empty(), Error::<T>::EmptyTitleProvided);
-        ensure!(
-            title.len() as u32 <= T::ThreadTitleLengthLimit::get(),
-            Error::<T>::TitleIsTooLong
-        );
+        Self::ensure_thread_title_valid(title)?;
 
         // get new 'threads in a row' counter for the author
         let current_thread_counter = Self::get_updated_thread_counter(thread_author_id);
@@ -326,9 +459,91 @@ impl<T: Trait> Module<T> {
 
         Ok(())
     }
+
+    /// Updates the title of the existing thread. Used on the proposal amendment.
+    pub fn update_thread_title(thread_id: T::ThreadId, title: Vec<u8>) -> DispatchResult {
+        Self::ensure_can_update_thread_title(thread_id, &title)?;
+
+        <ThreadById<T>>::mutate(thread_id, |thread| thread.title = title);
+        Self::deposit_event(RawEvent::ThreadTitleUpdated(thread_id));
+
+        Ok(())
+    }
+
+    /// Ensures thread title can be updated.
+    /// Checks:
+    /// - thread exists
+    /// - title is valid
+    pub fn ensure_can_update_thread_title(thread_id: T::ThreadId, title: &[u8]) -> DispatchResult {
+        ensure!(
+            <ThreadById<T>>::contains_key(thread_id),
+            Error::<T>::ThreadDoesntExist
+        );
+
+        Self::ensure_thread_title_valid(title)
+    }
+
+    /// Removes the thread with its posts and the post reactions. Used on the proposal archiving.
+    pub fn remove_thread(thread_id: T::ThreadId) {
+        if !<ThreadById<T>>::contains_key(thread_id) {
+            return;
+        }
+
+        let post_ids: Vec<T::PostId> = <PostThreadIdByPostId<T>>::iter_prefix(thread_id)
+            .map(|(post_id, _)| post_id)
+            .collect();
+
+        for post_id in post_ids {
+            <PostReactionByPostByMember<T>>::remove_prefix(post_id);
+        }
+
+        <PostThreadIdByPostId<T>>::remove_prefix(thread_id);
+        <ThreadById<T>>::remove(thread_id);
+        Self::deposit_event(RawEvent::ThreadRemoved(thread_id));
+    }
 }
 
 impl<T: Trait> Module<T> {
+    // Ensures the thread title is not empty and not too long.
+    fn ensure_thread_title_valid(title: &[u8]) -> DispatchResult {
+        ensure!(!title.is_empty(), Error::<T>::EmptyTitleProvided);
+        ensure!(
+            title.len() as u32 <= T::ThreadTitleLengthLimit::get(),
+            Error::<T>::TitleIsTooLong
+        );
+
+        Ok(())
+    }
+
+    // Ensures the thread and the post exist and returns the post.
+    fn ensure_post_exists(
+        thread_id: T::ThreadId,
+        post_id: T::PostId,
+    ) -> Result<DiscussionPost<MemberId<T>, T::BlockNumber, T::ThreadId, T::PostId>, DispatchError>
+    {
+        ensure!(
+            <ThreadById<T>>::contains_key(thread_id),
+            Error::<T>::ThreadDoesntExist
+        );
+        ensure!(
+            <PostThreadIdByPostId<T>>::contains_key(thread_id, post_id),
+            Error::<T>::PostDoesntExist
+        );
+
+        Ok(<PostThreadIdByPostId<T>>::get(thread_id, post_id))
+    }
+
+    // Ensures the origin belongs to the designated moderator or a councilor.
+    fn ensure_moderator_origin(origin: T::Origin, moderator_id: MemberId<T>) -> DispatchResult {
+        if Self::moderator() == Some(moderator_id) {
+            T::PostAuthorOriginValidator::ensure_actor_origin(origin, moderator_id)?;
+        } else {
+            T::CouncilOriginValidator::ensure_actor_origin(origin, moderator_id)?;
+        }
+
+        Ok(())
+    }
+
     // Wrapper-function over system::block_number()
     fn current_block() -> T::BlockNumber {
         <system::Module<T>>::block_number()
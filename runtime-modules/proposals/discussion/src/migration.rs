@@ -0,0 +1,48 @@
+//! Storage migration of the discussion posts created before the replies and the moderation were
+//! introduced.
+
+use codec::{Decode, Encode};
+use frame_support::storage::migration::{put_storage_value, StorageIterator};
+use sp_std::vec::Vec;
+
+use crate::types::DiscussionPost;
+use crate::{MemberId, Module, Trait};
+
+// Post layout without the parent post and the moderation.
+#[derive(Encode, Decode)]
+struct OldDiscussionPost<PostAuthorId, BlockNumber, ThreadId> {
+    text: Vec<u8>,
+    created_at: BlockNumber,
+    updated_at: BlockNumber,
+    author_id: PostAuthorId,
+    thread_id: ThreadId,
+    edition_number: u32,
+}
+
+type OldDiscussionPostOf<T> =
+    OldDiscussionPost<MemberId<T>, <T as system::Trait>::BlockNumber, <T as Trait>::ThreadId>;
+
+impl<T: Trait> Module<T> {
+    /// Migrates the posts stored with the previous post layout. Migrated posts become top level
+    /// posts without moderation. Should be called once on the runtime upgrade.
+    pub fn migrate_posts() {
+        let old_posts: Vec<(Vec<u8>, OldDiscussionPostOf<T>)> =
+            StorageIterator::new(b"ProposalDiscussion", b"PostThreadIdByPostId").collect();
+
+        for (key, old_post) in old_posts {
+            let post: DiscussionPost<MemberId<T>, T::BlockNumber, T::ThreadId, T::PostId> =
+                DiscussionPost {
+                    text: old_post.text,
+                    created_at: old_post.created_at,
+                    updated_at: old_post.updated_at,
+                    author_id: old_post.author_id,
+                    thread_id: old_post.thread_id,
+                    edition_number: old_post.edition_number,
+                    parent_post_id: None,
+                    moderation: None,
+                };
+
+            put_storage_value(b"ProposalDiscussion", b"PostThreadIdByPostId", &key, post);
+        }
+    }
+}
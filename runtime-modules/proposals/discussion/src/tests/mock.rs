@@ -82,6 +82,7 @@ impl membership::Trait for Test {
 impl crate::Trait for Test {
     type Event = TestEvent;
     type PostAuthorOriginValidator = ();
+    type CouncilOriginValidator = CouncilMock;
     type ThreadId = u64;
     type PostId = u64;
     type MaxPostEditionNumber = MaxPostEditionNumber;
@@ -104,6 +105,22 @@ impl ActorOriginValidator<Origin, u64, u64> for () {
     }
 }
 
+// Member id and account id of the councilor.
+pub const COUNCILOR_ID: u64 = 2;
+
+pub struct CouncilMock;
+impl ActorOriginValidator<Origin, u64, u64> for CouncilMock {
+    fn ensure_actor_origin(origin: Origin, actor_id: u64) -> Result<u64, &'static str> {
+        let account_id = system::ensure_signed(origin)?;
+
+        if actor_id == COUNCILOR_ID && account_id == COUNCILOR_ID {
+            return Ok(account_id);
+        }
+
+        Err("Not a councilor")
+    }
+}
+
 impl system::Trait for Test {
     type BaseCallFilter = ();
     type Origin = Origin;
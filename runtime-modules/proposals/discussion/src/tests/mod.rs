@@ -55,6 +55,8 @@ fn assert_thread_content(thread_entry: TestThreadEntry, post_entries: Vec<TestPo
             author_id: 1,
             thread_id: thread_entry.thread_id,
             edition_number: post_entry.edition_number,
+            parent_post_id: None,
+            moderation: None,
         };
 
         assert_eq!(actual_post, expected_post);
@@ -98,6 +100,7 @@ struct PostFixture {
     pub thread_id: u64,
     pub post_id: Option<u64>,
     pub author_id: u64,
+    pub parent_post_id: Option<u64>,
 }
 
 impl PostFixture {
@@ -108,6 +111,14 @@ impl PostFixture {
             thread_id,
             origin: RawOrigin::Signed(1),
             post_id: None,
+            parent_post_id: None,
+        }
+    }
+
+    fn with_parent_post_id(self, parent_post_id: u64) -> Self {
+        PostFixture {
+            parent_post_id: Some(parent_post_id),
+            ..self
         }
     }
 
@@ -140,6 +151,7 @@ impl PostFixture {
             self.author_id,
             self.thread_id,
             self.text.clone(),
+            self.parent_post_id,
         );
 
         assert_eq!(add_post_result, result);
@@ -166,6 +178,71 @@ impl PostFixture {
     fn update_post_and_assert(&mut self, result: DispatchResult) {
         self.update_post_with_text_and_assert(self.text.clone(), result);
     }
+
+    fn react_to_post_and_assert(&self, reaction: Option<PostReactionId>, result: DispatchResult) {
+        let react_result = Discussions::react_to_post(
+            self.origin.clone().into(),
+            self.author_id,
+            self.thread_id,
+            self.post_id.unwrap(),
+            reaction,
+        );
+
+        assert_eq!(react_result, result);
+    }
+}
+
+struct ModerationFixture {
+    pub origin: RawOrigin<u64>,
+    pub moderator_id: u64,
+    pub rationale: Vec<u8>,
+}
+
+impl Default for ModerationFixture {
+    fn default() -> Self {
+        ModerationFixture {
+            origin: RawOrigin::Signed(COUNCILOR_ID),
+            moderator_id: COUNCILOR_ID,
+            rationale: b"rationale".to_vec(),
+        }
+    }
+}
+
+impl ModerationFixture {
+    fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        ModerationFixture { origin, ..self }
+    }
+
+    fn with_moderator(self, moderator_id: u64) -> Self {
+        ModerationFixture {
+            moderator_id,
+            ..self
+        }
+    }
+
+    fn with_rationale(self, rationale: Vec<u8>) -> Self {
+        ModerationFixture { rationale, ..self }
+    }
+
+    fn moderate_post_and_assert(&self, thread_id: u64, post_id: u64, result: DispatchResult) {
+        let moderate_result = Discussions::moderate_post(
+            self.origin.clone().into(),
+            self.moderator_id,
+            thread_id,
+            post_id,
+            self.rationale.clone(),
+        );
+
+        assert_eq!(moderate_result, result);
+
+        if result.is_ok() {
+            let post = <PostThreadIdByPostId<Test>>::get(thread_id, post_id);
+            let moderation = post.moderation.unwrap();
+
+            assert_eq!(moderation.moderator_id, self.moderator_id);
+            assert_eq!(moderation.rationale, self.rationale);
+        }
+    }
 }
 
 #[test]
@@ -425,3 +502,284 @@ fn discussion_thread_and_post_counters_are_valid() {
         assert_eq!(Discussions::post_count(), 1);
     });
 }
+
+#[test]
+fn update_thread_title_succeeds_and_fails_with_invalid_title() {
+    initial_test_ext().execute_with(|| {
+        let thread_id = DiscussionFixture::default()
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        assert_eq!(
+            Discussions::update_thread_title(thread_id, Vec::new()),
+            Err(Error::<Test>::EmptyTitleProvided.into())
+        );
+        assert_eq!(
+            Discussions::update_thread_title(2, b"title".to_vec()),
+            Err(Error::<Test>::ThreadDoesntExist.into())
+        );
+
+        assert_eq!(
+            Discussions::update_thread_title(thread_id, b"amended title".to_vec()),
+            Ok(())
+        );
+
+        assert_thread_content(
+            TestThreadEntry {
+                thread_id,
+                title: b"amended title".to_vec(),
+            },
+            Vec::new(),
+        );
+
+        EventFixture::assert_events(vec![
+            RawEvent::ThreadCreated(1, 1),
+            RawEvent::ThreadTitleUpdated(1),
+        ]);
+    });
+}
+
+#[test]
+fn remove_thread_prunes_posts_and_reactions() {
+    initial_test_ext().execute_with(|| {
+        let thread_id = DiscussionFixture::default()
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        let mut post_fixture = PostFixture::default_for_thread(thread_id);
+        let post_id = post_fixture.add_post_and_assert(Ok(())).unwrap();
+        post_fixture.react_to_post_and_assert(Some(3), Ok(()));
+
+        Discussions::remove_thread(thread_id);
+
+        assert!(!<ThreadById<Test>>::contains_key(thread_id));
+        assert!(!<PostThreadIdByPostId<Test>>::contains_key(
+            thread_id, post_id
+        ));
+        assert_eq!(
+            Discussions::post_reaction_by_post_by_member(post_id, 1),
+            None
+        );
+
+        EventFixture::assert_events(vec![
+            RawEvent::ThreadCreated(1, 1),
+            RawEvent::PostCreated(1, 1),
+            RawEvent::PostReactionUpdated(1, 1, Some(3)),
+            RawEvent::ThreadRemoved(1),
+        ]);
+    });
+}
+
+#[test]
+fn add_reply_call_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let discussion_fixture = DiscussionFixture::default();
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        let mut post_fixture = PostFixture::default_for_thread(thread_id);
+        let post_id = post_fixture.add_post_and_assert(Ok(())).unwrap();
+
+        let mut reply_fixture =
+            PostFixture::default_for_thread(thread_id).with_parent_post_id(post_id);
+        let reply_id = reply_fixture.add_post_and_assert(Ok(())).unwrap();
+
+        let reply = <PostThreadIdByPostId<Test>>::get(thread_id, reply_id);
+        assert_eq!(reply.parent_post_id, Some(post_id));
+    });
+}
+
+#[test]
+fn add_reply_call_with_invalid_parent_post_failed() {
+    initial_test_ext().execute_with(|| {
+        let discussion_fixture = DiscussionFixture::default();
+        let thread_id1 = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+        let thread_id2 = discussion_fixture
+            .create_discussion_and_assert(Ok(2))
+            .unwrap();
+
+        let mut reply_fixture1 = PostFixture::default_for_thread(thread_id1).with_parent_post_id(1);
+        reply_fixture1.add_post_and_assert(Err(Error::<Test>::ParentPostDoesntExist.into()));
+
+        let mut post_fixture = PostFixture::default_for_thread(thread_id1);
+        let post_id = post_fixture.add_post_and_assert(Ok(())).unwrap();
+
+        let mut reply_fixture2 =
+            PostFixture::default_for_thread(thread_id2).with_parent_post_id(post_id);
+        reply_fixture2.add_post_and_assert(Err(Error::<Test>::ParentPostDoesntExist.into()));
+    });
+}
+
+#[test]
+fn react_to_post_call_succeeds() {
+    initial_test_ext().execute_with(|| {
+        run_to_block(1);
+
+        let discussion_fixture = DiscussionFixture::default();
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        let mut post_fixture = PostFixture::default_for_thread(thread_id);
+        let post_id = post_fixture.add_post_and_assert(Ok(())).unwrap();
+
+        post_fixture.react_to_post_and_assert(Some(3), Ok(()));
+        assert_eq!(
+            Discussions::post_reaction_by_post_by_member(post_id, 1),
+            Some(3)
+        );
+
+        post_fixture.react_to_post_and_assert(None, Ok(()));
+        assert_eq!(
+            Discussions::post_reaction_by_post_by_member(post_id, 1),
+            None
+        );
+
+        EventFixture::assert_events(vec![
+            RawEvent::ThreadCreated(1, 1),
+            RawEvent::PostCreated(1, 1),
+            RawEvent::PostReactionUpdated(1, 1, Some(3)),
+            RawEvent::PostReactionUpdated(1, 1, None),
+        ]);
+    });
+}
+
+#[test]
+fn react_to_post_call_failed() {
+    initial_test_ext().execute_with(|| {
+        let discussion_fixture = DiscussionFixture::default();
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        let mut post_fixture = PostFixture::default_for_thread(thread_id);
+        post_fixture.add_post_and_assert(Ok(()));
+
+        PostFixture::default_for_thread(thread_id)
+            .with_author(2)
+            .change_post_id(1)
+            .react_to_post_and_assert(Some(1), Err(DispatchError::Other("Invalid author")));
+
+        PostFixture::default_for_thread(thread_id)
+            .change_post_id(2)
+            .react_to_post_and_assert(Some(1), Err(Error::<Test>::PostDoesntExist.into()));
+
+        ModerationFixture::default().moderate_post_and_assert(thread_id, 1, Ok(()));
+
+        post_fixture.react_to_post_and_assert(Some(1), Err(Error::<Test>::PostIsModerated.into()));
+    });
+}
+
+#[test]
+fn moderate_post_call_succeeds() {
+    initial_test_ext().execute_with(|| {
+        run_to_block(1);
+
+        let discussion_fixture = DiscussionFixture::default();
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        let mut post_fixture1 = PostFixture::default_for_thread(thread_id);
+        let post_id1 = post_fixture1.add_post_and_assert(Ok(())).unwrap();
+
+        let mut post_fixture2 = PostFixture::default_for_thread(thread_id);
+        let post_id2 = post_fixture2.add_post_and_assert(Ok(())).unwrap();
+
+        // councilor
+        ModerationFixture::default().moderate_post_and_assert(thread_id, post_id1, Ok(()));
+
+        // designated moderator
+        assert_eq!(
+            Discussions::set_moderator(RawOrigin::Root.into(), Some(3)),
+            Ok(())
+        );
+        assert_eq!(Discussions::moderator(), Some(3));
+
+        ModerationFixture::default()
+            .with_origin(RawOrigin::None)
+            .with_moderator(3)
+            .moderate_post_and_assert(thread_id, post_id2, Ok(()));
+
+        post_fixture1.update_post_and_assert(Err(Error::<Test>::PostIsModerated.into()));
+
+        EventFixture::assert_events(vec![
+            RawEvent::ThreadCreated(1, 1),
+            RawEvent::PostCreated(1, 1),
+            RawEvent::PostCreated(2, 1),
+            RawEvent::PostModerated(1, COUNCILOR_ID),
+            RawEvent::ModeratorUpdated(Some(3)),
+            RawEvent::PostModerated(2, 3),
+        ]);
+    });
+}
+
+#[test]
+fn moderate_post_call_failed() {
+    initial_test_ext().execute_with(|| {
+        let discussion_fixture = DiscussionFixture::default();
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        let mut post_fixture = PostFixture::default_for_thread(thread_id);
+        let post_id = post_fixture.add_post_and_assert(Ok(())).unwrap();
+
+        ModerationFixture::default()
+            .with_origin(RawOrigin::Signed(1))
+            .with_moderator(1)
+            .moderate_post_and_assert(
+                thread_id,
+                post_id,
+                Err(DispatchError::Other("Not a councilor")),
+            );
+
+        ModerationFixture::default().moderate_post_and_assert(
+            thread_id,
+            post_id + 1,
+            Err(Error::<Test>::PostDoesntExist.into()),
+        );
+
+        ModerationFixture::default().moderate_post_and_assert(
+            thread_id + 1,
+            post_id,
+            Err(Error::<Test>::ThreadDoesntExist.into()),
+        );
+
+        ModerationFixture::default()
+            .with_rationale(Vec::new())
+            .moderate_post_and_assert(
+                thread_id,
+                post_id,
+                Err(Error::<Test>::EmptyModerationRationaleProvided.into()),
+            );
+
+        ModerationFixture::default()
+            .with_rationale([0; 2001].to_vec())
+            .moderate_post_and_assert(
+                thread_id,
+                post_id,
+                Err(Error::<Test>::ModerationRationaleIsTooLong.into()),
+            );
+
+        ModerationFixture::default().moderate_post_and_assert(thread_id, post_id, Ok(()));
+        ModerationFixture::default().moderate_post_and_assert(
+            thread_id,
+            post_id,
+            Err(Error::<Test>::PostIsModerated.into()),
+        );
+    });
+}
+
+#[test]
+fn set_moderator_call_fails_without_root() {
+    initial_test_ext().execute_with(|| {
+        assert_eq!(
+            Discussions::set_moderator(RawOrigin::Signed(1).into(), Some(1)),
+            Err(DispatchError::BadOrigin)
+        );
+    });
+}
@@ -22,7 +22,7 @@ pub struct DiscussionThread<ThreadAuthorId, BlockNumber> {
 /// Post for the discussion thread
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
-pub struct DiscussionPost<PostAuthorId, BlockNumber, ThreadId> {
+pub struct DiscussionPost<PostAuthorId, BlockNumber, ThreadId, PostId> {
     /// Text
     pub text: Vec<u8>,
 
@@ -40,8 +40,31 @@ pub struct DiscussionPost<PostAuthorId, BlockNumber, ThreadId> {
 
     /// Defines how many times this post was edited. Zero on creation.
     pub edition_number: u32,
+
+    /// Parent post id of the reply. None for the top level posts.
+    pub parent_post_id: Option<PostId>,
+
+    /// Moderation of the post. Moderated posts are hidden and cannot be changed.
+    pub moderation: Option<ModerationAction<PostAuthorId, BlockNumber>>,
 }
 
+/// Represents a moderation outcome applied to a post.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct ModerationAction<ModeratorId, BlockNumber> {
+    /// When the post was moderated.
+    pub moderated_at: BlockNumber,
+
+    /// Councilor or designated moderator which acted.
+    pub moderator_id: ModeratorId,
+
+    /// Moderation rationale
+    pub rationale: Vec<u8>,
+}
+
+/// Post reaction id. Reactions are interpreted by the UI.
+pub type PostReactionId = u8;
+
 /// Post for the discussion thread
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
 #[derive(Encode, Decode, Default, Clone, Copy, PartialEq, Eq)]
@@ -21,8 +21,24 @@
 //!
 //! ### Notes
 //!
+//! - The proposer can [amend](./struct.Module.html#method.amend_proposal) the active proposal
+//! before the first vote or during the amendment period. The votes get cleared and the previous
+//! version of the proposal is archived.
+//! - Besides the global active proposals limit, the _sudo_ can limit the number of active
+//! proposals per proposer and per [proposal type](./type.ProposalType.html).
 //! - The proposal can be [vetoed](./struct.Module.html#method.veto_proposal)
 //! anytime before the proposal execution by the _sudo_.
+//! - The proposal can depend on other proposals. It gets executed only after all its dependencies
+//! were executed successfully and fails if any of them was finalized without the execution.
+//! - Batched proposals contain a list of calls executed atomically with a single vote. The whole
+//! batch gets reverted if any of the calls fails.
+//! - The proposer can provide the [execution schedule](./enum.ExecutionSchedule.html): the exact
+//! or the earliest execution block. The approved proposal stays in the _PendingExecution_ status
+//! until the block.
+//! - Finalized proposals are moved to the proposals history after the retention period: a
+//! compact [archived proposal](./struct.ArchivedProposal.html) record keeps the decision, the
+//! execution outcome and the voting results, the rest of the proposal data and its votes get
+//! pruned. The stake left after the unstaking error is refunded on archiving.
 //! - When the proposal is created with some stake - refunding on proposal finalization with
 //! different statuses should be accomplished from the external handler from the _stake module_
 //! (_StakingEventsHandler_). Such a handler should call
@@ -30,6 +46,11 @@
 //! - If the _council_ got reelected during the proposal _voting period_ the external handler calls
 //! [reset_active_proposals](./trait.Module.html#method.reset_active_proposals) function and
 //! all voting results get cleared.
+//! - Proposals with the _constitutionality_ parameter above one must be approved by that many
+//! consecutive councils. Approved proposals wait for the next council in the
+//! _PendingConstitutionality_ status and the external handler calls
+//! [reactivate_pending_constitutionality_proposals](./struct.Module.html#method.reactivate_pending_constitutionality_proposals)
+//! on the council election to re-open the voting.
 //!
 //! ### Important abstract types to be implemented
 //! Proposals `engine` module has several abstractions to be implemented in order to work correctly.
@@ -44,15 +65,26 @@
 //! A full list of the abstractions can be found [here](./trait.Trait.html).
 //!
 //! ### Supported extrinsics
-//! - [vote](./struct.Module.html#method.vote) - registers a vote for the proposal
+//! - [vote](./struct.Module.html#method.vote) - registers or changes a vote for the proposal
 //! - [cancel_proposal](./struct.Module.html#method.cancel_proposal) - cancels the proposal (can be canceled only by owner)
 //! - [veto_proposal](./struct.Module.html#method.veto_proposal) - vetoes the proposal
+//! - [set_max_active_proposals_per_proposer](./struct.Module.html#method.set_max_active_proposals_per_proposer) - sets the active proposals limit per proposer
+//! - [set_max_active_proposals_per_type](./struct.Module.html#method.set_max_active_proposals_per_type) - sets the active proposals limit per proposal type
 //!
 //! ### Public API
 //! - [create_proposal](./struct.Module.html#method.create_proposal) - creates proposal using provided parameters
 //! - [ensure_create_proposal_parameters_are_valid](./struct.Module.html#method.ensure_create_proposal_parameters_are_valid) - ensures that we can create the proposal
 //! - [refund_proposal_stake](./struct.Module.html#method.refund_proposal_stake) - a callback for _StakingHandlerEvents_
 //! - [reset_active_proposals](./trait.Module.html#method.reset_active_proposals) - resets voting results for active proposals
+//! - [reactivate_pending_constitutionality_proposals](./struct.Module.html#method.reactivate_pending_constitutionality_proposals) - re-opens the voting for the proposals pending constitutionality
+//! - [active_proposals](./struct.Module.html#method.active_proposals) - proposals open for voting
+//! - [pending_execution_proposals](./struct.Module.html#method.pending_execution_proposals) - approved proposals pending execution
+//! - [proposal_voting_status](./struct.Module.html#method.proposal_voting_status) - quorum and threshold status of the proposal and its decision if the voting ended now
+//! - [proposal_votes](./struct.Module.html#method.proposal_votes) - current votes of the proposal
+//! - [amend_proposal](./struct.Module.html#method.amend_proposal) - amends the proposal by its proposer
+//! - [ensure_can_amend_proposal](./struct.Module.html#method.ensure_can_amend_proposal) - ensures that the proposal can be amended
+//! - [dry_run_proposal](./struct.Module.html#method.dry_run_proposal) - dispatches the proposal code without committing the changes
+//! - [proposal_history](./struct.Module.html#method.proposal_history) - a page of the archived proposals
 //!
 //! ## Usage
 //!
@@ -84,20 +116,28 @@
 //!             let encoded_proposal_code = <Call<T>>::executable_proposal().encode();
 //!
 //!             <engine::Module<T>>::ensure_create_proposal_parameters_are_valid(
+//!                 proposer_id,
+//!                 0,
 //!                 &parameters,
 //!                 &title,
 //!                 &description,
-//!                 None
+//!                 None,
+//!                 None,
+//!                 &[]
 //!             )?;
-//!             <engine::Module<T>>::create_proposal(
+//!             <engine::Module<T>>::create_proposal(engine::ProposalCreationParameters {
 //!                 account_id,
 //!                 proposer_id,
+//!                 proposal_type: 0,
 //!                 parameters,
 //!                 title,
 //!                 description,
-//!                 None,
-//!                 encoded_proposal_code
-//!             )?;
+//!                 stake_balance: None,
+//!                 encoded_dispatchable_call_code: encoded_proposal_code,
+//!                 execution_schedule: None,
+//!                 dependencies: Vec::new(),
+//!                 batch: false,
+//!             })?;
 //!         }
 //!     }
 //! }
@@ -114,14 +154,18 @@ use crate::types::ApprovedProposalData;
 use types::FinalizedProposalData;
 use types::ProposalStakeManager;
 pub use types::{
-    ActiveStake, ApprovedProposalStatus, FinalizationData, Proposal, ProposalDecisionStatus,
-    ProposalParameters, ProposalStatus, VotingResults,
+    ActiveStake, ApprovedProposalStatus, ArchivedProposal, DryRunError, ExecutionSchedule,
+    FinalizationData, Proposal, ProposalCreationParameters, ProposalDecisionStatus,
+    ProposalParameters, ProposalStatus, ProposalType, ProposalVersion, ProposalVotingStatus,
+    VotingResults,
 };
 pub use types::{BalanceOf, CurrencyOf, NegativeImbalance};
 pub use types::{DefaultStakeHandlerProvider, StakeHandler, StakeHandlerProvider};
 pub use types::{ProposalCodeDecoder, ProposalExecutable};
-pub use types::{VoteKind, VotersParameters};
+pub use types::{ProposalObserver, ProposalVoteObserver, VoteKind, VotersParameters};
+pub use types::{VotingWeightProvider, WeightedVotingResults};
 
+mod migration;
 pub(crate) mod types;
 
 #[cfg(test)]
@@ -129,12 +173,13 @@ mod tests;
 
 use codec::Decode;
 use frame_support::dispatch::{DispatchError, DispatchResult, UnfilteredDispatchable};
-use frame_support::storage::IterableStorageMap;
-use frame_support::traits::{Currency, Get};
+use frame_support::storage::{with_transaction, IterableStorageDoubleMap, IterableStorageMap};
+use frame_support::traits::{Currency, EnsureOrigin, Get};
 use frame_support::{
     decl_error, decl_event, decl_module, decl_storage, ensure, print, Parameter, StorageDoubleMap,
 };
 use sp_arithmetic::traits::Zero;
+use sp_runtime::TransactionOutcome;
 use sp_std::vec::Vec;
 use system::{ensure_root, RawOrigin};
 
@@ -159,9 +204,18 @@ pub trait Trait:
     /// Validates voter id and origin combination
     type VoterOriginValidator: ActorOriginValidator<Self::Origin, MemberId<Self>, Self::AccountId>;
 
+    /// Provides the voting weight for the proposals with the weighted voting.
+    type VotingWeightProvider: VotingWeightProvider<Self::AccountId>;
+
     /// Provides data for voting. Defines maximum voters count for the proposal.
     type TotalVotersCounter: VotersParameters;
 
+    /// Observes the proposal votes.
+    type VoteObserver: ProposalVoteObserver<Self::AccountId, Self::ProposalId>;
+
+    /// Observes the proposal finalization and archiving.
+    type ProposalObserver: ProposalObserver<Self::ProposalId>;
+
     /// Proposal Id type
     type ProposalId: From<u32> + Parameter + Default + Copy;
 
@@ -180,11 +234,31 @@ pub trait Trait:
     /// Defines max allowed proposal description length.
     type DescriptionMaxLength: Get<u32>;
 
+    /// Defines max allowed vote rationale length.
+    type RationaleMaxLength: Get<u32>;
+
     /// Defines max simultaneous active proposals number.
     type MaxActiveProposalLimit: Get<u32>;
 
+    /// Defines max number of the proposal dependencies.
+    type MaxProposalDependencies: Get<u32>;
+
+    /// Defines the period after the proposal activation when the proposer can amend the proposal
+    /// regardless of the votes.
+    type AmendmentPeriod: Get<Self::BlockNumber>;
+
+    /// Defines max number of the proposal amendments.
+    type MaxProposalAmendments: Get<u32>;
+
+    /// Defines the period after the final proposal decision (including the execution) when
+    /// the proposal gets moved to the proposals history.
+    type ProposalRetentionPeriod: Get<Self::BlockNumber>;
+
     /// Proposals executable code. Can be instantiated by external module Call enum members.
     type DispatchableCallCode: Parameter + UnfilteredDispatchable<Origin = Self::Origin> + Default;
+
+    /// Origin allowed to veto the proposals.
+    type VetoOrigin: EnsureOrigin<Self::Origin>;
 }
 
 decl_event!(
@@ -209,12 +283,24 @@ decl_event!(
         /// - New proposal status
         ProposalStatusUpdated(ProposalId, ProposalStatus<BlockNumber, StakeId, AccountId>),
 
-        /// Emits on voting for the proposal
+        /// Emits on voting for the proposal and on the vote change
         /// Params:
         /// - Voter - member id of a voter.
         /// - Id of a proposal.
         /// - Kind of vote.
-        Voted(MemberId, ProposalId, VoteKind),
+        /// - Vote rationale.
+        Voted(MemberId, ProposalId, VoteKind, Vec<u8>),
+
+        /// Emits on the proposal amendment.
+        /// Params:
+        /// - Member id of a proposer.
+        /// - Id of the amended proposal.
+        ProposalAmended(MemberId, ProposalId),
+
+        /// Emits on moving the finalized proposal to the proposals history.
+        /// Params:
+        /// - Id of the archived proposal.
+        ProposalArchived(ProposalId),
     }
 );
 
@@ -233,13 +319,19 @@ decl_error! {
         /// Description is too long
         DescriptionIsTooLong,
 
+        /// Vote rationale is too long
+        RationaleIsTooLong,
+
+        /// Scheduled execution block should come after the voting and grace periods
+        InvalidExecutionBlock,
+
         /// The proposal does not exist
         ProposalNotFound,
 
         /// Proposal is finalized already
         ProposalFinalized,
 
-        /// The proposal have been already voted on
+        /// The proposal have been already voted on with the same vote kind
         AlreadyVoted,
 
         /// Not an author
@@ -248,6 +340,12 @@ decl_error! {
         /// Max active proposals number exceeded
         MaxActiveProposalNumberExceeded,
 
+        /// Max active proposals number of the proposer exceeded
+        MaxActiveProposalsPerProposerExceeded,
+
+        /// Max active proposals number of the proposal type exceeded
+        MaxActiveProposalsPerTypeExceeded,
+
         /// Stake cannot be empty with this proposal
         EmptyStake,
 
@@ -265,6 +363,25 @@ decl_error! {
 
         /// Require root origin in extrinsics
         RequireRootOrigin,
+
+        /// The exact execution block cannot be scheduled for the proposal requiring the approval
+        /// of several councils
+        ExactExecutionBlockWithConstitutionality,
+
+        /// Max number of the proposal dependencies exceeded
+        DependenciesLimitExceeded,
+
+        /// The proposal dependency does not exist
+        DependencyNotFound,
+
+        /// Batched proposal code should be a non-empty list of calls
+        InvalidBatch,
+
+        /// The proposal can be amended only before the first vote or during the amendment period
+        AmendmentPeriodExpired,
+
+        /// Max number of the proposal amendments exceeded
+        MaxProposalAmendmentsExceeded,
     }
 }
 
@@ -285,6 +402,25 @@ decl_storage! {
         /// Count of active proposals.
         pub ActiveProposalCount get(fn active_proposal_count): u32;
 
+        /// Count of active proposals by the proposer.
+        pub ActiveProposalCountByProposer get(fn active_proposal_count_by_proposer):
+            map hasher(blake2_128_concat) MemberId<T> => u32;
+
+        /// Count of active proposals by the proposal type.
+        pub ActiveProposalCountByType get(fn active_proposal_count_by_type):
+            map hasher(blake2_128_concat) ProposalType => u32;
+
+        /// Map proposal id to its proposal type.
+        pub ProposalTypeByProposalId get(fn proposal_type_by_proposal_id):
+            map hasher(blake2_128_concat) T::ProposalId => ProposalType;
+
+        /// Max number of active proposals per proposer. No limit if not set.
+        pub MaxActiveProposalsPerProposer get(fn max_active_proposals_per_proposer): Option<u32>;
+
+        /// Max number of active proposals per proposal type. No limit if not set.
+        pub MaxActiveProposalsPerType get(fn max_active_proposals_per_type):
+            map hasher(blake2_128_concat) ProposalType => Option<u32>;
+
         /// Ids of proposals that are open for voting (have not been finalized yet).
         pub ActiveProposalIds get(fn active_proposal_ids): map hasher(blake2_128_concat)
             T::ProposalId=> ();
@@ -293,13 +429,58 @@ decl_storage! {
         pub PendingExecutionProposalIds get(fn pending_proposal_ids): map hasher(blake2_128_concat)
             T::ProposalId=> ();
 
+        /// Ids of proposals that were approved by the council and await the approval of the next
+        /// council.
+        pub PendingConstitutionalityProposalIds get(fn pending_constitutionality_proposal_ids):
+            map hasher(blake2_128_concat) T::ProposalId=> ();
+
         /// Double map for preventing duplicate votes. Should be cleaned after usage.
         pub VoteExistsByProposalByVoter get(fn vote_by_proposal_by_voter):
             double_map hasher(blake2_128_concat)  T::ProposalId, hasher(blake2_128_concat) MemberId<T> => VoteKind;
 
+        /// Vote rationales by proposal and voter. Cleaned along with the votes.
+        pub VoteRationaleByProposalByVoter get(fn vote_rationale_by_proposal_by_voter):
+            double_map hasher(blake2_128_concat)  T::ProposalId, hasher(blake2_128_concat) MemberId<T> => Vec<u8>;
+
+        /// Voting weight of the voter at the vote time for the proposals with the weighted voting.
+        pub VoteWeightByProposalByVoter get(fn vote_weight_by_proposal_by_voter):
+            double_map hasher(blake2_128_concat)  T::ProposalId, hasher(blake2_128_concat) MemberId<T> => u64;
+
+        /// Weighted voting results for the proposals with the weighted voting.
+        pub WeightedVotingResultsByProposal get(fn weighted_voting_results): map hasher(blake2_128_concat)
+            T::ProposalId => WeightedVotingResults;
+
+        /// Total voting weight snapshotted on the voting start for the proposals with the weighted
+        /// voting.
+        pub TotalVotingWeightByProposal get(fn total_voting_weight_by_proposal):
+            map hasher(blake2_128_concat) T::ProposalId => u64;
+
         /// Map proposal id by stake id. Required by StakingEventsHandler callback call
         pub StakesProposals get(fn stakes_proposals): map hasher(blake2_128_concat)
             T::StakeId =>  T::ProposalId;
+
+        /// Proposals that must be executed successfully before the proposal execution.
+        pub DependenciesByProposal get(fn proposal_dependencies): map hasher(blake2_128_concat)
+            T::ProposalId => Vec<T::ProposalId>;
+
+        /// Ids of the batched proposals. Their code is a list of calls executed atomically.
+        pub BatchedProposalIds get(fn batched_proposal_ids): map hasher(blake2_128_concat)
+            T::ProposalId => ();
+
+        /// Previous versions of the amended proposals.
+        pub ProposalVersions get(fn proposal_versions): map hasher(blake2_128_concat)
+            T::ProposalId => Vec<ProposalVersion<T::BlockNumber>>;
+
+        /// Finalized proposal ids by the block when they get moved to the proposals history.
+        pub ProposalsToArchive get(fn proposals_to_archive): double_map
+            hasher(blake2_128_concat) T::BlockNumber, hasher(blake2_128_concat) T::ProposalId => ();
+
+        /// Proposals history: compact records of the archived proposals.
+        pub ArchivedProposals get(fn archived_proposals): map hasher(blake2_128_concat)
+            T::ProposalId => Option<ArchivedProposal<T::BlockNumber, MemberId<T>>>;
+
+        /// Id of the proposal being executed. Set only during the proposal call dispatch.
+        pub ExecutingProposalId get(fn executing_proposal_id): Option<T::ProposalId>;
     }
 }
 
@@ -324,13 +505,37 @@ decl_module! {
         /// Exports const -  max allowed proposal description length.
         const DescriptionMaxLength: u32 = T::DescriptionMaxLength::get();
 
+        /// Exports const -  max allowed vote rationale length.
+        const RationaleMaxLength: u32 = T::RationaleMaxLength::get();
+
         /// Exports const -  max simultaneous active proposals number.
         const MaxActiveProposalLimit: u32 = T::MaxActiveProposalLimit::get();
 
-        /// Vote extrinsic. Conditions:  origin must allow votes.
+        /// Exports const -  max number of the proposal dependencies.
+        const MaxProposalDependencies: u32 = T::MaxProposalDependencies::get();
+
+        /// Exports const -  period after the proposal activation when the proposal can be amended.
+        const AmendmentPeriod: T::BlockNumber = T::AmendmentPeriod::get();
+
+        /// Exports const -  max number of the proposal amendments.
+        const MaxProposalAmendments: u32 = T::MaxProposalAmendments::get();
+
+        /// Exports const -  period after the final proposal decision when the proposal gets
+        /// archived.
+        const ProposalRetentionPeriod: T::BlockNumber = T::ProposalRetentionPeriod::get();
+
+        /// Vote extrinsic. Conditions:  origin must allow votes. The vote can be changed to another
+        /// vote kind while the proposal is active. Vote can carry the rationale text. Voting again
+        /// with the same vote kind updates the rationale only.
         #[weight = 10_000_000] // TODO: adjust weight
-        pub fn vote(origin, voter_id: MemberId<T>, proposal_id: T::ProposalId, vote: VoteKind)  {
-            T::VoterOriginValidator::ensure_actor_origin(
+        pub fn vote(
+            origin,
+            voter_id: MemberId<T>,
+            proposal_id: T::ProposalId,
+            vote: VoteKind,
+            rationale: Vec<u8>,
+        )  {
+            let voter_account_id = T::VoterOriginValidator::ensure_actor_origin(
                 origin,
                 voter_id,
             )?;
@@ -340,20 +545,69 @@ decl_module! {
 
             ensure!(matches!(proposal.status, ProposalStatus::Active{..}), Error::<T>::ProposalFinalized);
 
-            let did_not_vote_before = !<VoteExistsByProposalByVoter<T>>::contains_key(
+            ensure!(
+                rationale.len() as u32 <= T::RationaleMaxLength::get(),
+                Error::<T>::RationaleIsTooLong
+            );
+
+            let voted_before = <VoteExistsByProposalByVoter<T>>::contains_key(
                 proposal_id,
                 voter_id,
             );
 
-            ensure!(did_not_vote_before, Error::<T>::AlreadyVoted);
+            let previous_vote = if voted_before {
+                Some(Self::vote_by_proposal_by_voter(proposal_id, voter_id))
+            } else {
+                None
+            };
+
+            let vote_kind_changed = previous_vote.as_ref() != Some(&vote);
 
-            proposal.voting_results.add_vote(vote.clone());
+            ensure!(
+                vote_kind_changed
+                    || Self::vote_rationale_by_proposal_by_voter(proposal_id, voter_id) != rationale,
+                Error::<T>::AlreadyVoted
+            );
 
             // mutation
 
-            <Proposals<T>>::insert(proposal_id, proposal);
-            <VoteExistsByProposalByVoter<T>>::insert(proposal_id, voter_id, vote.clone());
-            Self::deposit_event(RawEvent::Voted(voter_id, proposal_id, vote));
+            if vote_kind_changed {
+                if let Some(previous_vote) = previous_vote.clone() {
+                    proposal.voting_results.remove_vote(previous_vote);
+                }
+
+                proposal.voting_results.add_vote(vote.clone());
+
+                if proposal.parameters.weighted_voting {
+                    Self::update_weighted_voting_results(
+                        proposal_id,
+                        voter_id,
+                        &voter_account_id,
+                        previous_vote,
+                        vote.clone(),
+                    );
+                }
+
+                <Proposals<T>>::insert(proposal_id, proposal);
+                <VoteExistsByProposalByVoter<T>>::insert(proposal_id, voter_id, vote.clone());
+            }
+
+            if rationale.is_empty() {
+                <VoteRationaleByProposalByVoter<T>>::remove(proposal_id, voter_id);
+            } else {
+                <VoteRationaleByProposalByVoter<T>>::insert(
+                    proposal_id,
+                    voter_id,
+                    rationale.clone(),
+                );
+            }
+
+            // changed votes are not observed as the new participation
+            if !voted_before {
+                T::VoteObserver::proposal_voted(&voter_account_id, proposal_id);
+            }
+
+            Self::deposit_event(RawEvent::Voted(voter_id, proposal_id, vote, rationale));
         }
 
         /// Cancel a proposal by its original proposer.
@@ -375,10 +629,10 @@ decl_module! {
             Self::finalize_proposal(proposal_id, ProposalDecisionStatus::Canceled);
         }
 
-        /// Veto a proposal. Must be root.
+        /// Veto a proposal. Must be the veto origin.
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn veto_proposal(origin, proposal_id: T::ProposalId) {
-            ensure_root(origin)?;
+            T::VetoOrigin::ensure_origin(origin)?;
 
             ensure!(<Proposals<T>>::contains_key(proposal_id), Error::<T>::ProposalNotFound);
             let proposal = Self::proposals(proposal_id);
@@ -388,14 +642,46 @@ decl_module! {
             if <PendingExecutionProposalIds<T>>::contains_key(proposal_id) {
                 Self::veto_pending_execution_proposal(proposal_id, proposal);
             } else {
-                ensure!(matches!(proposal.status, ProposalStatus::Active{..}), Error::<T>::ProposalFinalized);
+                ensure!(
+                    matches!(
+                        proposal.status,
+                        ProposalStatus::Active{..} | ProposalStatus::PendingConstitutionality{..}
+                    ),
+                    Error::<T>::ProposalFinalized
+                );
                 Self::finalize_proposal(proposal_id, ProposalDecisionStatus::Vetoed);
             }
         }
 
+        /// Sets the max number of active proposals per proposer. Removes the limit if `None`.
+        /// Must be root.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn set_max_active_proposals_per_proposer(origin, limit: Option<u32>) {
+            ensure_root(origin)?;
+
+            // mutation
+
+            MaxActiveProposalsPerProposer::mutate(|value| *value = limit);
+        }
+
+        /// Sets the max number of active proposals per proposal type. Removes the limit if `None`.
+        /// Must be root.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn set_max_active_proposals_per_type(
+            origin,
+            proposal_type: ProposalType,
+            limit: Option<u32>,
+        ) {
+            ensure_root(origin)?;
+
+            // mutation
+
+            MaxActiveProposalsPerType::mutate(proposal_type, |value| *value = limit);
+        }
+
         /// Block finalization. Perform voting period check, vote result tally, approved proposals
-        /// grace period checks, and proposal execution.
-        fn on_finalize(_n: T::BlockNumber) {
+        /// grace period checks, proposal execution and archiving of the finalized proposals.
+        fn on_finalize(now: T::BlockNumber) {
             let finalized_proposals = Self::get_finalized_proposals();
 
             // mutation
@@ -414,6 +700,11 @@ decl_module! {
             for approved_proosal in executable_proposals {
                 Self::execute_proposal(approved_proosal);
             }
+
+            // Move finalized proposals with expired retention period to the proposals history
+            for proposal_id in Self::get_proposals_with_expired_retention_period(now) {
+                Self::archive_proposal(now, proposal_id);
+            }
         }
     }
 }
@@ -421,21 +712,37 @@ decl_module! {
 impl<T: Trait> Module<T> {
     /// Create proposal. Requires 'proposal origin' membership.
     pub fn create_proposal(
-        account_id: T::AccountId,
-        proposer_id: MemberId<T>,
-        parameters: ProposalParameters<T::BlockNumber, types::BalanceOf<T>>,
-        title: Vec<u8>,
-        description: Vec<u8>,
-        stake_balance: Option<types::BalanceOf<T>>,
-        encoded_dispatchable_call_code: Vec<u8>,
+        creation_params: ProposalCreationParametersOf<T>,
     ) -> Result<T::ProposalId, DispatchError> {
+        let ProposalCreationParameters {
+            account_id,
+            proposer_id,
+            proposal_type,
+            parameters,
+            title,
+            description,
+            stake_balance,
+            encoded_dispatchable_call_code,
+            execution_schedule,
+            dependencies,
+            batch,
+        } = creation_params;
+
         Self::ensure_create_proposal_parameters_are_valid(
+            proposer_id,
+            proposal_type,
             &parameters,
             &title,
             &description,
             stake_balance,
+            execution_schedule,
+            &dependencies,
         )?;
 
+        if batch {
+            Self::ensure_batch_code_valid(&encoded_dispatchable_call_code)?;
+        }
+
         // checks passed
         // mutation
 
@@ -463,6 +770,9 @@ impl<T: Trait> Module<T> {
 
         let new_proposal = Proposal {
             created_at: Self::current_block(),
+            activated_at: Self::current_block(),
+            council_approvals: 0,
+            execution_schedule,
             parameters,
             title,
             description,
@@ -471,11 +781,21 @@ impl<T: Trait> Module<T> {
             voting_results: VotingResults::default(),
         };
 
+        Self::snapshot_total_voting_weight(proposal_id, &new_proposal.parameters);
         <Proposals<T>>::insert(proposal_id, new_proposal);
         <DispatchableCallCode<T>>::insert(proposal_id, encoded_dispatchable_call_code);
+        if !dependencies.is_empty() {
+            <DependenciesByProposal<T>>::insert(proposal_id, dependencies);
+        }
+        if batch {
+            <BatchedProposalIds<T>>::insert(proposal_id, ());
+        }
         <ActiveProposalIds<T>>::insert(proposal_id, ());
+        <ProposalTypeByProposalId<T>>::insert(proposal_id, proposal_type);
         ProposalCount::put(next_proposal_count_value);
-        Self::increase_active_proposal_counter();
+        Self::increase_active_proposal_counter(proposer_id, proposal_type);
+
+        T::VoteObserver::proposal_voting_opened(proposal_id);
 
         Self::deposit_event(RawEvent::ProposalCreated(proposer_id, proposal_id));
 
@@ -484,34 +804,23 @@ impl<T: Trait> Module<T> {
 
     /// Performs all checks for the proposal creation:
     /// - title, body lengths
-    /// - max active proposal
+    /// - max active proposal: total, per proposer and per proposal type
     /// - provided parameters: approval_threshold_percentage and slashing_threshold_percentage > 0
     /// - provided stake balance and parameters.required_stake are valid
+    #[allow(clippy::too_many_arguments)]
     pub fn ensure_create_proposal_parameters_are_valid(
+        proposer_id: MemberId<T>,
+        proposal_type: ProposalType,
         parameters: &ProposalParameters<T::BlockNumber, types::BalanceOf<T>>,
         title: &[u8],
         description: &[u8],
         stake_balance: Option<types::BalanceOf<T>>,
+        execution_schedule: Option<ExecutionSchedule<T::BlockNumber>>,
+        dependencies: &[T::ProposalId],
     ) -> DispatchResult {
-        ensure!(!title.is_empty(), Error::<T>::EmptyTitleProvided);
-        ensure!(
-            title.len() as u32 <= T::TitleMaxLength::get(),
-            Error::<T>::TitleIsTooLong
-        );
-
-        ensure!(
-            !description.is_empty(),
-            Error::<T>::EmptyDescriptionProvided
-        );
-        ensure!(
-            description.len() as u32 <= T::DescriptionMaxLength::get(),
-            Error::<T>::DescriptionIsTooLong
-        );
+        Self::ensure_title_and_description_valid(title, description)?;
 
-        ensure!(
-            (Self::active_proposal_count()) < T::MaxActiveProposalLimit::get(),
-            Error::<T>::MaxActiveProposalNumberExceeded
-        );
+        Self::ensure_active_proposal_limits_not_exceeded(proposer_id, proposal_type)?;
 
         ensure!(
             parameters.approval_threshold_percentage > 0,
@@ -539,9 +848,118 @@ impl<T: Trait> Module<T> {
             return Err(Error::<T>::StakeShouldBeEmpty.into());
         }
 
+        // the proposal cannot be executed before the voting and grace periods pass
+        if let Some(execution_schedule) = execution_schedule {
+            // the next councils approval time is unknown
+            if let ExecutionSchedule::AtBlock(_) = execution_schedule {
+                ensure!(
+                    parameters.constitutionality <= 1,
+                    Error::<T>::ExactExecutionBlockWithConstitutionality
+                );
+            }
+
+            ensure!(
+                execution_schedule.execution_block()
+                    >= Self::current_block() + parameters.voting_period + parameters.grace_period,
+                Error::<T>::InvalidExecutionBlock
+            );
+        }
+
+        ensure!(
+            dependencies.len() as u32 <= T::MaxProposalDependencies::get(),
+            Error::<T>::DependenciesLimitExceeded
+        );
+
+        for dependency in dependencies {
+            ensure!(
+                <Proposals<T>>::contains_key(dependency)
+                    || <ArchivedProposals<T>>::contains_key(dependency),
+                Error::<T>::DependencyNotFound
+            );
+        }
+
         Ok(())
     }
 
+    /// Amends the title, description and the code of the active proposal by its proposer. All
+    /// votes get cleared and the voting is re-opened. The previous version of the proposal is
+    /// archived.
+    pub fn amend_proposal(
+        proposer_id: MemberId<T>,
+        proposal_id: T::ProposalId,
+        title: Vec<u8>,
+        description: Vec<u8>,
+        encoded_dispatchable_call_code: Vec<u8>,
+    ) -> DispatchResult {
+        Self::ensure_can_amend_proposal(proposer_id, proposal_id, &title, &description)?;
+
+        if <BatchedProposalIds<T>>::contains_key(proposal_id) {
+            Self::ensure_batch_code_valid(&encoded_dispatchable_call_code)?;
+        }
+
+        // mutation
+
+        let mut proposal = Self::proposals(proposal_id);
+
+        let previous_version = ProposalVersion {
+            title: sp_std::mem::replace(&mut proposal.title, title),
+            description: sp_std::mem::replace(&mut proposal.description, description),
+            encoded_dispatchable_call_code: <DispatchableCallCode<T>>::get(proposal_id),
+            amended_at: Self::current_block(),
+        };
+
+        proposal.reset_proposal();
+        Self::clear_votes(proposal_id);
+        Self::snapshot_total_voting_weight(proposal_id, &proposal.parameters);
+
+        <ProposalVersions<T>>::mutate(proposal_id, |versions| versions.push(previous_version));
+        <Proposals<T>>::insert(proposal_id, proposal);
+        <DispatchableCallCode<T>>::insert(proposal_id, encoded_dispatchable_call_code);
+
+        T::VoteObserver::proposal_voting_opened(proposal_id);
+
+        Self::deposit_event(RawEvent::ProposalAmended(proposer_id, proposal_id));
+
+        Ok(())
+    }
+
+    /// Performs all checks for the proposal amendment:
+    /// - the proposal is active and the member is its proposer
+    /// - no votes were cast yet or the amendment period is not expired
+    /// - max number of the amendments
+    /// - title, body lengths
+    pub fn ensure_can_amend_proposal(
+        proposer_id: MemberId<T>,
+        proposal_id: T::ProposalId,
+        title: &[u8],
+        description: &[u8],
+    ) -> DispatchResult {
+        ensure!(
+            <Proposals<T>>::contains_key(proposal_id),
+            Error::<T>::ProposalNotFound
+        );
+        let proposal = Self::proposals(proposal_id);
+
+        ensure!(proposer_id == proposal.proposer_id, Error::<T>::NotAuthor);
+        ensure!(
+            matches!(proposal.status, ProposalStatus::Active { .. }),
+            Error::<T>::ProposalFinalized
+        );
+
+        ensure!(
+            proposal.voting_results.votes_number() == 0
+                || Self::current_block() < proposal.activated_at + T::AmendmentPeriod::get(),
+            Error::<T>::AmendmentPeriodExpired
+        );
+
+        ensure!(
+            (Self::proposal_versions(proposal_id).len() as u32) < T::MaxProposalAmendments::get(),
+            Error::<T>::MaxProposalAmendmentsExceeded
+        );
+
+        Self::ensure_title_and_description_valid(title, description)
+    }
+
     /// Callback from StakingEventsHandler. Refunds unstaked imbalance back to the source account.
     /// There can be a lot of invariant breaks in the scope of this proposal.
     /// Such situations are handled by adding error messages to the log.
@@ -552,19 +970,26 @@ impl<T: Trait> Module<T> {
             if <Proposals<T>>::contains_key(proposal_id) {
                 let proposal = Self::proposals(proposal_id);
 
-                if let ProposalStatus::Active(active_stake_result) = proposal.status {
-                    if let Some(active_stake) = active_stake_result {
-                        let refunding_result = CurrencyOf::<T>::resolve_into_existing(
-                            &active_stake.source_account_id,
-                            imbalance,
-                        );
+                // the finalized proposals keep the stake data only after the unstaking error
+                let active_stake = match proposal.status {
+                    ProposalStatus::Active(active_stake)
+                    | ProposalStatus::PendingConstitutionality(active_stake) => active_stake,
+                    ProposalStatus::Finalized(finalization_data) => {
+                        finalization_data.stake_data_after_unstaking_error
+                    }
+                };
+
+                if let Some(active_stake) = active_stake {
+                    let refunding_result = CurrencyOf::<T>::resolve_into_existing(
+                        &active_stake.source_account_id,
+                        imbalance,
+                    );
 
-                        if refunding_result.is_err() {
-                            print("Broken invariant: cannot refund");
-                        }
+                    if refunding_result.is_err() {
+                        print("Broken invariant: cannot refund");
                     }
                 } else {
-                    print("Broken invariant: proposal status is not Active");
+                    print("Broken invariant: proposal has no stake");
                 }
             } else {
                 print("Broken invariant: proposal doesn't exist");
@@ -580,18 +1005,238 @@ impl<T: Trait> Module<T> {
         <ActiveProposalIds<T>>::iter().for_each(|(proposal_id, _)| {
             <Proposals<T>>::mutate(proposal_id, |proposal| {
                 proposal.reset_proposal();
-                <VoteExistsByProposalByVoter<T>>::remove_prefix(&proposal_id);
+                Self::clear_votes(proposal_id);
+                Self::snapshot_total_voting_weight(proposal_id, &proposal.parameters);
             });
+
+            T::VoteObserver::proposal_voting_opened(proposal_id);
         });
     }
+
+    /// Re-opens the voting for the proposals approved by the previous council and pending
+    /// constitutionality. Should be called on the new council election.
+    pub fn reactivate_pending_constitutionality_proposals() {
+        let proposal_ids: Vec<T::ProposalId> = <PendingConstitutionalityProposalIds<T>>::iter()
+            .map(|(proposal_id, _)| proposal_id)
+            .collect();
+
+        for proposal_id in proposal_ids {
+            <PendingConstitutionalityProposalIds<T>>::remove(proposal_id);
+            <ActiveProposalIds<T>>::insert(proposal_id, ());
+
+            let mut proposal = Self::proposals(proposal_id);
+            proposal.reactivate_proposal(Self::current_block());
+            Self::clear_votes(proposal_id);
+            Self::snapshot_total_voting_weight(proposal_id, &proposal.parameters);
+
+            let new_proposal_status = proposal.status.clone();
+            <Proposals<T>>::insert(proposal_id, proposal);
+
+            T::VoteObserver::proposal_voting_opened(proposal_id);
+
+            Self::deposit_event(RawEvent::ProposalStatusUpdated(
+                proposal_id,
+                new_proposal_status,
+            ));
+        }
+    }
+
+    /// Returns the proposals open for voting with their ids.
+    pub fn active_proposals() -> Vec<(T::ProposalId, ProposalOf<T>)> {
+        <ActiveProposalIds<T>>::iter()
+            .map(|(proposal_id, _)| (proposal_id, Self::proposals(proposal_id)))
+            .collect()
+    }
+
+    /// Returns the approved proposals pending execution with their ids.
+    pub fn pending_execution_proposals() -> Vec<(T::ProposalId, ProposalOf<T>)> {
+        <PendingExecutionProposalIds<T>>::iter()
+            .map(|(proposal_id, _)| (proposal_id, Self::proposals(proposal_id)))
+            .collect()
+    }
+
+    /// Returns the voting progress of the proposal open for voting: quorums and thresholds
+    /// reached by the current votes, blocks remaining and the decision if the voting ended now.
+    /// Returns None if the proposal is not open for voting.
+    pub fn proposal_voting_status(
+        proposal_id: T::ProposalId,
+    ) -> Option<ProposalVotingStatus<T::BlockNumber>> {
+        if !<ActiveProposalIds<T>>::contains_key(proposal_id) {
+            return None;
+        }
+
+        let proposal = Self::proposals(proposal_id);
+
+        let voting_status = if proposal.parameters.weighted_voting {
+            proposal.define_weighted_voting_status(
+                &Self::weighted_voting_results(proposal_id),
+                Self::total_voting_weight_by_proposal(proposal_id),
+                Self::current_block(),
+            )
+        } else {
+            proposal.define_voting_status(
+                T::TotalVotersCounter::total_voters_count(),
+                Self::current_block(),
+            )
+        };
+
+        Some(voting_status)
+    }
+
+    /// Dispatches the proposal code at the current state in the storage transaction that is
+    /// always rolled back. Returns the reason if the proposal execution would fail now: the
+    /// dependencies not executed yet or the dispatch error. Neither the proposal status nor the
+    /// voting is checked.
+    pub fn dry_run_proposal(proposal_id: T::ProposalId) -> Result<(), DryRunError<T::ProposalId>> {
+        if !<Proposals<T>>::contains_key(proposal_id) {
+            return Err(DryRunError::ProposalNotFound);
+        }
+
+        let unmet_dependencies = Self::unmet_dependencies(proposal_id);
+        if !unmet_dependencies.is_empty() {
+            return Err(DryRunError::UnmetDependencies(unmet_dependencies));
+        }
+
+        with_transaction(|| {
+            <ExecutingProposalId<T>>::put(proposal_id);
+
+            TransactionOutcome::Rollback(Self::dispatch_proposal_code(proposal_id))
+        })
+        .map_err(DryRunError::from)
+    }
+
+    /// Returns the current votes of the proposal by voter.
+    pub fn proposal_votes(proposal_id: T::ProposalId) -> Vec<(MemberId<T>, VoteKind)> {
+        <VoteExistsByProposalByVoter<T>>::iter_prefix(proposal_id).collect()
+    }
+
+    /// Returns a page of the proposals history: archived proposals with ids starting from the
+    /// provided id. The page covers `limit` proposal ids.
+    pub fn proposal_history(
+        first_proposal_id: u32,
+        limit: u32,
+    ) -> Vec<(T::ProposalId, ArchivedProposalOf<T>)> {
+        (first_proposal_id..first_proposal_id.saturating_add(limit))
+            .map(T::ProposalId::from)
+            .filter_map(|proposal_id| {
+                Self::archived_proposals(proposal_id)
+                    .map(|archived_proposal| (proposal_id, archived_proposal))
+            })
+            .collect()
+    }
 }
 
 impl<T: Trait> Module<T> {
+    /// Checks the proposal title and body lengths.
+    pub fn ensure_title_and_description_valid(title: &[u8], description: &[u8]) -> DispatchResult {
+        ensure!(!title.is_empty(), Error::<T>::EmptyTitleProvided);
+        ensure!(
+            title.len() as u32 <= T::TitleMaxLength::get(),
+            Error::<T>::TitleIsTooLong
+        );
+
+        ensure!(
+            !description.is_empty(),
+            Error::<T>::EmptyDescriptionProvided
+        );
+        ensure!(
+            description.len() as u32 <= T::DescriptionMaxLength::get(),
+            Error::<T>::DescriptionIsTooLong
+        );
+
+        Ok(())
+    }
+
+    /// Checks the max active proposals number: total, per proposer and per proposal type.
+    pub fn ensure_active_proposal_limits_not_exceeded(
+        proposer_id: MemberId<T>,
+        proposal_type: ProposalType,
+    ) -> DispatchResult {
+        ensure!(
+            (Self::active_proposal_count()) < T::MaxActiveProposalLimit::get(),
+            Error::<T>::MaxActiveProposalNumberExceeded
+        );
+
+        if let Some(limit) = Self::max_active_proposals_per_proposer() {
+            ensure!(
+                Self::active_proposal_count_by_proposer(proposer_id) < limit,
+                Error::<T>::MaxActiveProposalsPerProposerExceeded
+            );
+        }
+
+        if let Some(limit) = Self::max_active_proposals_per_type(proposal_type) {
+            ensure!(
+                Self::active_proposal_count_by_type(proposal_type) < limit,
+                Error::<T>::MaxActiveProposalsPerTypeExceeded
+            );
+        }
+
+        Ok(())
+    }
+
+    // Checks that the batched proposal code is a non-empty list of the encoded calls.
+    fn ensure_batch_code_valid(encoded_dispatchable_call_code: &[u8]) -> DispatchResult {
+        let encoded_calls = Vec::<Vec<u8>>::decode(&mut &encoded_dispatchable_call_code[..])
+            .map_err(|_| Error::<T>::InvalidBatch)?;
+
+        ensure!(!encoded_calls.is_empty(), Error::<T>::InvalidBatch);
+
+        Ok(())
+    }
+
     // Wrapper-function over system::block_number()
     fn current_block() -> T::BlockNumber {
         <system::Module<T>>::block_number()
     }
 
+    // Moves the voter weight from the previous vote to the new vote. The weight is recorded at the
+    // vote time.
+    fn update_weighted_voting_results(
+        proposal_id: T::ProposalId,
+        voter_id: MemberId<T>,
+        voter_account_id: &T::AccountId,
+        previous_vote: Option<VoteKind>,
+        vote: VoteKind,
+    ) {
+        let weight = T::VotingWeightProvider::voting_weight(voter_account_id);
+
+        <WeightedVotingResultsByProposal<T>>::mutate(proposal_id, |results| {
+            if let Some(previous_vote) = previous_vote {
+                let previous_weight = Self::vote_weight_by_proposal_by_voter(proposal_id, voter_id);
+
+                results.remove_vote(previous_vote, previous_weight);
+            }
+
+            results.add_vote(vote, weight);
+        });
+
+        <VoteWeightByProposalByVoter<T>>::insert(proposal_id, voter_id, weight);
+    }
+
+    // Saves the total voting weight on the voting start for the proposal with the weighted voting.
+    // The quorum is calculated against this snapshot as the votes weights are recorded at the vote
+    // time.
+    fn snapshot_total_voting_weight(
+        proposal_id: T::ProposalId,
+        parameters: &ProposalParameters<T::BlockNumber, types::BalanceOf<T>>,
+    ) {
+        if parameters.weighted_voting {
+            <TotalVotingWeightByProposal<T>>::insert(
+                proposal_id,
+                T::VotingWeightProvider::total_voting_weight(),
+            );
+        }
+    }
+
+    // Removes the votes with their rationales and weights for the proposal.
+    fn clear_votes(proposal_id: T::ProposalId) {
+        <VoteExistsByProposalByVoter<T>>::remove_prefix(&proposal_id);
+        <VoteRationaleByProposalByVoter<T>>::remove_prefix(&proposal_id);
+        <VoteWeightByProposalByVoter<T>>::remove_prefix(&proposal_id);
+        <WeightedVotingResultsByProposal<T>>::remove(&proposal_id);
+        <TotalVotingWeightByProposal<T>>::remove(&proposal_id);
+    }
+
     // Enumerates through active proposals. Tally Voting results.
     // Returns proposals with finalized status and id
     fn get_finalized_proposals() -> Vec<FinalizedProposal<T>> {
@@ -604,10 +1249,18 @@ impl<T: Trait> Module<T> {
 
                 // Calculates votes, takes in account voting period expiration.
                 // If voting process is in progress, then decision status is None.
-                let decision_status = proposal.define_proposal_decision_status(
-                    T::TotalVotersCounter::total_voters_count(),
-                    Self::current_block(),
-                );
+                let decision_status = if proposal.parameters.weighted_voting {
+                    proposal.define_weighted_proposal_decision_status(
+                        &Self::weighted_voting_results(proposal_id),
+                        Self::total_voting_weight_by_proposal(proposal_id),
+                        Self::current_block(),
+                    )
+                } else {
+                    proposal.define_proposal_decision_status(
+                        T::TotalVotersCounter::total_voters_count(),
+                        Self::current_block(),
+                    )
+                };
 
                 // map to FinalizedProposalData if decision for the proposal is made or return None
                 decision_status.map(|status| FinalizedProposalData {
@@ -639,27 +1292,33 @@ impl<T: Trait> Module<T> {
                 ..proposal
             },
         );
+        Self::schedule_proposal_archiving(proposal_id);
     }
 
     // Executes approved proposal code
     fn execute_proposal(approved_proposal: ApprovedProposal<T>) {
-        let proposal_code = Self::proposal_codes(approved_proposal.proposal_id);
+        let proposal_id = approved_proposal.proposal_id;
+
+        let approved_proposal_status = if approved_proposal
+            .proposal
+            .is_execution_block_missed(Self::current_block())
+        {
+            ApprovedProposalStatus::failed_execution("ExecutionBlockMissed")
+        } else if !Self::dependencies_executed(proposal_id) {
+            ApprovedProposalStatus::failed_execution("DependencyNotExecuted")
+        } else {
+            <ExecutingProposalId<T>>::put(proposal_id);
 
-        let proposal_code_result = T::DispatchableCallCode::decode(&mut &proposal_code[..]);
+            let execution_result = Self::dispatch_proposal_code(proposal_id);
 
-        let approved_proposal_status = match proposal_code_result {
-            Ok(proposal_code) => {
-                if let Err(dispatch_error) =
-                    proposal_code.dispatch_bypass_filter(T::Origin::from(RawOrigin::Root))
-                {
-                    ApprovedProposalStatus::failed_execution(Self::parse_dispatch_error(
-                        dispatch_error.error,
-                    ))
-                } else {
-                    ApprovedProposalStatus::Executed
+            <ExecutingProposalId<T>>::kill();
+
+            match execution_result {
+                Ok(()) => ApprovedProposalStatus::Executed,
+                Err(error) => {
+                    ApprovedProposalStatus::failed_execution(Self::parse_dispatch_error(error))
                 }
             }
-            Err(error) => ApprovedProposalStatus::failed_execution(error.what()),
         };
 
         let proposal_execution_status = approved_proposal
@@ -676,25 +1335,195 @@ impl<T: Trait> Module<T> {
         ));
 
         <PendingExecutionProposalIds<T>>::remove(&approved_proposal.proposal_id);
+        Self::schedule_proposal_archiving(approved_proposal.proposal_id);
+    }
+
+    // Decodes and dispatches the proposal code with the root origin. The calls of the batched
+    // proposal are dispatched atomically: all changes are reverted if any of the calls fails.
+    fn dispatch_proposal_code(proposal_id: T::ProposalId) -> DispatchResult {
+        let proposal_code = Self::proposal_codes(proposal_id);
+
+        if !<BatchedProposalIds<T>>::contains_key(proposal_id) {
+            return Self::dispatch_call(&proposal_code);
+        }
+
+        let encoded_calls = Vec::<Vec<u8>>::decode(&mut &proposal_code[..])
+            .map_err(|error| DispatchError::Other(error.what()))?;
+
+        with_transaction(|| {
+            for encoded_call in encoded_calls.iter() {
+                if let Err(error) = Self::dispatch_call(encoded_call) {
+                    return TransactionOutcome::Rollback(Err(error));
+                }
+            }
+
+            TransactionOutcome::Commit(Ok(()))
+        })
+    }
+
+    // Decodes and dispatches the encoded call with the root origin.
+    fn dispatch_call(encoded_call: &[u8]) -> DispatchResult {
+        let call = T::DispatchableCallCode::decode(&mut &encoded_call[..])
+            .map_err(|error| DispatchError::Other(error.what()))?;
+
+        call.dispatch_bypass_filter(T::Origin::from(RawOrigin::Root))
+            .map(|_| ())
+            .map_err(|dispatch_error| dispatch_error.error)
+    }
+
+    // Returns whether all the proposal dependencies were executed successfully.
+    fn dependencies_executed(proposal_id: T::ProposalId) -> bool {
+        Self::unmet_dependencies(proposal_id).is_empty()
+    }
+
+    // Returns the proposal dependencies that were not executed successfully yet. Dependencies
+    // can be archived already.
+    fn unmet_dependencies(proposal_id: T::ProposalId) -> Vec<T::ProposalId> {
+        Self::proposal_dependencies(proposal_id)
+            .into_iter()
+            .filter(|dependency| match Self::archived_proposals(dependency) {
+                Some(archived_proposal) => {
+                    archived_proposal.decision
+                        != ProposalDecisionStatus::Approved(ApprovedProposalStatus::Executed)
+                }
+                None => !Self::proposals(dependency).status.is_executed(),
+            })
+            .collect()
+    }
+
+    // Returns whether any of the proposal dependencies can still be executed. Archived
+    // dependencies are final.
+    fn has_pending_dependencies(proposal_id: T::ProposalId) -> bool {
+        Self::proposal_dependencies(proposal_id)
+            .iter()
+            .any(|dependency| {
+                !<ArchivedProposals<T>>::contains_key(dependency)
+                    && Self::proposals(dependency).status.is_execution_pending()
+            })
+    }
+
+    // Schedules moving the proposal with the final decision to the proposals history and notifies
+    // the proposal observer about the final decision.
+    fn schedule_proposal_archiving(proposal_id: T::ProposalId) {
+        let archiving_block = Self::current_block() + T::ProposalRetentionPeriod::get();
+
+        <ProposalsToArchive<T>>::insert(archiving_block, proposal_id, ());
+
+        T::ProposalObserver::proposal_finalized(proposal_id);
+    }
+
+    // Enumerates finalized proposals with the retention period expiring at the provided block.
+    fn get_proposals_with_expired_retention_period(now: T::BlockNumber) -> Vec<T::ProposalId> {
+        <ProposalsToArchive<T>>::iter_prefix(now)
+            .map(|(proposal_id, _)| proposal_id)
+            .collect()
+    }
+
+    // Moves the finalized proposal to the proposals history:
+    // - retries the stake refund if the unstaking failed during the finalization
+    // - saves the compact archive record
+    // - prunes the proposal data, votes and code
+    // - notifies the proposal observer
+    // - fires an event
+    fn archive_proposal(archiving_block: T::BlockNumber, proposal_id: T::ProposalId) {
+        <ProposalsToArchive<T>>::remove(archiving_block, proposal_id);
+
+        let proposal = Self::proposals(proposal_id);
+
+        if let ProposalStatus::Finalized(finalization_data) = proposal.status {
+            if let Some(stake_data) = finalization_data.stake_data_after_unstaking_error {
+                if ProposalStakeManager::<T>::remove_stake(stake_data.stake_id).is_err() {
+                    print("Broken invariant: cannot refund the stake of the archived proposal");
+                }
+
+                <StakesProposals<T>>::remove(stake_data.stake_id);
+            }
+
+            <ArchivedProposals<T>>::insert(
+                proposal_id,
+                ArchivedProposal {
+                    proposer_id: proposal.proposer_id,
+                    created_at: proposal.created_at,
+                    finalized_at: finalization_data.finalized_at,
+                    decision: finalization_data.proposal_status,
+                    voting_results: proposal.voting_results,
+                },
+            );
+
+            <Proposals<T>>::remove(proposal_id);
+            <DispatchableCallCode<T>>::remove(proposal_id);
+            <DependenciesByProposal<T>>::remove(proposal_id);
+            <BatchedProposalIds<T>>::remove(proposal_id);
+            <ProposalVersions<T>>::remove(proposal_id);
+            <ProposalTypeByProposalId<T>>::remove(proposal_id);
+            Self::clear_votes(proposal_id);
+
+            T::ProposalObserver::proposal_archived(proposal_id);
+
+            Self::deposit_event(RawEvent::ProposalArchived(proposal_id));
+        } else {
+            print("Broken invariant: proposal cannot be non-finalized during the archiving");
+        }
+    }
+
+    // Moves the proposal approved by the current council to the 'PendingConstitutionality'
+    // status. The proposal keeps its stake and stays counted as active until the next council
+    // election re-opens the voting.
+    fn await_next_council_approval(proposal_id: T::ProposalId, mut proposal: ProposalOf<T>) {
+        <ActiveProposalIds<T>>::remove(&proposal_id);
+        <PendingConstitutionalityProposalIds<T>>::insert(proposal_id, ());
+
+        if let ProposalStatus::Active(active_stake) = proposal.status.clone() {
+            let new_proposal_status = ProposalStatus::PendingConstitutionality(active_stake);
+
+            proposal.council_approvals += 1;
+            proposal.status = new_proposal_status.clone();
+            <Proposals<T>>::insert(proposal_id, proposal);
+
+            Self::deposit_event(RawEvent::ProposalStatusUpdated(
+                proposal_id,
+                new_proposal_status,
+            ));
+        } else {
+            print("Broken invariant: proposal cannot be non-active during the approval");
+        }
     }
 
     // Performs all actions on proposal finalization:
-    // - clean active proposal cache
+    // - clean active and pending constitutionality proposal caches
     // - update proposal status fields (status, finalized_at)
     // - add to pending execution proposal cache if approved
     // - slash and unstake proposal stake if stake exists
     // - decrease active proposal counter
     // - fire an event
+    // Approved proposals requiring the approval of the next councils are moved to the
+    // 'PendingConstitutionality' status instead.
     // It prints an error message in case of an attempt to finalize the non-active proposal.
     fn finalize_proposal(proposal_id: T::ProposalId, decision_status: ProposalDecisionStatus) {
-        Self::decrease_active_proposal_counter();
-        <ActiveProposalIds<T>>::remove(&proposal_id.clone());
-
         let mut proposal = Self::proposals(proposal_id);
 
-        if let ProposalStatus::Active(active_stake) = proposal.status.clone() {
+        if let ProposalDecisionStatus::Approved { .. } = decision_status {
+            if proposal.requires_next_council_approval() {
+                Self::await_next_council_approval(proposal_id, proposal);
+
+                return;
+            }
+        }
+
+        Self::decrease_active_proposal_counter(
+            proposal.proposer_id,
+            Self::proposal_type_by_proposal_id(proposal_id),
+        );
+        <ActiveProposalIds<T>>::remove(&proposal_id.clone());
+        <PendingConstitutionalityProposalIds<T>>::remove(&proposal_id);
+
+        if let ProposalStatus::Active(active_stake)
+        | ProposalStatus::PendingConstitutionality(active_stake) = proposal.status.clone()
+        {
             if let ProposalDecisionStatus::Approved { .. } = decision_status {
                 <PendingExecutionProposalIds<T>>::insert(proposal_id, ());
+            } else {
+                Self::schedule_proposal_archiving(proposal_id);
             }
 
             // deal with stakes if necessary
@@ -767,7 +1596,11 @@ impl<T: Trait> Module<T> {
             .filter_map(|(proposal_id, _)| {
                 let proposal = Self::proposals(proposal_id);
 
-                if proposal.is_grace_period_expired(Self::current_block()) {
+                // proposals wait for their dependencies to be executed or to be finalized
+                // without the execution
+                if proposal.is_ready_for_execution(Self::current_block())
+                    && !Self::has_pending_dependencies(proposal_id)
+                {
                     // this should be true, because it was tested inside is_grace_period_expired()
                     if let ProposalStatus::Finalized(finalisation_data) = proposal.status.clone() {
                         Some(ApprovedProposalData {
@@ -785,20 +1618,29 @@ impl<T: Trait> Module<T> {
             .collect()
     }
 
-    // Increases active proposal counter.
-    fn increase_active_proposal_counter() {
+    /// Increases active proposal counters: total, per proposer and per proposal type. Used for the
+    /// proposals tracked outside the engine to apply the active proposal limits to them.
+    pub fn increase_active_proposal_counter(proposer_id: MemberId<T>, proposal_type: ProposalType) {
         let next_active_proposal_count_value = Self::active_proposal_count() + 1;
         ActiveProposalCount::put(next_active_proposal_count_value);
+
+        <ActiveProposalCountByProposer<T>>::mutate(proposer_id, |count| *count += 1);
+        ActiveProposalCountByType::mutate(proposal_type, |count| *count += 1);
     }
 
-    // Decreases active proposal counter down to zero. Decreasing below zero has no effect.
-    fn decrease_active_proposal_counter() {
+    /// Decreases active proposal counters down to zero. Decreasing below zero has no effect.
+    pub fn decrease_active_proposal_counter(proposer_id: MemberId<T>, proposal_type: ProposalType) {
         let current_active_proposal_counter = Self::active_proposal_count();
 
         if current_active_proposal_counter > 0 {
             let next_active_proposal_count_value = current_active_proposal_counter - 1;
             ActiveProposalCount::put(next_active_proposal_count_value);
         };
+
+        <ActiveProposalCountByProposer<T>>::mutate(proposer_id, |count| {
+            *count = count.saturating_sub(1)
+        });
+        ActiveProposalCountByType::mutate(proposal_type, |count| *count = count.saturating_sub(1));
     }
 
     // Parse dispatchable execution result.
@@ -836,11 +1678,23 @@ type ApprovedProposal<T> = ApprovedProposalData<
     <T as system::Trait>::AccountId,
 >;
 
-// Simplification of the 'Proposal' type
-type ProposalOf<T> = Proposal<
+/// Simplification of the 'ProposalCreationParameters' type
+pub type ProposalCreationParametersOf<T> = ProposalCreationParameters<
+    <T as system::Trait>::BlockNumber,
+    MemberId<T>,
+    types::BalanceOf<T>,
+    <T as system::Trait>::AccountId,
+    <T as Trait>::ProposalId,
+>;
+
+/// Simplification of the 'Proposal' type
+pub type ProposalOf<T> = Proposal<
     <T as system::Trait>::BlockNumber,
     MemberId<T>,
     types::BalanceOf<T>,
     <T as stake::Trait>::StakeId,
     <T as system::Trait>::AccountId,
 >;
+
+/// Simplification of the 'ArchivedProposal' type
+pub type ArchivedProposalOf<T> = ArchivedProposal<<T as system::Trait>::BlockNumber, MemberId<T>>;
@@ -0,0 +1,98 @@
+//! Storage migration of the proposals created before the constitutionality, the weighted voting
+//! and the execution schedule were introduced.
+
+use codec::{Decode, Encode};
+use frame_support::storage::migration::{put_storage_value, StorageIterator};
+use frame_support::traits::Get;
+use sp_std::vec::Vec;
+
+use crate::types::BalanceOf;
+use crate::{
+    MemberId, Module, PendingExecutionProposalIds, Proposal, ProposalOf, ProposalParameters,
+    ProposalStatus, ProposalsToArchive, Trait, VotingResults,
+};
+
+// Proposal parameters layout without the weighted voting and the constitutionality.
+#[derive(Encode, Decode)]
+struct OldProposalParameters<BlockNumber, Balance> {
+    voting_period: BlockNumber,
+    grace_period: BlockNumber,
+    approval_quorum_percentage: u32,
+    approval_threshold_percentage: u32,
+    slashing_quorum_percentage: u32,
+    slashing_threshold_percentage: u32,
+    required_stake: Option<Balance>,
+}
+
+// Proposal layout without the voting activation block, the council approvals and the execution
+// schedule.
+#[derive(Encode, Decode)]
+struct OldProposal<BlockNumber, ProposerId, Balance, StakeId, AccountId> {
+    parameters: OldProposalParameters<BlockNumber, Balance>,
+    proposer_id: ProposerId,
+    title: Vec<u8>,
+    description: Vec<u8>,
+    created_at: BlockNumber,
+    status: ProposalStatus<BlockNumber, StakeId, AccountId>,
+    voting_results: VotingResults,
+}
+
+type OldProposalOf<T> = OldProposal<
+    <T as system::Trait>::BlockNumber,
+    MemberId<T>,
+    BalanceOf<T>,
+    <T as stake::Trait>::StakeId,
+    <T as system::Trait>::AccountId,
+>;
+
+impl<T: Trait> Module<T> {
+    /// Migrates the proposals stored with the previous proposal layout. Migrated proposals require
+    /// the approval of the current council only, use the votes count and have no execution
+    /// schedule. The proposals with the final decision are scheduled for the archiving after the
+    /// retention period. Should be called once on the runtime upgrade.
+    pub fn migrate_proposals() {
+        let old_proposals: Vec<(Vec<u8>, OldProposalOf<T>)> =
+            StorageIterator::new(b"ProposalEngine", b"Proposals").collect();
+
+        let archiving_block = Self::current_block() + T::ProposalRetentionPeriod::get();
+
+        for (key, old_proposal) in old_proposals {
+            if let ProposalStatus::Finalized(_) = old_proposal.status {
+                // The key is the Blake2 128 hash of the proposal id concatenated with the proposal
+                // id. Approved proposals pending execution get archived after the execution.
+                if let Ok(proposal_id) = T::ProposalId::decode(&mut &key[16..]) {
+                    if !<PendingExecutionProposalIds<T>>::contains_key(proposal_id) {
+                        <ProposalsToArchive<T>>::insert(archiving_block, proposal_id, ());
+                    }
+                }
+            }
+
+            let old_parameters = old_proposal.parameters;
+
+            let proposal: ProposalOf<T> = Proposal {
+                parameters: ProposalParameters {
+                    voting_period: old_parameters.voting_period,
+                    grace_period: old_parameters.grace_period,
+                    approval_quorum_percentage: old_parameters.approval_quorum_percentage,
+                    approval_threshold_percentage: old_parameters.approval_threshold_percentage,
+                    slashing_quorum_percentage: old_parameters.slashing_quorum_percentage,
+                    slashing_threshold_percentage: old_parameters.slashing_threshold_percentage,
+                    required_stake: old_parameters.required_stake,
+                    weighted_voting: false,
+                    constitutionality: 1,
+                },
+                proposer_id: old_proposal.proposer_id,
+                title: old_proposal.title,
+                description: old_proposal.description,
+                created_at: old_proposal.created_at,
+                activated_at: old_proposal.created_at,
+                council_approvals: 0,
+                execution_schedule: None,
+                status: old_proposal.status,
+                voting_results: old_proposal.voting_results,
+            };
+
+            put_storage_value(b"ProposalEngine", b"Proposals", &key, proposal);
+        }
+    }
+}
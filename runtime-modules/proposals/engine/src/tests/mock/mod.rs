@@ -15,6 +15,7 @@ use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
     Perbill,
 };
+use std::cell::RefCell;
 pub use system;
 
 mod balance_manager;
@@ -81,7 +82,12 @@ parameter_types! {
     pub const RejectionFee: u64 = 3;
     pub const TitleMaxLength: u32 = 100;
     pub const DescriptionMaxLength: u32 = 10000;
+    pub const RationaleMaxLength: u32 = 100;
     pub const MaxActiveProposalLimit: u32 = 100;
+    pub const MaxProposalDependencies: u32 = 5;
+    pub const AmendmentPeriod: u64 = 5;
+    pub const MaxProposalAmendments: u32 = 2;
+    pub const ProposalRetentionPeriod: u64 = 20;
 }
 
 impl membership::Trait for Test {
@@ -96,15 +102,24 @@ impl crate::Trait for Test {
     type Event = TestEvent;
     type ProposerOriginValidator = ();
     type VoterOriginValidator = ();
+    type VotingWeightProvider = ();
     type TotalVotersCounter = ();
+    type VoteObserver = TestVoteObserver;
+    type ProposalObserver = ();
     type ProposalId = u32;
     type StakeHandlerProvider = stakes::TestStakeHandlerProvider;
     type CancellationFee = CancellationFee;
     type RejectionFee = RejectionFee;
     type TitleMaxLength = TitleMaxLength;
     type DescriptionMaxLength = DescriptionMaxLength;
+    type RationaleMaxLength = RationaleMaxLength;
     type MaxActiveProposalLimit = MaxActiveProposalLimit;
+    type MaxProposalDependencies = MaxProposalDependencies;
+    type AmendmentPeriod = AmendmentPeriod;
+    type MaxProposalAmendments = MaxProposalAmendments;
+    type ProposalRetentionPeriod = ProposalRetentionPeriod;
     type DispatchableCallCode = proposals::Call<Test>;
+    type VetoOrigin = system::EnsureRoot<u64>;
 }
 
 impl Default for proposals::Call<Test> {
@@ -129,6 +144,53 @@ impl crate::VotersParameters for () {
     }
 }
 
+thread_local! {
+    static TOTAL_VOTING_WEIGHT: RefCell<u64> = RefCell::new(10);
+}
+
+// Sets the total voting weight returned by the mock voting weight provider.
+pub(crate) fn set_total_voting_weight(total_voting_weight: u64) {
+    TOTAL_VOTING_WEIGHT.with(|weight| *weight.borrow_mut() = total_voting_weight);
+}
+
+// Voting weight equals the voter account id, the voters are accounts 1-4.
+impl crate::VotingWeightProvider<u64> for () {
+    fn voting_weight(voter: &u64) -> u64 {
+        *voter
+    }
+
+    fn total_voting_weight() -> u64 {
+        TOTAL_VOTING_WEIGHT.with(|weight| *weight.borrow())
+    }
+}
+
+thread_local! {
+    static OPENED_VOTINGS_COUNT: RefCell<u32> = RefCell::new(0);
+    static OBSERVED_VOTES_COUNT: RefCell<u32> = RefCell::new(0);
+}
+
+// Returns the counts of the proposal votings opened and the votes observed by the mock vote
+// observer.
+pub(crate) fn observed_attendance() -> (u32, u32) {
+    (
+        OPENED_VOTINGS_COUNT.with(|count| *count.borrow()),
+        OBSERVED_VOTES_COUNT.with(|count| *count.borrow()),
+    )
+}
+
+// Counts the opened proposal votings and the votes like the councilors attendance tracker.
+pub struct TestVoteObserver;
+
+impl crate::ProposalVoteObserver<u64, u32> for TestVoteObserver {
+    fn proposal_voting_opened(_proposal_id: u32) {
+        OPENED_VOTINGS_COUNT.with(|count| *count.borrow_mut() += 1);
+    }
+
+    fn proposal_voted(_voter: &u64, _proposal_id: u32) {
+        OBSERVED_VOTES_COUNT.with(|count| *count.borrow_mut() += 1);
+    }
+}
+
 parameter_types! {
     pub const BlockHashCount: u64 = 250;
     pub const MaximumBlockWeight: u32 = 1024;
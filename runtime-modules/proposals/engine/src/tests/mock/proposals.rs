@@ -1,9 +1,16 @@
 //! Contains executable proposal extrinsic mocks
 
-use frame_support::decl_module;
+use frame_support::{decl_module, decl_storage};
 use sp_std::vec::Vec;
 pub trait Trait: system::Trait {}
 
+decl_storage! {
+    trait Store for Module<T: Trait> as ProposalsMock {
+        /// Counter of the successful 'counting_proposal' executions.
+        pub ExecutionsCount get(fn executions_count): u32;
+    }
+}
+
 decl_module! {
     pub struct Module<T: Trait> for enum Call where origin: T::Origin {
         /// Working extrinsic test
@@ -15,5 +22,11 @@ decl_module! {
         pub fn faulty_proposal(_origin, _title: Vec<u8>, _description: Vec<u8>,) {
              Err("ExecutionFailed")?
         }
+
+        /// Working extrinsic test with the storage change
+        #[weight = 10_000_000]
+        pub fn counting_proposal(_origin) {
+            ExecutionsCount::mutate(|count| *count += 1);
+        }
     }
 }
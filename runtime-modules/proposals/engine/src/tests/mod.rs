@@ -5,8 +5,9 @@ use mock::*;
 
 use codec::Encode;
 use frame_support::dispatch::DispatchResult;
-use frame_support::traits::{Currency, OnFinalize, OnInitialize};
-use frame_support::{StorageDoubleMap, StorageMap, StorageValue};
+use frame_support::storage::migration::put_storage_value;
+use frame_support::traits::{Currency, Get, OnFinalize, OnInitialize};
+use frame_support::{Blake2_128Concat, StorageDoubleMap, StorageHasher, StorageMap, StorageValue};
 use sp_std::rc::Rc;
 use system::RawOrigin;
 use system::{EventRecord, Phase};
@@ -41,6 +42,33 @@ impl ProposalParametersFixture {
         }
     }
 
+    fn with_voting_period(&self, voting_period: u64) -> Self {
+        ProposalParametersFixture {
+            parameters: ProposalParameters {
+                voting_period,
+                ..self.parameters
+            },
+        }
+    }
+
+    fn with_weighted_voting(&self) -> Self {
+        ProposalParametersFixture {
+            parameters: ProposalParameters {
+                weighted_voting: true,
+                ..self.parameters
+            },
+        }
+    }
+
+    fn with_constitutionality(&self, constitutionality: u32) -> Self {
+        ProposalParametersFixture {
+            parameters: ProposalParameters {
+                constitutionality,
+                ..self.parameters
+            },
+        }
+    }
+
     fn params(&self) -> ProposalParameters<u64, u64> {
         self.parameters.clone()
     }
@@ -57,6 +85,8 @@ impl Default for ProposalParametersFixture {
                 slashing_threshold_percentage: 60,
                 grace_period: 0,
                 required_stake: None,
+                weighted_voting: false,
+                constitutionality: 1,
             },
         }
     }
@@ -67,10 +97,14 @@ struct DummyProposalFixture {
     parameters: ProposalParameters<u64, u64>,
     account_id: u64,
     proposer_id: u64,
+    proposal_type: ProposalType,
     proposal_code: Vec<u8>,
     title: Vec<u8>,
     description: Vec<u8>,
     stake_balance: Option<BalanceOf<Test>>,
+    execution_schedule: Option<ExecutionSchedule<u64>>,
+    dependencies: Vec<u32>,
+    batch: bool,
 }
 
 impl Default for DummyProposalFixture {
@@ -89,13 +123,19 @@ impl Default for DummyProposalFixture {
                 slashing_threshold_percentage: 60,
                 grace_period: 0,
                 required_stake: None,
+                weighted_voting: false,
+                constitutionality: 1,
             },
             account_id: 1,
             proposer_id: 1,
+            proposal_type: 0,
             proposal_code: dummy_proposal.encode(),
             title,
             description,
             stake_balance: None,
+            execution_schedule: None,
+            dependencies: Vec::new(),
+            batch: false,
         }
     }
 }
@@ -117,6 +157,20 @@ impl DummyProposalFixture {
         DummyProposalFixture { account_id, ..self }
     }
 
+    fn with_proposer(self, proposer_id: u64) -> Self {
+        DummyProposalFixture {
+            proposer_id,
+            ..self
+        }
+    }
+
+    fn with_proposal_type(self, proposal_type: ProposalType) -> Self {
+        DummyProposalFixture {
+            proposal_type,
+            ..self
+        }
+    }
+
     fn with_stake(self, stake_balance: BalanceOf<Test>) -> Self {
         DummyProposalFixture {
             stake_balance: Some(stake_balance),
@@ -131,16 +185,44 @@ impl DummyProposalFixture {
         }
     }
 
+    fn with_execution_schedule(self, execution_schedule: ExecutionSchedule<u64>) -> Self {
+        DummyProposalFixture {
+            execution_schedule: Some(execution_schedule),
+            ..self
+        }
+    }
+
+    fn with_dependencies(self, dependencies: Vec<u32>) -> Self {
+        DummyProposalFixture {
+            dependencies,
+            ..self
+        }
+    }
+
+    fn with_batch(self, calls: Vec<mock::proposals::Call<Test>>) -> Self {
+        let encoded_calls: Vec<Vec<u8>> = calls.iter().map(|call| call.encode()).collect();
+
+        DummyProposalFixture {
+            proposal_code: encoded_calls.encode(),
+            batch: true,
+            ..self
+        }
+    }
+
     fn create_proposal_and_assert(self, result: Result<u32, DispatchError>) -> Option<u32> {
-        let proposal_id_result = ProposalsEngine::create_proposal(
-            self.account_id,
-            self.proposer_id,
-            self.parameters,
-            self.title,
-            self.description,
-            self.stake_balance,
-            self.proposal_code,
-        );
+        let proposal_id_result = ProposalsEngine::create_proposal(ProposalCreationParameters {
+            account_id: self.account_id,
+            proposer_id: self.proposer_id,
+            proposal_type: self.proposal_type,
+            parameters: self.parameters,
+            title: self.title,
+            description: self.description,
+            stake_balance: self.stake_balance,
+            encoded_dispatchable_call_code: self.proposal_code,
+            execution_schedule: self.execution_schedule,
+            dependencies: self.dependencies,
+            batch: self.batch,
+        });
         assert_eq!(proposal_id_result, result);
 
         proposal_id_result.ok()
@@ -214,6 +296,7 @@ struct VoteGenerator {
     current_account_id: u64,
     current_voter_id: u64,
     pub auto_increment_voter_id: bool,
+    pub rationale: Vec<u8>,
 }
 
 impl VoteGenerator {
@@ -223,6 +306,7 @@ impl VoteGenerator {
             current_voter_id: 0,
             current_account_id: 0,
             auto_increment_voter_id: true,
+            rationale: Vec::new(),
         }
     }
     fn vote_and_assert_ok(&mut self, vote_kind: VoteKind) {
@@ -244,6 +328,7 @@ impl VoteGenerator {
             self.current_voter_id,
             self.proposal_id,
             vote_kind,
+            self.rationale.clone(),
         )
     }
 }
@@ -305,7 +390,13 @@ fn vote_succeeds() {
 fn vote_fails_with_insufficient_rights() {
     initial_test_ext().execute_with(|| {
         assert_eq!(
-            ProposalsEngine::vote(system::RawOrigin::None.into(), 1, 1, VoteKind::Approve),
+            ProposalsEngine::vote(
+                system::RawOrigin::None.into(),
+                1,
+                1,
+                VoteKind::Approve,
+                Vec::new()
+            ),
             Err(DispatchError::Other("Bad origin"))
         );
     });
@@ -338,6 +429,9 @@ fn proposal_execution_succeeds() {
                 parameters: parameters_fixture.params(),
                 proposer_id: 1,
                 created_at: 0,
+                activated_at: 0,
+                council_approvals: 0,
+                execution_schedule: None,
                 status: ProposalStatus::approved(ApprovedProposalStatus::Executed, 0),
                 title: b"title".to_vec(),
                 description: b"description".to_vec(),
@@ -387,6 +481,9 @@ fn proposal_execution_failed() {
                 parameters: parameters_fixture.params(),
                 proposer_id: 1,
                 created_at: 0,
+                activated_at: 0,
+                council_approvals: 0,
+                execution_schedule: None,
                 status: ProposalStatus::approved(
                     ApprovedProposalStatus::failed_execution("ExecutionFailed"),
                     0
@@ -415,6 +512,8 @@ fn voting_results_calculation_succeeds() {
             slashing_threshold_percentage: 60,
             grace_period: 0,
             required_stake: None,
+            weighted_voting: false,
+            constitutionality: 1,
         };
         let dummy_proposal = DummyProposalFixture::default().with_parameters(parameters);
         let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
@@ -441,6 +540,64 @@ fn voting_results_calculation_succeeds() {
     });
 }
 
+#[test]
+fn proposal_voting_status_and_votes_succeed() {
+    initial_test_ext().execute_with(|| {
+        let parameters = ProposalParameters {
+            voting_period: 3,
+            approval_quorum_percentage: 50,
+            approval_threshold_percentage: 50,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 60,
+            grace_period: 0,
+            required_stake: None,
+            weighted_voting: false,
+            constitutionality: 1,
+        };
+        let dummy_proposal = DummyProposalFixture::default().with_parameters(parameters);
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        assert_eq!(ProposalsEngine::proposal_voting_status(2), None);
+        assert_eq!(
+            ProposalsEngine::active_proposals(),
+            vec![(proposal_id, ProposalsEngine::proposals(proposal_id))]
+        );
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        let voting_status = ProposalsEngine::proposal_voting_status(proposal_id).unwrap();
+        assert!(!voting_status.approval_quorum_reached);
+        assert_eq!(voting_status.blocks_remaining, 3);
+        assert_eq!(
+            voting_status.decision_if_voting_ended,
+            ProposalDecisionStatus::Expired
+        );
+
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        let voting_status = ProposalsEngine::proposal_voting_status(proposal_id).unwrap();
+        assert!(voting_status.approval_quorum_reached);
+        assert!(voting_status.approval_threshold_reached);
+        assert_eq!(
+            voting_status.decision_if_voting_ended,
+            ProposalDecisionStatus::Approved(ApprovedProposalStatus::PendingExecution)
+        );
+
+        let mut votes = ProposalsEngine::proposal_votes(proposal_id);
+        votes.sort_by_key(|(voter_id, _)| *voter_id);
+        assert_eq!(
+            votes,
+            vec![
+                (1, VoteKind::Approve),
+                (2, VoteKind::Reject),
+                (3, VoteKind::Approve)
+            ]
+        );
+    });
+}
+
 #[test]
 fn rejected_voting_results_and_remove_proposal_id_from_active_succeeds() {
     initial_test_ext().execute_with(|| {
@@ -573,6 +730,38 @@ fn vote_fails_on_double_voting() {
     });
 }
 
+#[test]
+fn vote_with_same_kind_updates_rationale_only() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.auto_increment_voter_id = false;
+
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        vote_generator.rationale = b"rationale".to_vec();
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        assert_eq!(
+            <VoteRationaleByProposalByVoter<Test>>::get(&proposal_id, &1),
+            b"rationale".to_vec()
+        );
+        assert_eq!(
+            ProposalsEngine::proposals(proposal_id).voting_results,
+            VotingResults {
+                abstentions: 0,
+                approvals: 1,
+                rejections: 0,
+                slashes: 0,
+            }
+        );
+
+        vote_generator.vote_and_assert(VoteKind::Approve, Err(Error::<Test>::AlreadyVoted.into()));
+    });
+}
+
 #[test]
 fn cancel_proposal_succeeds() {
     initial_test_ext().execute_with(|| {
@@ -598,6 +787,9 @@ fn cancel_proposal_succeeds() {
                 parameters: parameters_fixture.params(),
                 proposer_id: 1,
                 created_at: 0,
+                activated_at: 0,
+                council_approvals: 0,
+                execution_schedule: None,
                 status: ProposalStatus::finalized_successfully(ProposalDecisionStatus::Canceled, 0),
                 title: b"title".to_vec(),
                 description: b"description".to_vec(),
@@ -666,6 +858,9 @@ fn veto_proposal_succeeds() {
                 parameters: parameters_fixture.params(),
                 proposer_id: 1,
                 created_at: 0,
+                activated_at: 0,
+                council_approvals: 0,
+                execution_schedule: None,
                 status: ProposalStatus::finalized_successfully(ProposalDecisionStatus::Vetoed, 0),
                 title: b"title".to_vec(),
                 description: b"description".to_vec(),
@@ -786,11 +981,82 @@ fn vote_proposal_event_emitted() {
 
         EventFixture::assert_events(vec![
             RawEvent::ProposalCreated(1, 1),
-            RawEvent::Voted(1, 1, VoteKind::Approve),
+            RawEvent::Voted(1, 1, VoteKind::Approve, Vec::new()),
+        ]);
+    });
+}
+
+#[test]
+fn vote_with_rationale_event_emitted() {
+    initial_test_ext().execute_with(|| {
+        run_to_block_and_finalize(1);
+
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.rationale = b"rationale".to_vec();
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        assert_eq!(
+            <VoteRationaleByProposalByVoter<Test>>::get(&proposal_id, &1),
+            b"rationale".to_vec()
+        );
+
+        EventFixture::assert_events(vec![
+            RawEvent::ProposalCreated(1, 1),
+            RawEvent::Voted(1, 1, VoteKind::Approve, b"rationale".to_vec()),
         ]);
     });
 }
 
+#[test]
+fn vote_fails_with_too_long_rationale() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.rationale = vec![0u8; RationaleMaxLength::get() as usize + 1];
+        vote_generator.vote_and_assert(
+            VoteKind::Approve,
+            Err(Error::<Test>::RationaleIsTooLong.into()),
+        );
+    });
+}
+
+#[test]
+fn vote_change_succeeds_and_updates_voting_results() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.auto_increment_voter_id = false;
+        vote_generator.rationale = b"mis-click".to_vec();
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        assert_eq!(
+            <Proposals<Test>>::get(proposal_id).voting_results,
+            VotingResults {
+                abstentions: 0,
+                approvals: 1,
+                rejections: 0,
+                slashes: 0,
+            }
+        );
+        assert_eq!(
+            <VoteExistsByProposalByVoter<Test>>::get(&proposal_id, &1),
+            VoteKind::Approve
+        );
+        assert_eq!(
+            <VoteRationaleByProposalByVoter<Test>>::get(&proposal_id, &1),
+            b"mis-click".to_vec()
+        );
+    });
+}
+
 #[test]
 fn create_proposal_and_expire_it() {
     initial_test_ext().execute_with(|| {
@@ -809,6 +1075,9 @@ fn create_proposal_and_expire_it() {
                 parameters: parameters_fixture.params(),
                 proposer_id: 1,
                 created_at: 0,
+                activated_at: 0,
+                council_approvals: 0,
+                execution_schedule: None,
                 status: ProposalStatus::finalized_successfully(ProposalDecisionStatus::Expired, 3),
                 title: b"title".to_vec(),
                 description: b"description".to_vec(),
@@ -849,6 +1118,9 @@ fn proposal_execution_postponed_because_of_grace_period() {
                 parameters: parameters_fixture.params(),
                 proposer_id: 1,
                 created_at: 0,
+                activated_at: 0,
+                council_approvals: 0,
+                execution_schedule: None,
                 status: ProposalStatus::approved(ApprovedProposalStatus::PendingExecution, 0),
                 title: b"title".to_vec(),
                 description: b"description".to_vec(),
@@ -894,6 +1166,9 @@ fn proposal_execution_vetoed_successfully_during_the_grace_period() {
                 parameters: parameters_fixture.params(),
                 proposer_id: 1,
                 created_at: 0,
+                activated_at: 0,
+                council_approvals: 0,
+                execution_schedule: None,
                 status: ProposalStatus::approved(ApprovedProposalStatus::PendingExecution, 0),
                 title: b"title".to_vec(),
                 description: b"description".to_vec(),
@@ -917,6 +1192,9 @@ fn proposal_execution_vetoed_successfully_during_the_grace_period() {
                 parameters: parameters_fixture.params(),
                 proposer_id: 1,
                 created_at: 0,
+                activated_at: 0,
+                council_approvals: 0,
+                execution_schedule: None,
                 status: ProposalStatus::finalized_successfully(ProposalDecisionStatus::Vetoed, 2),
                 title: b"title".to_vec(),
                 description: b"description".to_vec(),
@@ -963,6 +1241,9 @@ fn proposal_execution_succeeds_after_the_grace_period() {
             parameters: parameters_fixture.params(),
             proposer_id: 1,
             created_at: 0,
+            activated_at: 0,
+            council_approvals: 0,
+            execution_schedule: None,
             status: ProposalStatus::approved(ApprovedProposalStatus::PendingExecution, 0),
             title: b"title".to_vec(),
             description: b"description".to_vec(),
@@ -1066,6 +1347,9 @@ fn create_dummy_proposal_succeeds_with_stake() {
                 parameters: parameters_fixture.params(),
                 proposer_id: 1,
                 created_at: 0,
+                activated_at: 0,
+                council_approvals: 0,
+                execution_schedule: None,
                 status: ProposalStatus::Active(Some(ActiveStake {
                     stake_id: 0, // valid stake_id
                     source_account_id: 1
@@ -1136,6 +1420,8 @@ fn finalize_expired_proposal_and_check_stake_removing_with_balance_checks_succee
             slashing_threshold_percentage: 60,
             grace_period: 5,
             required_stake: Some(stake_amount),
+            weighted_voting: false,
+            constitutionality: 1,
         };
         let dummy_proposal = DummyProposalFixture::default()
             .with_parameters(parameters)
@@ -1163,6 +1449,9 @@ fn finalize_expired_proposal_and_check_stake_removing_with_balance_checks_succee
             parameters,
             proposer_id: 1,
             created_at: 0,
+            activated_at: 0,
+            council_approvals: 0,
+            execution_schedule: None,
             status: ProposalStatus::Active(Some(ActiveStake {
                 stake_id: 0,
                 source_account_id: 1,
@@ -1209,6 +1498,8 @@ fn proposal_cancellation_with_slashes_with_balance_checks_succeeds() {
             slashing_threshold_percentage: 60,
             grace_period: 5,
             required_stake: Some(stake_amount),
+            weighted_voting: false,
+            constitutionality: 1,
         };
         let dummy_proposal = DummyProposalFixture::default()
             .with_parameters(parameters)
@@ -1236,6 +1527,9 @@ fn proposal_cancellation_with_slashes_with_balance_checks_succeeds() {
             parameters,
             proposer_id: 1,
             created_at: 0,
+            activated_at: 0,
+            council_approvals: 0,
+            execution_schedule: None,
             status: ProposalStatus::Active(Some(ActiveStake {
                 stake_id: 0,
                 source_account_id: 1,
@@ -1399,6 +1693,9 @@ fn finalize_proposal_using_stake_mocks_failed() {
                     parameters: parameters_fixture.params(),
                     proposer_id: 1,
                     created_at: 0,
+                    activated_at: 0,
+                    council_approvals: 0,
+                    execution_schedule: None,
                     status: ProposalStatus::finalized(
                         ProposalDecisionStatus::Expired,
                         Some("Cannot remove stake"),
@@ -1428,6 +1725,8 @@ fn create_proposal_fails_with_invalid_threshold_parameters() {
             slashing_threshold_percentage: 60,
             grace_period: 5,
             required_stake: None,
+            weighted_voting: false,
+            constitutionality: 1,
         };
 
         let mut dummy_proposal = DummyProposalFixture::default().with_parameters(parameters);
@@ -1602,3 +1901,931 @@ fn slash_balance_is_calculated_correctly() {
         assert_eq!(slash_balance_with_stake, stake);
     });
 }
+
+#[test]
+fn weighted_voting_proposal_is_decided_by_voting_weight() {
+    initial_test_ext().execute_with(|| {
+        let mut parameters = ProposalParametersFixture::default()
+            .with_weighted_voting()
+            .params();
+        parameters.approval_threshold_percentage = 70;
+
+        let dummy_proposal = DummyProposalFixture::default().with_parameters(parameters);
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        // 75% of the voters approve, but only 60% of the voting weight
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+
+        assert_eq!(
+            ProposalsEngine::weighted_voting_results(proposal_id),
+            WeightedVotingResults {
+                abstentions: 0,
+                approvals: 6,
+                rejections: 4,
+                slashes: 0,
+            }
+        );
+
+        run_to_block_and_finalize(2);
+
+        let proposal = <Proposals<Test>>::get(proposal_id);
+
+        assert_eq!(
+            proposal.status,
+            ProposalStatus::finalized_successfully(ProposalDecisionStatus::Rejected, 0),
+        );
+    });
+}
+
+#[test]
+fn weighted_vote_change_moves_voting_weight() {
+    initial_test_ext().execute_with(|| {
+        let parameters = ProposalParametersFixture::default()
+            .with_weighted_voting()
+            .params();
+
+        let dummy_proposal = DummyProposalFixture::default().with_parameters(parameters);
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.auto_increment_voter_id = false;
+        vote_generator.vote_and_assert_ok(VoteKind::Slash);
+
+        assert_eq!(
+            ProposalsEngine::weighted_voting_results(proposal_id),
+            WeightedVotingResults {
+                abstentions: 0,
+                approvals: 1,
+                rejections: 0,
+                slashes: 2,
+            }
+        );
+        assert_eq!(
+            <VoteWeightByProposalByVoter<Test>>::get(&proposal_id, &2),
+            2
+        );
+    });
+}
+
+#[test]
+fn weighted_voting_uses_total_voting_weight_snapshot() {
+    initial_test_ext().execute_with(|| {
+        let parameters = ProposalParametersFixture::default()
+            .with_weighted_voting()
+            .params();
+
+        let dummy_proposal = DummyProposalFixture::default().with_parameters(parameters);
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        assert_eq!(
+            ProposalsEngine::total_voting_weight_by_proposal(proposal_id),
+            10
+        );
+
+        // total voting weight changes after the voting start
+        set_total_voting_weight(100);
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        let voting_status = ProposalsEngine::proposal_voting_status(proposal_id).unwrap();
+        assert!(voting_status.approval_quorum_reached);
+        assert_eq!(
+            voting_status.decision_if_voting_ended,
+            ProposalDecisionStatus::Approved(ApprovedProposalStatus::PendingExecution)
+        );
+    });
+}
+
+#[test]
+fn proposal_with_constitutionality_executes_after_next_council_approval() {
+    initial_test_ext().execute_with(|| {
+        let parameters = ProposalParametersFixture::default()
+            .with_constitutionality(2)
+            .params();
+
+        let dummy_proposal = DummyProposalFixture::default().with_parameters(parameters);
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        run_to_block_and_finalize(1);
+
+        let proposal = <Proposals<Test>>::get(proposal_id);
+
+        assert_eq!(
+            proposal.status,
+            ProposalStatus::PendingConstitutionality(None)
+        );
+        assert_eq!(proposal.council_approvals, 1);
+        assert!(!<ActiveProposalIds<Test>>::contains_key(proposal_id));
+        assert!(<PendingConstitutionalityProposalIds<Test>>::contains_key(
+            proposal_id
+        ));
+        assert_eq!(<ActiveProposalCount>::get(), 1);
+
+        // new council elected
+        ProposalsEngine::reset_active_proposals();
+        ProposalsEngine::reactivate_pending_constitutionality_proposals();
+
+        let proposal = <Proposals<Test>>::get(proposal_id);
+
+        assert_eq!(proposal.status, ProposalStatus::Active(None));
+        assert_eq!(proposal.activated_at, 1);
+        assert_eq!(proposal.voting_results, VotingResults::default());
+        assert!(<ActiveProposalIds<Test>>::contains_key(proposal_id));
+        assert!(!<PendingConstitutionalityProposalIds<Test>>::contains_key(
+            proposal_id
+        ));
+        assert_eq!(
+            <VoteExistsByProposalByVoter<Test>>::get(&proposal_id, &1),
+            VoteKind::default()
+        );
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        run_to_block_and_finalize(2);
+
+        let proposal = <Proposals<Test>>::get(proposal_id);
+
+        assert_eq!(
+            proposal.status,
+            ProposalStatus::approved(ApprovedProposalStatus::Executed, 1)
+        );
+        assert_eq!(proposal.council_approvals, 1);
+        assert_eq!(<ActiveProposalCount>::get(), 0);
+    });
+}
+
+#[test]
+fn proposal_pending_constitutionality_can_be_vetoed() {
+    initial_test_ext().execute_with(|| {
+        let parameters = ProposalParametersFixture::default()
+            .with_constitutionality(2)
+            .params();
+
+        let dummy_proposal = DummyProposalFixture::default().with_parameters(parameters);
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        run_to_block_and_finalize(1);
+
+        VetoProposalFixture::new(proposal_id).veto_and_assert(Ok(()));
+
+        let proposal = <Proposals<Test>>::get(proposal_id);
+
+        assert_eq!(
+            proposal.status,
+            ProposalStatus::finalized_successfully(ProposalDecisionStatus::Vetoed, 1),
+        );
+        assert!(!<PendingConstitutionalityProposalIds<Test>>::contains_key(
+            proposal_id
+        ));
+        assert_eq!(<ActiveProposalCount>::get(), 0);
+    });
+}
+
+#[test]
+fn create_proposal_fails_with_invalid_execution_block() {
+    initial_test_ext().execute_with(|| {
+        let parameters = ProposalParametersFixture::default()
+            .with_grace_period(2)
+            .params();
+
+        DummyProposalFixture::default()
+            .with_parameters(parameters)
+            .with_execution_schedule(ExecutionSchedule::AtBlock(4))
+            .create_proposal_and_assert(Err(Error::<Test>::InvalidExecutionBlock.into()));
+
+        DummyProposalFixture::default()
+            .with_parameters(parameters)
+            .with_execution_schedule(ExecutionSchedule::NotBefore(5))
+            .create_proposal_and_assert(Ok(1));
+    });
+}
+
+#[test]
+fn create_proposal_fails_with_exact_execution_block_and_constitutionality() {
+    initial_test_ext().execute_with(|| {
+        let parameters = ProposalParametersFixture::default()
+            .with_constitutionality(2)
+            .params();
+
+        DummyProposalFixture::default()
+            .with_parameters(parameters)
+            .with_execution_schedule(ExecutionSchedule::AtBlock(100))
+            .create_proposal_and_assert(Err(
+                Error::<Test>::ExactExecutionBlockWithConstitutionality.into(),
+            ));
+
+        DummyProposalFixture::default()
+            .with_parameters(parameters)
+            .with_execution_schedule(ExecutionSchedule::NotBefore(100))
+            .create_proposal_and_assert(Ok(1));
+    });
+}
+
+#[test]
+fn proposal_execution_postponed_until_scheduled_block() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default()
+            .with_execution_schedule(ExecutionSchedule::NotBefore(5));
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        run_to_block_and_finalize(4);
+
+        let proposal = <Proposals<Test>>::get(proposal_id);
+
+        assert_eq!(
+            proposal.status,
+            ProposalStatus::approved(ApprovedProposalStatus::PendingExecution, 0)
+        );
+        assert!(<PendingExecutionProposalIds<Test>>::contains_key(
+            proposal_id
+        ));
+
+        run_to_block_and_finalize(5);
+
+        let proposal = <Proposals<Test>>::get(proposal_id);
+
+        assert_eq!(
+            proposal.status,
+            ProposalStatus::approved(ApprovedProposalStatus::Executed, 0)
+        );
+        assert!(!<PendingExecutionProposalIds<Test>>::contains_key(
+            proposal_id
+        ));
+    });
+}
+
+#[test]
+fn proposal_execution_fails_when_exact_execution_block_missed() {
+    initial_test_ext().execute_with(|| {
+        let parameters = ProposalParametersFixture::default()
+            .with_constitutionality(2)
+            .params();
+
+        let dummy_proposal = DummyProposalFixture::default()
+            .with_parameters(parameters)
+            .with_execution_schedule(ExecutionSchedule::AtBlock(3));
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        run_to_block_and_finalize(4);
+
+        // new council elected after the scheduled block
+        ProposalsEngine::reactivate_pending_constitutionality_proposals();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        run_to_block_and_finalize(5);
+
+        let proposal = <Proposals<Test>>::get(proposal_id);
+
+        assert_eq!(
+            proposal.status,
+            ProposalStatus::approved(
+                ApprovedProposalStatus::failed_execution("ExecutionBlockMissed"),
+                4
+            )
+        );
+    });
+}
+
+#[test]
+fn create_proposal_fails_with_invalid_dependencies() {
+    initial_test_ext().execute_with(|| {
+        DummyProposalFixture::default()
+            .with_dependencies(vec![1])
+            .create_proposal_and_assert(Err(Error::<Test>::DependencyNotFound.into()));
+
+        DummyProposalFixture::default().create_proposal_and_assert(Ok(1));
+
+        DummyProposalFixture::default()
+            .with_dependencies(vec![1; 6])
+            .create_proposal_and_assert(Err(Error::<Test>::DependenciesLimitExceeded.into()));
+
+        DummyProposalFixture::default()
+            .with_dependencies(vec![1])
+            .create_proposal_and_assert(Ok(2));
+
+        assert_eq!(ProposalsEngine::proposal_dependencies(2), vec![1]);
+    });
+}
+
+#[test]
+fn proposal_execution_waits_for_dependencies() {
+    initial_test_ext().execute_with(|| {
+        let parameters = ProposalParametersFixture::default()
+            .with_grace_period(5)
+            .params();
+        let dependency_id = DummyProposalFixture::default()
+            .with_parameters(parameters)
+            .create_proposal_and_assert(Ok(1))
+            .unwrap();
+
+        let proposal_id = DummyProposalFixture::default()
+            .with_dependencies(vec![dependency_id])
+            .create_proposal_and_assert(Ok(2))
+            .unwrap();
+
+        for id in &[dependency_id, proposal_id] {
+            let mut vote_generator = VoteGenerator::new(*id);
+            vote_generator.vote_and_assert_ok(VoteKind::Approve);
+            vote_generator.vote_and_assert_ok(VoteKind::Approve);
+            vote_generator.vote_and_assert_ok(VoteKind::Approve);
+            vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        }
+
+        run_to_block_and_finalize(5);
+
+        assert_eq!(
+            <Proposals<Test>>::get(proposal_id).status,
+            ProposalStatus::approved(ApprovedProposalStatus::PendingExecution, 0)
+        );
+        assert_eq!(
+            <Proposals<Test>>::get(dependency_id).status,
+            ProposalStatus::approved(ApprovedProposalStatus::Executed, 0)
+        );
+
+        run_to_block_and_finalize(6);
+
+        assert_eq!(
+            <Proposals<Test>>::get(proposal_id).status,
+            ProposalStatus::approved(ApprovedProposalStatus::Executed, 0)
+        );
+    });
+}
+
+#[test]
+fn proposal_execution_fails_when_dependency_was_not_executed() {
+    initial_test_ext().execute_with(|| {
+        let dependency_id = DummyProposalFixture::default()
+            .create_proposal_and_assert(Ok(1))
+            .unwrap();
+
+        let proposal_id = DummyProposalFixture::default()
+            .with_dependencies(vec![dependency_id])
+            .create_proposal_and_assert(Ok(2))
+            .unwrap();
+
+        let mut vote_generator = VoteGenerator::new(dependency_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        run_to_block_and_finalize(1);
+
+        assert_eq!(
+            <Proposals<Test>>::get(proposal_id).status,
+            ProposalStatus::approved(
+                ApprovedProposalStatus::failed_execution("DependencyNotExecuted"),
+                0
+            )
+        );
+    });
+}
+
+#[test]
+fn create_batched_proposal_fails_with_empty_batch() {
+    initial_test_ext().execute_with(|| {
+        DummyProposalFixture::default()
+            .with_batch(Vec::new())
+            .create_proposal_and_assert(Err(Error::<Test>::InvalidBatch.into()));
+    });
+}
+
+#[test]
+fn batched_proposal_execution_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let counting_proposal = mock::proposals::Call::<Test>::counting_proposal();
+
+        let proposal_id = DummyProposalFixture::default()
+            .with_batch(vec![counting_proposal.clone(), counting_proposal])
+            .create_proposal_and_assert(Ok(1))
+            .unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        run_to_block_and_finalize(1);
+
+        assert_eq!(
+            <Proposals<Test>>::get(proposal_id).status,
+            ProposalStatus::approved(ApprovedProposalStatus::Executed, 0)
+        );
+        assert_eq!(ExecutionsCount::get(), 2);
+    });
+}
+
+#[test]
+fn batched_proposal_execution_reverts_all_calls_on_failure() {
+    initial_test_ext().execute_with(|| {
+        let counting_proposal = mock::proposals::Call::<Test>::counting_proposal();
+        let faulty_proposal = mock::proposals::Call::<Test>::faulty_proposal(
+            b"title".to_vec(),
+            b"description".to_vec(),
+        );
+
+        let proposal_id = DummyProposalFixture::default()
+            .with_batch(vec![counting_proposal, faulty_proposal])
+            .create_proposal_and_assert(Ok(1))
+            .unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        run_to_block_and_finalize(1);
+
+        assert_eq!(
+            <Proposals<Test>>::get(proposal_id).status,
+            ProposalStatus::approved(
+                ApprovedProposalStatus::failed_execution("ExecutionFailed"),
+                0
+            )
+        );
+        assert_eq!(ExecutionsCount::get(), 0);
+    });
+}
+
+#[test]
+fn dry_run_proposal_does_not_commit_changes() {
+    initial_test_ext().execute_with(|| {
+        let counting_proposal = mock::proposals::Call::<Test>::counting_proposal();
+
+        let proposal_id = DummyProposalFixture::default()
+            .with_proposal_code(counting_proposal.encode())
+            .create_proposal_and_assert(Ok(1))
+            .unwrap();
+
+        assert_eq!(ProposalsEngine::dry_run_proposal(proposal_id), Ok(()));
+        assert_eq!(ExecutionsCount::get(), 0);
+        assert_eq!(ProposalsEngine::executing_proposal_id(), None);
+        assert_eq!(
+            <Proposals<Test>>::get(proposal_id).status,
+            ProposalStatus::Active(None)
+        );
+    });
+}
+
+#[test]
+fn dry_run_proposal_returns_dispatch_error() {
+    initial_test_ext().execute_with(|| {
+        let faulty_proposal = mock::proposals::Call::<Test>::faulty_proposal(
+            b"title".to_vec(),
+            b"description".to_vec(),
+        );
+
+        let proposal_id = DummyProposalFixture::default()
+            .with_proposal_code(faulty_proposal.encode())
+            .create_proposal_and_assert(Ok(1))
+            .unwrap();
+
+        assert_eq!(
+            ProposalsEngine::dry_run_proposal(proposal_id),
+            Err(DryRunError::DispatchFailed {
+                module_index: None,
+                error_index: None,
+                message: b"ExecutionFailed".to_vec(),
+            })
+        );
+
+        assert_eq!(
+            ProposalsEngine::dry_run_proposal(2),
+            Err(DryRunError::ProposalNotFound)
+        );
+    });
+}
+
+#[test]
+fn dry_run_proposal_reports_unmet_dependencies() {
+    initial_test_ext().execute_with(|| {
+        let dependency_id = DummyProposalFixture::default()
+            .create_proposal_and_assert(Ok(1))
+            .unwrap();
+
+        let proposal_id = DummyProposalFixture::default()
+            .with_dependencies(vec![dependency_id])
+            .create_proposal_and_assert(Ok(2))
+            .unwrap();
+
+        assert_eq!(
+            ProposalsEngine::dry_run_proposal(proposal_id),
+            Err(DryRunError::UnmetDependencies(vec![dependency_id]))
+        );
+    });
+}
+
+#[test]
+fn amend_proposal_succeeds_and_archives_previous_version() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        let previous_code = dummy_proposal.proposal_code.clone();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        let amended_code = mock::proposals::Call::<Test>::counting_proposal().encode();
+        assert_eq!(
+            ProposalsEngine::amend_proposal(
+                1,
+                proposal_id,
+                b"amended title".to_vec(),
+                b"amended description".to_vec(),
+                amended_code.clone(),
+            ),
+            Ok(())
+        );
+
+        let proposal = ProposalsEngine::proposals(proposal_id);
+        assert_eq!(proposal.title, b"amended title".to_vec());
+        assert_eq!(proposal.description, b"amended description".to_vec());
+        assert_eq!(proposal.voting_results, VotingResults::default());
+        assert!(!<VoteExistsByProposalByVoter<Test>>::contains_key(
+            proposal_id,
+            1
+        ));
+        assert_eq!(ProposalsEngine::proposal_codes(proposal_id), amended_code);
+        assert_eq!(
+            ProposalsEngine::proposal_versions(proposal_id),
+            vec![ProposalVersion {
+                title: b"title".to_vec(),
+                description: b"description".to_vec(),
+                encoded_dispatchable_call_code: previous_code,
+                amended_at: 0,
+            }]
+        );
+
+        EventFixture::assert_events(vec![
+            RawEvent::ProposalCreated(1, proposal_id),
+            RawEvent::Voted(1, proposal_id, VoteKind::Approve, Vec::new()),
+            RawEvent::ProposalAmended(1, proposal_id),
+        ]);
+    });
+}
+
+#[test]
+fn amend_proposal_reopens_voting_for_attendance_tracking() {
+    initial_test_ext().execute_with(|| {
+        let proposal_id = DummyProposalFixture::default()
+            .create_proposal_and_assert(Ok(1))
+            .unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        assert_eq!(observed_attendance(), (1, 1));
+
+        let amended_code = mock::proposals::Call::<Test>::counting_proposal().encode();
+        assert_eq!(
+            ProposalsEngine::amend_proposal(
+                1,
+                proposal_id,
+                b"amended title".to_vec(),
+                b"amended description".to_vec(),
+                amended_code,
+            ),
+            Ok(())
+        );
+
+        // The voter votes again on the amended proposal: the attendance stays full.
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        assert_eq!(observed_attendance(), (2, 2));
+    });
+}
+
+#[test]
+fn amend_proposal_fails_with_exceeded_amendments_limit() {
+    initial_test_ext().execute_with(|| {
+        let proposal_id = DummyProposalFixture::default()
+            .create_proposal_and_assert(Ok(1))
+            .unwrap();
+
+        let amend_proposal = || {
+            ProposalsEngine::amend_proposal(
+                1,
+                proposal_id,
+                b"amended title".to_vec(),
+                b"amended description".to_vec(),
+                Vec::new(),
+            )
+        };
+
+        assert_eq!(amend_proposal(), Ok(()));
+        assert_eq!(amend_proposal(), Ok(()));
+        assert_eq!(
+            amend_proposal(),
+            Err(Error::<Test>::MaxProposalAmendmentsExceeded.into())
+        );
+        assert_eq!(ProposalsEngine::proposal_versions(proposal_id).len(), 2);
+    });
+}
+
+#[test]
+fn amend_proposal_fails_with_invalid_parameters() {
+    initial_test_ext().execute_with(|| {
+        let parameters = ProposalParametersFixture::default()
+            .with_voting_period(10)
+            .params();
+        let proposal_id = DummyProposalFixture::default()
+            .with_parameters(parameters.clone())
+            .create_proposal_and_assert(Ok(1))
+            .unwrap();
+
+        let amend_proposal = |proposer_id, proposal_id, title: &[u8]| {
+            ProposalsEngine::amend_proposal(
+                proposer_id,
+                proposal_id,
+                title.to_vec(),
+                b"description".to_vec(),
+                Vec::new(),
+            )
+        };
+
+        assert_eq!(
+            amend_proposal(1, 2, b"title"),
+            Err(Error::<Test>::ProposalNotFound.into())
+        );
+        assert_eq!(
+            amend_proposal(2, proposal_id, b"title"),
+            Err(Error::<Test>::NotAuthor.into())
+        );
+        assert_eq!(
+            amend_proposal(1, proposal_id, b""),
+            Err(Error::<Test>::EmptyTitleProvided.into())
+        );
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        run_to_block(6);
+
+        assert_eq!(
+            amend_proposal(1, proposal_id, b"title"),
+            Err(Error::<Test>::AmendmentPeriodExpired.into())
+        );
+
+        // proposals without votes can be amended after the amendment period
+        let not_voted_proposal_id = DummyProposalFixture::default()
+            .with_parameters(parameters)
+            .create_proposal_and_assert(Ok(2))
+            .unwrap();
+
+        run_to_block(12);
+
+        assert_eq!(amend_proposal(1, not_voted_proposal_id, b"title"), Ok(()));
+
+        CancelProposalFixture::new(not_voted_proposal_id).cancel_and_assert(Ok(()));
+
+        assert_eq!(
+            amend_proposal(1, not_voted_proposal_id, b"title"),
+            Err(Error::<Test>::ProposalFinalized.into())
+        );
+    });
+}
+
+#[test]
+fn create_proposal_fails_with_exceeded_per_proposer_and_per_type_limits() {
+    initial_test_ext().execute_with(|| {
+        assert_eq!(
+            ProposalsEngine::set_max_active_proposals_per_proposer(
+                RawOrigin::Signed(1).into(),
+                Some(1)
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            ProposalsEngine::set_max_active_proposals_per_proposer(RawOrigin::Root.into(), Some(1)),
+            Ok(())
+        );
+        assert_eq!(
+            ProposalsEngine::set_max_active_proposals_per_type(RawOrigin::Root.into(), 1, Some(2)),
+            Ok(())
+        );
+
+        let proposal_id = DummyProposalFixture::default()
+            .with_proposal_type(1)
+            .create_proposal_and_assert(Ok(1))
+            .unwrap();
+
+        DummyProposalFixture::default()
+            .with_proposal_type(2)
+            .create_proposal_and_assert(Err(
+                Error::<Test>::MaxActiveProposalsPerProposerExceeded.into()
+            ));
+
+        DummyProposalFixture::default()
+            .with_proposer(2)
+            .with_proposal_type(1)
+            .create_proposal_and_assert(Ok(2));
+
+        DummyProposalFixture::default()
+            .with_proposer(3)
+            .with_proposal_type(1)
+            .create_proposal_and_assert(Err(
+                Error::<Test>::MaxActiveProposalsPerTypeExceeded.into()
+            ));
+
+        assert_eq!(ProposalsEngine::active_proposal_count_by_proposer(1), 1);
+        assert_eq!(ProposalsEngine::active_proposal_count_by_type(1), 2);
+
+        CancelProposalFixture::new(proposal_id).cancel_and_assert(Ok(()));
+
+        assert_eq!(ProposalsEngine::active_proposal_count_by_proposer(1), 0);
+        assert_eq!(ProposalsEngine::active_proposal_count_by_type(1), 1);
+
+        DummyProposalFixture::default()
+            .with_proposer(3)
+            .with_proposal_type(1)
+            .create_proposal_and_assert(Ok(3));
+
+        assert_eq!(
+            ProposalsEngine::set_max_active_proposals_per_type(RawOrigin::Root.into(), 1, None),
+            Ok(())
+        );
+
+        DummyProposalFixture::default()
+            .with_proposal_type(1)
+            .create_proposal_and_assert(Ok(4));
+    });
+}
+
+#[test]
+fn finalized_proposal_gets_archived_after_retention_period() {
+    initial_test_ext().execute_with(|| {
+        let proposal_id = DummyProposalFixture::default()
+            .create_proposal_and_assert(Ok(1))
+            .unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+
+        VetoProposalFixture::new(proposal_id).veto_and_assert(Ok(()));
+
+        assert!(<crate::ProposalsToArchive<Test>>::contains_key(
+            20,
+            proposal_id
+        ));
+
+        run_to_block_and_finalize(19);
+
+        assert!(<crate::Proposals<Test>>::contains_key(proposal_id));
+        assert_eq!(ProposalsEngine::archived_proposals(proposal_id), None);
+
+        run_to_block_and_finalize(20);
+
+        let archived_proposal = ArchivedProposal {
+            proposer_id: 1,
+            created_at: 0,
+            finalized_at: 0,
+            decision: ProposalDecisionStatus::Vetoed,
+            voting_results: VotingResults {
+                abstentions: 0,
+                approvals: 0,
+                rejections: 1,
+                slashes: 0,
+            },
+        };
+
+        assert_eq!(
+            ProposalsEngine::archived_proposals(proposal_id),
+            Some(archived_proposal.clone())
+        );
+        assert!(!<crate::Proposals<Test>>::contains_key(proposal_id));
+        assert!(!<crate::DispatchableCallCode<Test>>::contains_key(
+            proposal_id
+        ));
+        assert!(!<crate::ProposalsToArchive<Test>>::contains_key(
+            20,
+            proposal_id
+        ));
+        assert!(ProposalsEngine::proposal_votes(proposal_id).is_empty());
+
+        assert_eq!(
+            ProposalsEngine::proposal_history(0, 10),
+            vec![(proposal_id, archived_proposal)]
+        );
+        assert!(ProposalsEngine::proposal_history(2, 10).is_empty());
+
+        EventFixture::assert_events(vec![
+            RawEvent::ProposalCreated(1, proposal_id),
+            RawEvent::Voted(1, proposal_id, VoteKind::Reject, Vec::new()),
+            RawEvent::ProposalStatusUpdated(
+                proposal_id,
+                ProposalStatus::finalized_successfully(ProposalDecisionStatus::Vetoed, 0),
+            ),
+            RawEvent::ProposalArchived(proposal_id),
+        ]);
+
+        // archived proposals can be used as dependencies
+        DummyProposalFixture::default()
+            .with_dependencies(vec![proposal_id])
+            .create_proposal_and_assert(Ok(2));
+    });
+}
+
+#[test]
+fn migrate_proposals_schedules_archiving_of_finalized_proposals() {
+    initial_test_ext().execute_with(|| {
+        // Emulates the proposals stored with the previous layout: the proposal parameters without
+        // the weighted voting and the constitutionality, no activation block, council approvals
+        // and execution schedule.
+        let put_old_proposal = |proposal_id: u32, status: ProposalStatus<u64, u64, u64>| {
+            let old_parameters = (3u64, 0u64, 60u32, 60u32, 60u32, 60u32, None::<u64>);
+
+            put_storage_value(
+                b"ProposalEngine",
+                b"Proposals",
+                &Blake2_128Concat::hash(&proposal_id.encode()),
+                (
+                    old_parameters,
+                    1u64,
+                    b"title".to_vec(),
+                    b"description".to_vec(),
+                    0u64,
+                    status,
+                    VotingResults::default(),
+                ),
+            );
+        };
+
+        put_old_proposal(1, ProposalStatus::Active(None));
+        put_old_proposal(
+            2,
+            ProposalStatus::finalized_successfully(ProposalDecisionStatus::Rejected, 0),
+        );
+        put_old_proposal(
+            3,
+            ProposalStatus::approved(ApprovedProposalStatus::PendingExecution, 0),
+        );
+        <PendingExecutionProposalIds<Test>>::insert(3, ());
+
+        ProposalsEngine::migrate_proposals();
+
+        let proposal = ProposalsEngine::proposals(2);
+        assert_eq!(proposal.parameters.constitutionality, 1);
+        assert_eq!(proposal.activated_at, 0);
+
+        let archiving_block = ProposalRetentionPeriod::get();
+        assert!(!<ProposalsToArchive<Test>>::contains_key(
+            archiving_block,
+            1
+        ));
+        assert!(<ProposalsToArchive<Test>>::contains_key(archiving_block, 2));
+        assert!(!<ProposalsToArchive<Test>>::contains_key(
+            archiving_block,
+            3
+        ));
+    });
+}
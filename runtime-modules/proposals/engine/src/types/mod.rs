@@ -4,10 +4,11 @@
 #![warn(missing_docs)]
 
 use codec::{Decode, Encode};
-use frame_support::dispatch::DispatchResult;
+use frame_support::dispatch::{DispatchError, DispatchResult};
 use frame_support::traits::Currency;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
+use sp_arithmetic::traits::Saturating;
 use sp_runtime::Perbill;
 use sp_std::boxed::Box;
 use sp_std::cmp::PartialOrd;
@@ -78,6 +79,14 @@ pub struct ProposalParameters<BlockNumber, Balance> {
 
     /// Proposal stake
     pub required_stake: Option<Balance>,
+
+    /// Votes are weighted by the voting weight provider instead of being counted by head. Quorum
+    /// and threshold percentages are evaluated over the voting weight.
+    pub weighted_voting: bool,
+
+    /// Number of consecutive councils that must approve the proposal before its execution.
+    /// Values below two require the approval of the current council only.
+    pub constitutionality: u32,
 }
 
 /// Contains current voting results
@@ -108,12 +117,169 @@ impl VotingResults {
         }
     }
 
+    /// Remove vote from the related counter. Used on the vote change.
+    pub fn remove_vote(&mut self, vote: VoteKind) {
+        match vote {
+            VoteKind::Abstain => self.abstentions = self.abstentions.saturating_sub(1),
+            VoteKind::Approve => self.approvals = self.approvals.saturating_sub(1),
+            VoteKind::Reject => self.rejections = self.rejections.saturating_sub(1),
+            VoteKind::Slash => self.slashes = self.slashes.saturating_sub(1),
+        }
+    }
+
     /// Calculates number of votes so far
     pub fn votes_number(&self) -> u32 {
         self.abstentions + self.approvals + self.rejections + self.slashes
     }
 }
 
+/// Contains current voting results weighted by the voters voting weight
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct WeightedVotingResults {
+    /// 'Abstain' votes weight
+    pub abstentions: u64,
+
+    /// 'Approve' votes weight
+    pub approvals: u64,
+
+    /// 'Reject' votes weight
+    pub rejections: u64,
+
+    /// 'Slash' votes weight
+    pub slashes: u64,
+}
+
+impl WeightedVotingResults {
+    /// Add vote weight to the related counter
+    pub fn add_vote(&mut self, vote: VoteKind, weight: u64) {
+        match vote {
+            VoteKind::Abstain => self.abstentions = self.abstentions.saturating_add(weight),
+            VoteKind::Approve => self.approvals = self.approvals.saturating_add(weight),
+            VoteKind::Reject => self.rejections = self.rejections.saturating_add(weight),
+            VoteKind::Slash => self.slashes = self.slashes.saturating_add(weight),
+        }
+    }
+
+    /// Remove vote weight from the related counter. Used on the vote change.
+    pub fn remove_vote(&mut self, vote: VoteKind, weight: u64) {
+        match vote {
+            VoteKind::Abstain => self.abstentions = self.abstentions.saturating_sub(weight),
+            VoteKind::Approve => self.approvals = self.approvals.saturating_sub(weight),
+            VoteKind::Reject => self.rejections = self.rejections.saturating_sub(weight),
+            VoteKind::Slash => self.slashes = self.slashes.saturating_sub(weight),
+        }
+    }
+
+    /// Calculates weight of the votes so far
+    pub fn votes_weight(&self) -> u64 {
+        self.abstentions
+            .saturating_add(self.approvals)
+            .saturating_add(self.rejections)
+            .saturating_add(self.slashes)
+    }
+}
+
+/// Defines when the approved proposal gets executed, in addition to the grace period expiration.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ExecutionSchedule<BlockNumber> {
+    /// The proposal gets executed exactly at the block. The execution fails if the proposal
+    /// cannot be executed at the block (grace period expires later). Not allowed for the
+    /// proposals requiring the approval of several councils.
+    AtBlock(BlockNumber),
+
+    /// The proposal gets executed at the block or later, when the grace period expires.
+    NotBefore(BlockNumber),
+}
+
+impl<BlockNumber: Copy> ExecutionSchedule<BlockNumber> {
+    /// Returns the scheduled execution block.
+    pub fn execution_block(&self) -> BlockNumber {
+        match self {
+            ExecutionSchedule::AtBlock(block) | ExecutionSchedule::NotBefore(block) => *block,
+        }
+    }
+}
+
+/// Archived version of the amended proposal.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct ProposalVersion<BlockNumber> {
+    /// Proposal title of the version.
+    pub title: Vec<u8>,
+
+    /// Proposal body of the version.
+    pub description: Vec<u8>,
+
+    /// Encoded proposal code of the version.
+    pub encoded_dispatchable_call_code: Vec<u8>,
+
+    /// When the version was replaced by the amendment.
+    pub amended_at: BlockNumber,
+}
+
+/// Compact record of the finalized proposal moved to the proposals history after the retention
+/// period.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct ArchivedProposal<BlockNumber, ProposerId> {
+    /// Identifier of member proposing.
+    pub proposer_id: ProposerId,
+
+    /// When it was created.
+    pub created_at: BlockNumber,
+
+    /// Proposal finalization block number.
+    pub finalized_at: BlockNumber,
+
+    /// Final proposal decision including the execution outcome for the approved proposals.
+    pub decision: ProposalDecisionStatus,
+
+    /// Final voting results of the proposal.
+    pub voting_results: VotingResults,
+}
+
+/// Proposal type id. Defined by the proposal creator (eg.: proposals codex).
+pub type ProposalType = u32;
+
+/// Parameters for the proposal creation.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ProposalCreationParameters<BlockNumber, MemberId, Balance, AccountId, ProposalId> {
+    /// Account id of the proposer.
+    pub account_id: AccountId,
+
+    /// Member id of the proposer.
+    pub proposer_id: MemberId,
+
+    /// Proposal type defined by the proposal creator. Used for the per-type active proposal limits.
+    pub proposal_type: ProposalType,
+
+    /// Proposal parameters.
+    pub parameters: ProposalParameters<BlockNumber, Balance>,
+
+    /// Proposal title.
+    pub title: Vec<u8>,
+
+    /// Proposal description.
+    pub description: Vec<u8>,
+
+    /// Stake balance for the proposal.
+    pub stake_balance: Option<Balance>,
+
+    /// Encoded proposal code (executable call).
+    pub encoded_dispatchable_call_code: Vec<u8>,
+
+    /// Optional execution block defined by the proposer.
+    pub execution_schedule: Option<ExecutionSchedule<BlockNumber>>,
+
+    /// Proposals that must be executed successfully before the proposal execution.
+    pub dependencies: Vec<ProposalId>,
+
+    /// The encoded code is a list of encoded calls (`Vec<Vec<u8>>`) executed atomically.
+    pub batch: bool,
+}
+
 /// Contains created stake id and source account for the stake balance
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, Copy, PartialEq, Eq, Debug)]
@@ -125,6 +291,71 @@ pub struct ActiveStake<StakeId, AccountId> {
     pub source_account_id: AccountId,
 }
 
+/// Voting progress of the active proposal computed by the current votes.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct ProposalVotingStatus<BlockNumber> {
+    /// Approval quorum is reached by the current votes.
+    pub approval_quorum_reached: bool,
+
+    /// Approval threshold is reached by the current votes.
+    pub approval_threshold_reached: bool,
+
+    /// Slashing quorum is reached by the current votes.
+    pub slashing_quorum_reached: bool,
+
+    /// Slashing threshold is reached by the current votes.
+    pub slashing_threshold_reached: bool,
+
+    /// Blocks remaining until the end of the voting period.
+    pub blocks_remaining: BlockNumber,
+
+    /// Proposal decision if the voting period ended now.
+    pub decision_if_voting_ended: ProposalDecisionStatus,
+}
+
+/// Reason the proposal execution would fail, reported by the proposal dry run. Unlike the
+/// `DispatchError`, keeps the error message in the encoding.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub enum DryRunError<ProposalId> {
+    /// The proposal does not exist.
+    ProposalNotFound,
+
+    /// The proposal dependencies that were not executed successfully yet.
+    UnmetDependencies(Vec<ProposalId>),
+
+    /// The proposal call dispatch failed.
+    DispatchFailed {
+        /// Index of the module that returned the error. None for the errors not related to a
+        /// module.
+        module_index: Option<u8>,
+
+        /// Error index within the module. None for the errors not related to a module.
+        error_index: Option<u8>,
+
+        /// Error message.
+        message: Vec<u8>,
+    },
+}
+
+impl<ProposalId> From<DispatchError> for DryRunError<ProposalId> {
+    fn from(dispatch_error: DispatchError) -> Self {
+        let (module_index, error_index) = match dispatch_error {
+            DispatchError::Module { index, error, .. } => (Some(index), Some(error)),
+            _ => (None, None),
+        };
+
+        let message: &'static str = dispatch_error.into();
+
+        DryRunError::DispatchFailed {
+            module_index,
+            error_index,
+            message: message.as_bytes().to_vec(),
+        }
+    }
+}
+
 /// 'Proposal' contains information necessary for the proposal system functioning.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
@@ -144,6 +375,16 @@ pub struct Proposal<BlockNumber, ProposerId, Balance, StakeId, AccountId> {
     /// When it was created.
     pub created_at: BlockNumber,
 
+    /// When the current voting started. It differs from the creation block when the voting
+    /// was re-opened for a new council.
+    pub activated_at: BlockNumber,
+
+    /// Number of councils that approved the proposal so far.
+    pub council_approvals: u32,
+
+    /// Optional execution block defined by the proposer.
+    pub execution_schedule: Option<ExecutionSchedule<BlockNumber>>,
+
     /// Current proposal status
     pub status: ProposalStatus<BlockNumber, StakeId, AccountId>,
 
@@ -154,13 +395,18 @@ pub struct Proposal<BlockNumber, ProposerId, Balance, StakeId, AccountId> {
 impl<BlockNumber, ProposerId, Balance, StakeId, AccountId>
     Proposal<BlockNumber, ProposerId, Balance, StakeId, AccountId>
 where
-    BlockNumber: Add<Output = BlockNumber> + PartialOrd + Copy,
+    BlockNumber: Add<Output = BlockNumber> + Saturating + PartialOrd + Copy,
     StakeId: Clone,
     AccountId: Clone,
 {
+    /// Returns the block when the current voting period ends.
+    pub fn voting_period_end(&self) -> BlockNumber {
+        self.activated_at + self.parameters.voting_period
+    }
+
     /// Returns whether voting period expired by now
     pub fn is_voting_period_expired(&self, now: BlockNumber) -> bool {
-        now >= self.created_at + self.parameters.voting_period
+        now >= self.voting_period_end()
     }
 
     /// Returns whether grace period expired by now.
@@ -176,6 +422,24 @@ where
         false
     }
 
+    /// Returns whether the approved proposal can be executed by now: the grace period expired
+    /// and the scheduled execution block (if any) was reached.
+    pub fn is_ready_for_execution(&self, now: BlockNumber) -> bool {
+        self.is_grace_period_expired(now)
+            && self
+                .execution_schedule
+                .map_or(true, |schedule| now >= schedule.execution_block())
+    }
+
+    /// Returns whether the exact execution block passed before the proposal became executable.
+    pub fn is_execution_block_missed(&self, now: BlockNumber) -> bool {
+        if let Some(ExecutionSchedule::AtBlock(block)) = self.execution_schedule {
+            return now > block;
+        }
+
+        false
+    }
+
     /// Determines the finalized proposal status using voting results tally for current proposal.
     /// Calculates votes, takes in account voting period expiration.
     /// If voting process is in progress, then decision status is None.
@@ -186,15 +450,124 @@ where
         total_voters_count: u32,
         now: BlockNumber,
     ) -> Option<ProposalDecisionStatus> {
-        let proposal_status_resolution = ProposalStatusResolution {
+        Self::resolve_decision_status(self.status_resolution(total_voters_count, now))
+    }
+
+    /// Determines the finalized proposal status using weighted voting results for current
+    /// proposal. Quorum and thresholds are evaluated over the voting weight.
+    /// Parameters: weighted voting results, total voting weight of all voters, current time.
+    /// Returns the proposal finalized status if any.
+    pub fn define_weighted_proposal_decision_status(
+        &self,
+        weighted_voting_results: &WeightedVotingResults,
+        total_voting_weight: u64,
+        now: BlockNumber,
+    ) -> Option<ProposalDecisionStatus> {
+        Self::resolve_decision_status(self.weighted_status_resolution(
+            weighted_voting_results,
+            total_voting_weight,
+            now,
+        ))
+    }
+
+    /// Computes the voting progress using voting results tally for current proposal.
+    /// Parameters: current time, total voters number involved (council size).
+    pub fn define_voting_status(
+        &self,
+        total_voters_count: u32,
+        now: BlockNumber,
+    ) -> ProposalVotingStatus<BlockNumber> {
+        Self::resolve_voting_status(self.status_resolution(total_voters_count, now))
+    }
+
+    /// Computes the voting progress using weighted voting results for current proposal.
+    /// Parameters: weighted voting results, total voting weight of all voters, current time.
+    pub fn define_weighted_voting_status(
+        &self,
+        weighted_voting_results: &WeightedVotingResults,
+        total_voting_weight: u64,
+        now: BlockNumber,
+    ) -> ProposalVotingStatus<BlockNumber> {
+        Self::resolve_voting_status(self.weighted_status_resolution(
+            weighted_voting_results,
+            total_voting_weight,
+            now,
+        ))
+    }
+
+    // Creates the status resolution with votes counted by head.
+    fn status_resolution(
+        &self,
+        total_voters_count: u32,
+        now: BlockNumber,
+    ) -> ProposalStatusResolution<BlockNumber, ProposerId, Balance, StakeId, AccountId> {
+        ProposalStatusResolution {
+            proposal: self,
+            approvals: self.voting_results.approvals.into(),
+            slashes: self.voting_results.slashes.into(),
+            now,
+            votes_count: self.voting_results.votes_number().into(),
+            total_voters_count: total_voters_count.into(),
+        }
+    }
+
+    // Creates the status resolution with votes weighted by the voting weight.
+    fn weighted_status_resolution(
+        &self,
+        weighted_voting_results: &WeightedVotingResults,
+        total_voting_weight: u64,
+        now: BlockNumber,
+    ) -> ProposalStatusResolution<BlockNumber, ProposerId, Balance, StakeId, AccountId> {
+        ProposalStatusResolution {
             proposal: self,
-            approvals: self.voting_results.approvals,
-            slashes: self.voting_results.slashes,
+            approvals: weighted_voting_results.approvals,
+            slashes: weighted_voting_results.slashes,
             now,
-            votes_count: self.voting_results.votes_number(),
-            total_voters_count,
+            votes_count: weighted_voting_results.votes_weight(),
+            total_voters_count: total_voting_weight,
+        }
+    }
+
+    // Evaluates quorums and thresholds. The decision is evaluated as if the voting period ended
+    // by now: the proposal is expired unless it is approved or slashed.
+    fn resolve_voting_status(
+        proposal_status_resolution: ProposalStatusResolution<
+            BlockNumber,
+            ProposerId,
+            Balance,
+            StakeId,
+            AccountId,
+        >,
+    ) -> ProposalVotingStatus<BlockNumber> {
+        let proposal = proposal_status_resolution.proposal;
+        let voting_ended_resolution = ProposalStatusResolution {
+            now: proposal.voting_period_end(),
+            ..proposal_status_resolution
         };
 
+        ProposalVotingStatus {
+            approval_quorum_reached: proposal_status_resolution.is_approval_quorum_reached(),
+            approval_threshold_reached: proposal_status_resolution.is_approval_threshold_reached(),
+            slashing_quorum_reached: proposal_status_resolution.is_slashing_quorum_reached(),
+            slashing_threshold_reached: proposal_status_resolution.is_slashing_threshold_reached(),
+            blocks_remaining: proposal
+                .voting_period_end()
+                .saturating_sub(proposal_status_resolution.now),
+            decision_if_voting_ended: Self::resolve_decision_status(voting_ended_resolution)
+                .unwrap_or(ProposalDecisionStatus::Expired),
+        }
+    }
+
+    // Calculates votes, takes in account voting period expiration.
+    fn resolve_decision_status(
+        proposal_status_resolution: ProposalStatusResolution<
+            BlockNumber,
+            ProposerId,
+            Balance,
+            StakeId,
+            AccountId,
+        >,
+    ) -> Option<ProposalDecisionStatus> {
         if proposal_status_resolution.is_approval_quorum_reached()
             && proposal_status_resolution.is_approval_threshold_reached()
         {
@@ -221,6 +594,22 @@ where
             self.voting_results = VotingResults::default();
         }
     }
+
+    /// Returns whether the proposal approved by the current council requires the approval
+    /// of the next councils.
+    pub fn requires_next_council_approval(&self) -> bool {
+        self.council_approvals + 1 < self.parameters.constitutionality
+    }
+
+    /// Re-opens the voting for the new council of the proposal pending constitutionality.
+    /// Proposal with other status won't be changed.
+    pub fn reactivate_proposal(&mut self, now: BlockNumber) {
+        if let ProposalStatus::PendingConstitutionality(active_stake) = self.status.clone() {
+            self.status = ProposalStatus::Active(active_stake);
+            self.activated_at = now;
+            self.voting_results = VotingResults::default();
+        }
+    }
 }
 
 /// Provides data for the voting.
@@ -229,20 +618,64 @@ pub trait VotersParameters {
     fn total_voters_count() -> u32;
 }
 
-// Calculates quorum, votes threshold, expiration status
+/// Observes the proposal votes. Allows to track the voters participation.
+pub trait ProposalVoteObserver<AccountId, ProposalId> {
+    /// Notifies about the proposal opened for the voting of the current council: created,
+    /// amended, reset for the new council or reactivated.
+    fn proposal_voting_opened(proposal_id: ProposalId);
+
+    /// Notifies about the vote. It is called after the vote was saved.
+    fn proposal_voted(voter: &AccountId, proposal_id: ProposalId);
+}
+
+impl<AccountId, ProposalId> ProposalVoteObserver<AccountId, ProposalId> for () {
+    fn proposal_voting_opened(_proposal_id: ProposalId) {}
+
+    fn proposal_voted(_voter: &AccountId, _proposal_id: ProposalId) {}
+}
+
+/// Observes the proposal finalization and archiving. Allows to release and prune the proposal
+/// data stored outside the engine.
+pub trait ProposalObserver<ProposalId> {
+    /// Notifies about the final decision of the proposal: rejection, cancellation, veto, expiration,
+    /// slashing or execution. The proposal stays in the engine until the archiving.
+    fn proposal_finalized(proposal_id: ProposalId);
+
+    /// Notifies about the proposal moved to the proposals history. It is called after the engine
+    /// data of the proposal was pruned.
+    fn proposal_archived(proposal_id: ProposalId);
+}
+
+impl<ProposalId> ProposalObserver<ProposalId> for () {
+    fn proposal_finalized(_proposal_id: ProposalId) {}
+
+    fn proposal_archived(_proposal_id: ProposalId) {}
+}
+
+/// Provides the voting weight for the proposals with the weighted voting.
+pub trait VotingWeightProvider<AccountId> {
+    /// Voting weight of the voter.
+    fn voting_weight(voter: &AccountId) -> u64;
+
+    /// Total voting weight of all the voters.
+    fn total_voting_weight() -> u64;
+}
+
+// Calculates quorum, votes threshold, expiration status. Votes are either counted by head or
+// weighted by the voting weight.
 struct ProposalStatusResolution<'a, BlockNumber, ProposerId, Balance, StakeId, AccountId> {
     proposal: &'a Proposal<BlockNumber, ProposerId, Balance, StakeId, AccountId>,
     now: BlockNumber,
-    votes_count: u32,
-    total_voters_count: u32,
-    approvals: u32,
-    slashes: u32,
+    votes_count: u64,
+    total_voters_count: u64,
+    approvals: u64,
+    slashes: u64,
 }
 
 impl<'a, BlockNumber, ProposerId, Balance, StakeId, AccountId>
     ProposalStatusResolution<'a, BlockNumber, ProposerId, Balance, StakeId, AccountId>
 where
-    BlockNumber: Add<Output = BlockNumber> + PartialOrd + Copy,
+    BlockNumber: Add<Output = BlockNumber> + Saturating + PartialOrd + Copy,
     StakeId: Clone,
     AccountId: Clone,
 {
@@ -381,6 +814,7 @@ mod tests {
         let mut proposal = ProposalObject::default();
 
         proposal.created_at = 1;
+        proposal.activated_at = 1;
         proposal.parameters.voting_period = 3;
 
         assert!(proposal.is_voting_period_expired(4));
@@ -391,6 +825,7 @@ mod tests {
         let mut proposal = ProposalObject::default();
 
         proposal.created_at = 1;
+        proposal.activated_at = 1;
         proposal.parameters.voting_period = 3;
 
         assert!(!proposal.is_voting_period_expired(3));
@@ -445,6 +880,7 @@ mod tests {
         let mut proposal = ProposalObject::default();
         let now = 5;
         proposal.created_at = 1;
+        proposal.activated_at = 1;
         proposal.parameters.voting_period = 3;
         proposal.parameters.approval_quorum_percentage = 80;
         proposal.parameters.approval_threshold_percentage = 40;
@@ -477,6 +913,7 @@ mod tests {
         let now = 2;
         let mut proposal = ProposalObject::default();
         proposal.created_at = 1;
+        proposal.activated_at = 1;
         proposal.parameters.voting_period = 3;
         proposal.parameters.approval_quorum_percentage = 60;
         proposal.parameters.slashing_quorum_percentage = 50;
@@ -506,12 +943,69 @@ mod tests {
         );
     }
 
+    #[test]
+    fn define_voting_status_returns_approved_decision() {
+        let now = 2;
+        let mut proposal = ProposalObject::default();
+        proposal.created_at = 1;
+        proposal.activated_at = 1;
+        proposal.parameters.voting_period = 3;
+        proposal.parameters.approval_quorum_percentage = 60;
+        proposal.parameters.approval_threshold_percentage = 60;
+        proposal.parameters.slashing_quorum_percentage = 50;
+        proposal.parameters.slashing_threshold_percentage = 50;
+
+        proposal.voting_results.add_vote(VoteKind::Reject);
+        proposal.voting_results.add_vote(VoteKind::Approve);
+        proposal.voting_results.add_vote(VoteKind::Approve);
+
+        assert_eq!(
+            proposal.define_voting_status(5, now),
+            ProposalVotingStatus {
+                approval_quorum_reached: true,
+                approval_threshold_reached: true,
+                slashing_quorum_reached: true,
+                slashing_threshold_reached: false,
+                blocks_remaining: 2,
+                decision_if_voting_ended: ProposalDecisionStatus::Approved(
+                    ApprovedProposalStatus::PendingExecution
+                ),
+            }
+        );
+    }
+
+    #[test]
+    fn define_voting_status_returns_expired_decision_without_quorum() {
+        let now = 5;
+        let mut proposal = ProposalObject::default();
+        proposal.created_at = 1;
+        proposal.activated_at = 1;
+        proposal.parameters.voting_period = 3;
+        proposal.parameters.approval_quorum_percentage = 60;
+        proposal.parameters.slashing_quorum_percentage = 60;
+
+        proposal.voting_results.add_vote(VoteKind::Approve);
+
+        assert_eq!(
+            proposal.define_voting_status(5, now),
+            ProposalVotingStatus {
+                approval_quorum_reached: false,
+                approval_threshold_reached: true,
+                slashing_quorum_reached: false,
+                slashing_threshold_reached: true,
+                blocks_remaining: 0,
+                decision_if_voting_ended: ProposalDecisionStatus::Expired,
+            }
+        );
+    }
+
     #[test]
     fn define_proposal_decision_status_returns_rejected() {
         let mut proposal = ProposalObject::default();
         let now = 2;
 
         proposal.created_at = 1;
+        proposal.activated_at = 1;
         proposal.parameters.voting_period = 3;
         proposal.parameters.approval_quorum_percentage = 50;
         proposal.parameters.approval_threshold_percentage = 51;
@@ -546,6 +1040,7 @@ mod tests {
         let now = 2;
 
         proposal.created_at = 1;
+        proposal.activated_at = 1;
         proposal.parameters.voting_period = 3;
         proposal.parameters.approval_quorum_percentage = 50;
         proposal.parameters.approval_threshold_percentage = 50;
@@ -580,6 +1075,7 @@ mod tests {
         let now = 2;
 
         proposal.created_at = 1;
+        proposal.activated_at = 1;
         proposal.parameters.voting_period = 3;
         proposal.parameters.approval_quorum_percentage = 60;
         proposal.parameters.slashing_quorum_percentage = 50;
@@ -605,6 +1101,7 @@ mod tests {
         let now = 2;
 
         proposal.created_at = 1;
+        proposal.activated_at = 1;
         proposal.parameters.voting_period = 3;
         proposal.parameters.approval_quorum_percentage = 50;
         proposal.parameters.approval_threshold_percentage = 30;
@@ -644,6 +1141,7 @@ mod tests {
         let now = 2;
 
         proposal.created_at = 1;
+        proposal.activated_at = 1;
         proposal.parameters.voting_period = 3;
         proposal.parameters.approval_quorum_percentage = 50;
         proposal.parameters.approval_threshold_percentage = 30;
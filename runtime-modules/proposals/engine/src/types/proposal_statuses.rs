@@ -16,6 +16,10 @@ pub enum ProposalStatus<BlockNumber, StakeId, AccountId> {
 
     /// The proposal decision was made.
     Finalized(FinalizationData<BlockNumber, StakeId, AccountId>),
+
+    /// The proposal was approved by the council and awaits the approval of the next council
+    /// (with optional stake data).
+    PendingConstitutionality(Option<ActiveStake<StakeId, AccountId>>),
 }
 
 impl<BlockNumber, StakeId, AccountId> Default for ProposalStatus<BlockNumber, StakeId, AccountId> {
@@ -25,6 +29,28 @@ impl<BlockNumber, StakeId, AccountId> Default for ProposalStatus<BlockNumber, St
 }
 
 impl<BlockNumber, StakeId, AccountId> ProposalStatus<BlockNumber, StakeId, AccountId> {
+    /// Returns whether the proposal was executed successfully.
+    pub fn is_executed(&self) -> bool {
+        if let ProposalStatus::Finalized(finalized_status) = self {
+            return finalized_status.proposal_status
+                == ProposalDecisionStatus::Approved(ApprovedProposalStatus::Executed);
+        }
+
+        false
+    }
+
+    /// Returns whether the proposal can still be executed: it is open for voting, awaits
+    /// the next council approval or awaits the execution.
+    pub fn is_execution_pending(&self) -> bool {
+        match self {
+            ProposalStatus::Active(_) | ProposalStatus::PendingConstitutionality(_) => true,
+            ProposalStatus::Finalized(finalized_status) => {
+                finalized_status.proposal_status
+                    == ProposalDecisionStatus::Approved(ApprovedProposalStatus::PendingExecution)
+            }
+        }
+    }
+
     /// Creates finalized proposal status with provided ProposalDecisionStatus
     pub fn finalized_successfully(
         decision_status: ProposalDecisionStatus,
@@ -138,6 +138,7 @@ parameter_types! {
 impl working_group::Trait<StorageWorkingGroupInstance> for Test {
     type Event = MetaEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type LeaderManagerOrigin = system::EnsureRoot<u64>;
 }
 
 impl pallet_timestamp::Trait for Test {
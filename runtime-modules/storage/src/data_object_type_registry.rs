@@ -1,10 +1,10 @@
 //! # Data object type registry module
-//! Data object type registry module for the Joystream platform allows to set constraints for the data objects. All extrinsics require leader.
+//! Data object type registry module for the Joystream platform allows to set constraints for the data objects. All extrinsics require leader or root.
 //!
 //! ## Comments
 //!
 //! Data object type registry module uses  working group module to authorize actions. Only leader can
-//! call extrinsics.
+//! call extrinsics. Root origin (council proposal) can override the leader.
 //!
 //! ## Supported extrinsics
 //!
@@ -23,12 +23,13 @@
 //#![warn(missing_docs)]
 
 use codec::{Codec, Decode, Encode};
-use frame_support::dispatch::DispatchError;
+use frame_support::dispatch::{DispatchError, DispatchResult};
 use frame_support::weights::Weight;
 use frame_support::{decl_error, decl_event, decl_module, decl_storage, Parameter};
 use sp_arithmetic::traits::BaseArithmetic;
 use sp_runtime::traits::{MaybeSerialize, Member};
 use sp_std::vec::Vec;
+use system::ensure_root;
 
 use crate::{StorageWorkingGroup, StorageWorkingGroupInstance};
 
@@ -135,10 +136,10 @@ decl_module! {
             10_000_000 //TODO: adjust weight
         }
 
-        /// Registers the new data object type. Requires leader privileges.
+        /// Registers the new data object type. Requires leader or root privileges.
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn register_data_object_type(origin, data_object_type: DataObjectType) {
-            <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
+            Self::ensure_origin_is_lead_or_root(origin)?;
 
             let new_do_type_id = Self::next_data_object_type_id();
             let do_type: DataObjectType = DataObjectType {
@@ -156,10 +157,10 @@ decl_module! {
             Self::deposit_event(RawEvent::DataObjectTypeRegistered(new_do_type_id));
         }
 
-        /// Updates existing data object type. Requires leader privileges.
+        /// Updates existing data object type. Requires leader or root privileges.
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn update_data_object_type(origin, id: T::DataObjectTypeId, data_object_type: DataObjectType) {
-            <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
+            Self::ensure_origin_is_lead_or_root(origin)?;
 
             let mut do_type = Self::ensure_data_object_type(id)?;
 
@@ -175,10 +176,10 @@ decl_module! {
             Self::deposit_event(RawEvent::DataObjectTypeUpdated(id));
         }
 
-        /// Activates existing data object type. Requires leader privileges.
+        /// Activates existing data object type. Requires leader or root privileges.
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn activate_data_object_type(origin, id: T::DataObjectTypeId) {
-            <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
+            Self::ensure_origin_is_lead_or_root(origin)?;
 
             let mut do_type = Self::ensure_data_object_type(id)?;
 
@@ -193,10 +194,10 @@ decl_module! {
             Self::deposit_event(RawEvent::DataObjectTypeUpdated(id));
         }
 
-        /// Deactivates existing data object type. Requires leader privileges.
+        /// Deactivates existing data object type. Requires leader or root privileges.
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn deactivate_data_object_type(origin, id: T::DataObjectTypeId) {
-            <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
+            Self::ensure_origin_is_lead_or_root(origin)?;
 
             let mut do_type = Self::ensure_data_object_type(id)?;
 
@@ -214,6 +215,15 @@ decl_module! {
 }
 
 impl<T: Trait> Module<T> {
+    // Ensures origin is the storage working group leader or root (council proposal).
+    fn ensure_origin_is_lead_or_root(origin: T::Origin) -> DispatchResult {
+        if ensure_root(origin.clone()).is_ok() {
+            return Ok(());
+        }
+
+        <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)
+    }
+
     fn ensure_data_object_type(id: T::DataObjectTypeId) -> Result<DataObjectType, DispatchError> {
         Self::data_object_types(&id).ok_or_else(|| Error::<T>::DataObjectTypeNotFound.into())
     }
@@ -274,6 +274,30 @@ fn register_data_object_type_failed_with_no_lead() {
     });
 }
 
+#[test]
+fn register_and_deactivate_data_object_type_succeeded_with_root() {
+    with_default_mock_builder(|| {
+        let data: TestDataObjectType = TestDataObjectType {
+            description: "foo".as_bytes().to_vec(),
+            active: true,
+        };
+        let data_object_type_id = TestDataObjectTypeRegistry::next_data_object_type_id();
+
+        let id_res =
+            TestDataObjectTypeRegistry::register_data_object_type(RawOrigin::Root.into(), data);
+        assert!(id_res.is_ok());
+
+        let res = TestDataObjectTypeRegistry::deactivate_data_object_type(
+            RawOrigin::Root.into(),
+            data_object_type_id,
+        );
+        assert!(res.is_ok());
+
+        let data = TestDataObjectTypeRegistry::data_object_types(data_object_type_id).unwrap();
+        assert!(!data.active);
+    });
+}
+
 #[test]
 fn activate_existing() {
     with_default_mock_builder(|| {
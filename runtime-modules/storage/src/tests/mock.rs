@@ -153,6 +153,7 @@ parameter_types! {
 impl working_group::Trait<StorageWorkingGroupInstance> for Test {
     type Event = MetaEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type LeaderManagerOrigin = system::EnsureRoot<u64>;
 }
 
 impl data_object_type_registry::Trait for Test {
@@ -54,7 +54,9 @@ mod errors;
 
 use frame_support::dispatch::{DispatchError, DispatchResult};
 use frame_support::storage::IterableStorageMap;
-use frame_support::traits::{Currency, ExistenceRequirement, Get, Imbalance, WithdrawReasons};
+use frame_support::traits::{
+    Currency, EnsureOrigin, ExistenceRequirement, Get, Imbalance, WithdrawReasons,
+};
 use frame_support::{decl_event, decl_module, decl_storage, ensure, print, StorageValue};
 use sp_arithmetic::traits::{Bounded, One, Zero};
 use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
@@ -161,6 +163,9 @@ pub trait Trait<I: Instance>:
 
     /// Defines max workers number in the working group.
     type MaxWorkerNumberLimit: Get<u32>;
+
+    /// Origin allowed to manage the leader: hire, stake and terminate.
+    type LeaderManagerOrigin: EnsureOrigin<Self::Origin>;
 }
 
 decl_event!(
@@ -328,7 +333,9 @@ decl_storage! {
     }
         add_extra_genesis {
         config(phantom): sp_std::marker::PhantomData<I>;
-        config(storage_working_group_mint_capacity): minting::BalanceOf<T>;
+        // Keeps the chain specs with the former storage working group field name valid.
+        #[serde(alias = "storageWorkingGroupMintCapacity")]
+        config(working_group_mint_capacity): minting::BalanceOf<T>;
         config(opening_human_readable_text_constraint): InputValidationLengthConstraint;
         config(worker_application_human_readable_text_constraint): InputValidationLengthConstraint;
         config(worker_exit_rationale_text_constraint): InputValidationLengthConstraint;
@@ -337,7 +344,7 @@ decl_storage! {
                 config.opening_human_readable_text_constraint,
                 config.worker_application_human_readable_text_constraint,
                 config.worker_exit_rationale_text_constraint,
-                config.storage_working_group_mint_capacity)
+                config.working_group_mint_capacity)
         });
     }
 }
@@ -1114,8 +1121,10 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
                 Self::ensure_origin_is_active_leader(origin)
             }
             OpeningType::Leader => {
-                // Council proposal.
-                ensure_root(origin).map_err(|err| err.into())
+                // Council proposal or motion.
+                T::LeaderManagerOrigin::ensure_origin(origin)
+                    .map(|_| ())
+                    .map_err(|err| err.into())
             }
         }
     }
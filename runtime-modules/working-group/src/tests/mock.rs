@@ -138,6 +138,7 @@ parameter_types! {
 impl Trait<TestWorkingGroupInstance> for Test {
     type Event = TestEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type LeaderManagerOrigin = system::EnsureRoot<u64>;
 }
 
 pub type Membership = membership::Module<Test>;
@@ -156,7 +157,7 @@ pub fn build_test_externalities() -> sp_io::TestExternalities {
 
     crate::GenesisConfig::<Test, TestWorkingGroupInstance> {
         phantom: Default::default(),
-        storage_working_group_mint_capacity: WORKING_GROUP_MINT_CAPACITY,
+        working_group_mint_capacity: WORKING_GROUP_MINT_CAPACITY,
         opening_human_readable_text_constraint: InputValidationLengthConstraint::new(
             WORKING_GROUP_CONSTRAINT_MIN,
             WORKING_GROUP_CONSTRAINT_DIFF,
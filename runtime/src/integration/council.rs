@@ -0,0 +1,57 @@
+use frame_support::traits::{Filter, Get};
+use proposals_engine::ProposalVoteObserver;
+
+use crate::{AccountId, Call, Council, CouncilElection, ProposalsEngine};
+
+/// Whitelist of the calls allowed in the council motions: urgent operational actions only.
+/// Minting and spending changes are left to the proposal system and its spending limits.
+pub struct MotionCallFilter;
+
+impl Filter<Call> for MotionCallFilter {
+    fn filter(call: &Call) -> bool {
+        match call {
+            // Stops a harmful proposal before its voting period or execution delay ends.
+            Call::ProposalsEngine(proposals_engine::Call::veto_proposal(..)) => true,
+            // Stops the payouts of a grant that is no longer justified. Doesn't spend the mint.
+            Call::Council(governance::council::Call::cancel_recurring_grant(..)) => true,
+            // Adjusts the councilors rewards floor from the next term, it doesn't mint by itself.
+            Call::Council(governance::council::Call::set_attendance_reward_floor(..)) => true,
+            // Removes a misbehaving working group leader without waiting for the proposal.
+            Call::StorageWorkingGroup(working_group::Call::terminate_role(..)) => true,
+            Call::ContentDirectoryWorkingGroup(working_group::Call::terminate_role(..)) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Provides the council size from the current election parameters.
+pub struct CouncilSizeProvider;
+
+impl Get<u32> for CouncilSizeProvider {
+    fn get() -> u32 {
+        CouncilElection::council_size()
+    }
+}
+
+/// Provides the id of the proposal being executed for the council spends ledger.
+pub struct ExecutingProposalIdProvider;
+
+impl Get<Option<u32>> for ExecutingProposalIdProvider {
+    fn get() -> Option<u32> {
+        ProposalsEngine::executing_proposal_id()
+    }
+}
+
+/// Tracks the councilors attendance in the proposals voting: every proposal opened for the voting
+/// of the council counts towards the attendance.
+pub struct CouncilAttendanceTracker;
+
+impl ProposalVoteObserver<AccountId, u32> for CouncilAttendanceTracker {
+    fn proposal_voting_opened(_proposal_id: u32) {
+        Council::note_proposal_opened();
+    }
+
+    fn proposal_voted(voter: &AccountId, _proposal_id: u32) {
+        Council::note_proposal_vote(voter);
+    }
+}
@@ -0,0 +1,45 @@
+use sp_runtime::traits::IdentifyAccount;
+use sp_runtime::RuntimeAppPublic;
+use sp_std::vec::Vec;
+use storage::data_directory::ContentIdExists;
+use system::offchain::{SendSignedTransaction, Signer, SigningTypes};
+
+use crate::primitives::election_reveal::{RevealerAppCrypto, RevealerId};
+use crate::{AccountId, ContentId, DataDirectory, Hash, Runtime};
+
+/// Submits the scheduled council election reveals with the voter keys from the local keystore.
+pub struct OffchainRevealSubmitter;
+
+impl governance::election::RevealSubmitter<AccountId, Hash> for OffchainRevealSubmitter {
+    fn submit_reveal(voter: AccountId, commitment: Hash, vote: AccountId, salt: Vec<u8>) -> bool {
+        // find the voter key in the local keystore
+        let voter_keys: Vec<<Runtime as SigningTypes>::Public> = RevealerId::all()
+            .into_iter()
+            .map(|key| sp_core::sr25519::Public::from(key).into())
+            .filter(|public: &<Runtime as SigningTypes>::Public| {
+                public.clone().into_account() == voter
+            })
+            .collect();
+
+        if voter_keys.is_empty() {
+            return false;
+        }
+
+        let results = Signer::<Runtime, RevealerAppCrypto>::all_accounts()
+            .with_filter(voter_keys)
+            .send_signed_transaction(|_| {
+                governance::election::Call::reveal(commitment, vote.clone(), salt.clone())
+            });
+
+        results.iter().any(|(_, result)| result.is_ok())
+    }
+}
+
+/// Verifies the council applicant manifestos uploaded to the data directory.
+pub struct ManifestoContentValidator;
+
+impl governance::election::ManifestoContentValidator<ContentId> for ManifestoContentValidator {
+    fn has_content(content_id: &ContentId) -> bool {
+        <DataDirectory as ContentIdExists<Runtime>>::has_content(content_id)
+    }
+}
@@ -1,7 +1,11 @@
+#[macro_use]
+pub mod working_group;
+
 pub mod content_working_group;
+pub mod council;
+pub mod election;
 pub mod forum;
 pub mod proposals;
 pub mod storage;
 pub mod transactions;
 pub mod versioned_store_permissions;
-pub mod working_group;
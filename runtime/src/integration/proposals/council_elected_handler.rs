@@ -4,11 +4,13 @@ use crate::Runtime;
 use governance::election::CouncilElected;
 
 /// 'Council elected' event handler. Should be applied to the 'election' substrate module.
-/// CouncilEvent is handled by resetting active proposals.
+/// CouncilEvent is handled by resetting active proposals and re-opening the voting for
+/// the proposals pending constitutionality.
 pub struct CouncilElectedHandler;
 
 impl<Elected, Term> CouncilElected<Elected, Term> for CouncilElectedHandler {
     fn council_elected(_new_council: Elected, _term: Term) {
         <proposals_engine::Module<Runtime>>::reset_active_proposals();
+        <proposals_engine::Module<Runtime>>::reactivate_pending_constitutionality_proposals();
     }
 }
@@ -1,14 +1,15 @@
 #![warn(missing_docs)]
 
+use sp_runtime::SaturatedConversion;
 use sp_std::marker::PhantomData;
 
 use common::origin::ActorOriginValidator;
-use proposals_engine::VotersParameters;
+use proposals_engine::{VotersParameters, VotingWeightProvider};
 
 use super::{MemberId, MembershipOriginValidator};
 
 /// Handles work with the council.
-/// Provides implementations for ActorOriginValidator and VotersParameters.
+/// Provides implementations for ActorOriginValidator, VotersParameters and VotingWeightProvider.
 pub struct CouncilManager<T> {
     marker: PhantomData<T>,
 }
@@ -40,6 +41,27 @@ impl<T: governance::council::Trait> VotersParameters for CouncilManager<T> {
     }
 }
 
+impl<T: governance::council::Trait> VotingWeightProvider<<T as system::Trait>::AccountId>
+    for CouncilManager<T>
+{
+    /// Implement voting_weight() as the councilor seat stake with the backing stake
+    fn voting_weight(voter: &<T as system::Trait>::AccountId) -> u64 {
+        <governance::council::Module<T>>::active_council()
+            .iter()
+            .find(|seat| seat.member == *voter)
+            .map_or(0, |seat| seat.calc_total_stake().saturated_into())
+    }
+
+    /// Implement total_voting_weight() as the total stake of the council
+    fn total_voting_weight() -> u64 {
+        <governance::council::Module<T>>::active_council()
+            .iter()
+            .fold(0u64, |total, seat| {
+                total.saturating_add(seat.calc_total_stake().saturated_into())
+            })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::CouncilManager;
@@ -1,5 +1,4 @@
-use crate::{Call, Runtime};
-use common::working_group::WorkingGroup;
+use crate::{data_object_type_registry, Call, Runtime};
 use proposals_codex::{ProposalDetails, ProposalDetailsOf, ProposalEncoder};
 use working_group::OpeningType;
 
@@ -10,16 +9,18 @@ use sp_std::marker::PhantomData;
 use sp_std::vec::Vec;
 
 // The macro binds working group outer-level Call with the provided inner-level working group
-// extrinsic call. Outer-call is defined by the provided WorkingGroup param expression.
-
+// extrinsic call. Outer-call is defined by the working group instance registered for the provided
+// WorkingGroup param expression.
 //Params:
 // - $working_group: expression returning the 'common::working_group::WorkingGroup' enum
 // - $working_group_instance_call: expression returning the exact working group instance extrinsic call
 macro_rules! wrap_working_group_call {
     ($working_group:expr, $working_group_instance_call:expr) => {{
-        match $working_group {
-            WorkingGroup::Storage => Call::StorageWorkingGroup($working_group_instance_call),
-        }
+        with_working_group_instance!($working_group, I, {
+            let working_group_call: working_group::Call<Runtime, I> = $working_group_instance_call;
+
+            Call::from(working_group_call)
+        })
     }};
 }
 
@@ -32,9 +33,14 @@ impl ProposalEncoder<Runtime> for ExtrinsicProposalEncoder {
             ProposalDetails::Text(text) => {
                 Call::ProposalsCodex(proposals_codex::Call::execute_text_proposal(text))
             }
-            ProposalDetails::SetElectionParameters(election_parameters) => Call::CouncilElection(
-                governance::election::Call::set_election_parameters(election_parameters),
-            ),
+            ProposalDetails::SetElectionParameters(election_parameters, apply_at_next_election) => {
+                Call::ProposalsCodex(
+                    proposals_codex::Call::execute_set_election_parameters_proposal(
+                        election_parameters,
+                        apply_at_next_election,
+                    ),
+                )
+            }
             ProposalDetails::SetContentWorkingGroupMintCapacity(mint_balance) => {
                 Call::ContentWorkingGroup(content_working_group::Call::set_mint_capacity(
                     mint_balance,
@@ -43,14 +49,96 @@ impl ProposalEncoder<Runtime> for ExtrinsicProposalEncoder {
             ProposalDetails::Spending(balance, destination) => Call::Council(
                 governance::council::Call::spend_from_council_mint(balance, destination),
             ),
+            ProposalDetails::RecurringGrant(recurring_grant_parameters) => {
+                Call::Council(governance::council::Call::create_recurring_grant(
+                    recurring_grant_parameters.destination,
+                    recurring_grant_parameters.amount_per_payout,
+                    recurring_grant_parameters.payout_interval,
+                    recurring_grant_parameters.ends_at,
+                ))
+            }
+            ProposalDetails::CancelRecurringGrant(grant_id) => {
+                Call::Council(governance::council::Call::cancel_recurring_grant(grant_id))
+            }
+            ProposalDetails::SetNewMembershipsAllowed(allowed) => {
+                Call::Members(membership::Call::set_new_memberships_allowed(allowed))
+            }
+            ProposalDetails::SetMembershipUserInfoConstraints(user_info_constraints) => {
+                Call::Members(membership::Call::set_user_info_constraints(
+                    user_info_constraints.min_handle_length,
+                    user_info_constraints.max_handle_length,
+                    user_info_constraints.max_avatar_uri_length,
+                    user_info_constraints.max_about_text_length,
+                ))
+            }
+            ProposalDetails::SetMembershipScreeningAuthority(screening_authority) => Call::Members(
+                membership::Call::set_screening_authority(screening_authority),
+            ),
+            ProposalDetails::SetForumSudo(forum_sudo) => {
+                Call::Forum(forum::Call::set_forum_sudo(forum_sudo))
+            }
+            ProposalDetails::SetForumInputValidationConstraints(constraints) => {
+                Call::Forum(forum::Call::set_input_validation_constraints(
+                    constraints.category_title,
+                    constraints.category_description,
+                    constraints.thread_title,
+                    constraints.post_text,
+                    constraints.thread_moderation_rationale,
+                    constraints.post_moderation_rationale,
+                ))
+            }
+            ProposalDetails::SetMaxMemoLength(max_memo_length) => {
+                Call::Memo(memo::Call::set_max_memo_length(max_memo_length))
+            }
+            ProposalDetails::SetServiceDiscoveryDefaultLifetime(default_lifetime) => {
+                Call::Discovery(service_discovery::Call::set_default_lifetime(
+                    default_lifetime,
+                ))
+            }
+            ProposalDetails::RegisterDataObjectType(data_object_type) => {
+                Call::DataObjectTypeRegistry(
+                    data_object_type_registry::Call::register_data_object_type(
+                        data_object_type_registry::DataObjectType {
+                            description: data_object_type.description,
+                            active: data_object_type.active,
+                        },
+                    ),
+                )
+            }
+            ProposalDetails::UpdateDataObjectType(data_object_type_id, data_object_type) => {
+                Call::DataObjectTypeRegistry(
+                    data_object_type_registry::Call::update_data_object_type(
+                        data_object_type_id,
+                        data_object_type_registry::DataObjectType {
+                            description: data_object_type.description,
+                            active: data_object_type.active,
+                        },
+                    ),
+                )
+            }
+            ProposalDetails::ActivateDataObjectType(data_object_type_id) => {
+                Call::DataObjectTypeRegistry(
+                    data_object_type_registry::Call::activate_data_object_type(data_object_type_id),
+                )
+            }
+            ProposalDetails::DeactivateDataObjectType(data_object_type_id) => {
+                Call::DataObjectTypeRegistry(
+                    data_object_type_registry::Call::deactivate_data_object_type(
+                        data_object_type_id,
+                    ),
+                )
+            }
+            ProposalDetails::SetServiceDiscoveryBootstrapEndpoints(endpoints) => {
+                Call::Discovery(service_discovery::Call::set_bootstrap_endpoints(endpoints))
+            }
             ProposalDetails::SetLead(new_lead) => {
                 Call::ContentWorkingGroup(content_working_group::Call::replace_lead(new_lead))
             }
             ProposalDetails::SetValidatorCount(new_validator_count) => Call::Staking(
                 pallet_staking::Call::set_validator_count(new_validator_count),
             ),
-            ProposalDetails::RuntimeUpgrade(wasm_code) => Call::ProposalsCodex(
-                proposals_codex::Call::execute_runtime_upgrade_proposal(wasm_code),
+            ProposalDetails::RuntimeUpgrade(wasm_hash) => Call::ProposalsCodex(
+                proposals_codex::Call::execute_runtime_upgrade_proposal(wasm_hash),
             ),
             // ********** Deprecated during the Nicaea release.
             // It is kept only for backward compatibility in the Pioneer. **********
@@ -119,6 +207,22 @@ impl ProposalEncoder<Runtime> for ExtrinsicProposalEncoder {
                     Wg::terminate_role_call(terminate_role_params)
                 )
             }
+            ProposalDetails::UpdateProposalParameters(proposal_type, proposal_parameters) => {
+                Call::ProposalsCodex(
+                    proposals_codex::Call::execute_update_proposal_parameters_proposal(
+                        proposal_type,
+                        proposal_parameters,
+                    ),
+                )
+            }
+            // The batched calls are dispatched atomically by the proposals engine.
+            ProposalDetails::Batch(batched_proposal_details) => {
+                return batched_proposal_details
+                    .into_iter()
+                    .map(Self::encode_proposal)
+                    .collect::<Vec<Vec<u8>>>()
+                    .encode();
+            }
         };
 
         call.encode()
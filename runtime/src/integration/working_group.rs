@@ -1,49 +1,115 @@
 use frame_support::StorageMap;
-use sp_std::marker::PhantomData;
 
-use crate::StorageWorkingGroupInstance;
+use crate::Runtime;
 use stake::{BalanceOf, NegativeImbalance};
 
-pub struct StakingEventsHandler<T> {
-    pub marker: PhantomData<T>,
+// Registered working groups: binds the 'common::working_group::WorkingGroup' values to the runtime
+// working group instances. A new working group requires only its instance registration here along
+// with the instance trait implementation and the 'construct_runtime!' declaration.
+// Params:
+// - $callback: macro invoked with the provided arguments and the registered working groups
+// - $args: arguments passed to the callback macro
+macro_rules! registered_working_groups {
+    ($callback:ident!($($args:tt)*)) => {
+        $callback!(($($args)*) {
+            Storage => crate::StorageWorkingGroupInstance,
+            Content => crate::ContentDirectoryWorkingGroupInstance,
+        })
+    };
 }
 
-impl<T: stake::Trait + working_group::Trait<StorageWorkingGroupInstance>>
-    stake::StakingEventsHandler<T> for StakingEventsHandler<T>
-{
+// The macro evaluates the expression with the type alias bound to the working group instance
+// registered for the provided working group.
+// Params:
+// - $working_group: expression returning the 'common::working_group::WorkingGroup' enum
+// - $instance: type alias of the working group instance available in the expression
+// - $expression: expression evaluated for the working group instance
+macro_rules! with_working_group_instance {
+    ($working_group:expr, $instance:ident, $expression:expr) => {
+        registered_working_groups!(with_working_group_instance_impl!(
+            $working_group,
+            $instance,
+            $expression
+        ))
+    };
+}
+
+macro_rules! with_working_group_instance_impl {
+    (($working_group:expr, $instance:ident, $expression:expr) {
+        $($group:ident => $group_instance:ty,)*
+    }) => {{
+        match $working_group {
+            $(common::working_group::WorkingGroup::$group => {
+                type $instance = $group_instance;
+                $expression
+            })*
+        }
+    }};
+}
+
+// The macro evaluates the expression for every registered working group instance.
+// Params:
+// - $instance: type alias of the working group instance available in the expression
+// - $expression: expression evaluated for each working group instance
+macro_rules! for_each_working_group_instance {
+    ($instance:ident, $expression:expr) => {
+        registered_working_groups!(for_each_working_group_instance_impl!(
+            $instance,
+            $expression
+        ))
+    };
+}
+
+macro_rules! for_each_working_group_instance_impl {
+    (($instance:ident, $expression:expr) {
+        $($group:ident => $group_instance:ty,)*
+    }) => {{
+        $({
+            type $instance = $group_instance;
+            $expression
+        })*
+    }};
+}
+
+/// Refunds the working group stakes of the registered working group instances.
+pub struct StakingEventsHandler;
+
+impl stake::StakingEventsHandler<Runtime> for StakingEventsHandler {
     /// Unstake remaining sum back to the source_account_id
     fn unstaked(
-        stake_id: &<T as stake::Trait>::StakeId,
-        _unstaked_amount: BalanceOf<T>,
-        remaining_imbalance: NegativeImbalance<T>,
-    ) -> NegativeImbalance<T> {
+        stake_id: &<Runtime as stake::Trait>::StakeId,
+        _unstaked_amount: BalanceOf<Runtime>,
+        remaining_imbalance: NegativeImbalance<Runtime>,
+    ) -> NegativeImbalance<Runtime> {
         // Stake not related to a staked role managed by the hiring module.
-        if !hiring::ApplicationIdByStakingId::<T>::contains_key(*stake_id) {
+        if !hiring::ApplicationIdByStakingId::<Runtime>::contains_key(*stake_id) {
             return remaining_imbalance;
         }
 
-        let hiring_application_id = hiring::ApplicationIdByStakingId::<T>::get(*stake_id);
+        let hiring_application_id = hiring::ApplicationIdByStakingId::<Runtime>::get(*stake_id);
 
-        if working_group::MemberIdByHiringApplicationId::<T, StorageWorkingGroupInstance>::contains_key(
-            hiring_application_id,
-        ) {
-            return <working_group::Module<T, StorageWorkingGroupInstance>>::refund_working_group_stake(
-				*stake_id,
-				remaining_imbalance,
-			);
-        }
+        for_each_working_group_instance!(I, {
+            if working_group::MemberIdByHiringApplicationId::<Runtime, I>::contains_key(
+                hiring_application_id,
+            ) {
+                return <working_group::Module<Runtime, I>>::refund_working_group_stake(
+                    *stake_id,
+                    remaining_imbalance,
+                );
+            }
+        });
 
         remaining_imbalance
     }
 
     /// Empty handler for the slashing.
     fn slashed(
-        _: &<T as stake::Trait>::StakeId,
-        _: Option<<T as stake::Trait>::SlashId>,
-        _: BalanceOf<T>,
-        _: BalanceOf<T>,
-        remaining_imbalance: NegativeImbalance<T>,
-    ) -> NegativeImbalance<T> {
+        _: &<Runtime as stake::Trait>::StakeId,
+        _: Option<<Runtime as stake::Trait>::SlashId>,
+        _: BalanceOf<Runtime>,
+        _: BalanceOf<Runtime>,
+        remaining_imbalance: NegativeImbalance<Runtime>,
+    ) -> NegativeImbalance<Runtime> {
         remaining_imbalance
     }
 }
@@ -15,6 +15,7 @@ include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
 mod constants;
 mod integration;
+mod migration;
 pub mod primitives;
 mod runtime_api;
 #[cfg(test)]
@@ -54,12 +55,21 @@ use storage::data_object_storage_registry;
 pub use common;
 pub use content_working_group as content_wg;
 pub use forum;
+pub use governance::council::{
+    RecurringGrant, RecurringGrantId, SpendRecord, SpendingCategory, TermIndex,
+};
+pub use governance::election::{ElectionResult, ElectionStage, Manifesto};
 pub use governance::election_params::ElectionParameters;
 pub use membership;
 #[cfg(any(feature = "std", test))]
 pub use pallet_balances::Call as BalancesCall;
 pub use pallet_staking::StakerStatus;
-pub use proposals_codex::ProposalsConfigParameters;
+pub use proposals_codex::{
+    default_proposal_parameters, proposal_parameters_with_grace_and_voting_periods,
+};
+pub use proposals_engine::{
+    ArchivedProposal, Proposal, ProposalParameters, ProposalType, ProposalVotingStatus, VoteKind,
+};
 pub use storage::{data_directory, data_object_type_registry};
 pub use versioned_store;
 pub use versioned_store_permissions;
@@ -70,10 +80,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: create_runtime_str!("joystream-node"),
     impl_name: create_runtime_str!("joystream-node"),
     authoring_version: 7,
-    spec_version: 5,
+    spec_version: 6,
     impl_version: 1,
     apis: crate::runtime_api::EXPORTED_RUNTIME_API_VERSIONS,
-    transaction_version: 1,
+    transaction_version: 2,
 };
 
 /// The version information used to identify this runtime when compiled natively.
@@ -393,7 +403,7 @@ impl minting::Trait for Runtime {
 }
 
 impl recurring_rewards::Trait for Runtime {
-    type PayoutStatusHandler = (); // TODO - deal with successful and failed payouts
+    type PayoutStatusHandler = Council;
     type RecipientId = u64;
     type RewardRelationshipId = u64;
 }
@@ -409,7 +419,7 @@ impl stake::Trait for Runtime {
         crate::integration::content_working_group::ContentWorkingGroupStakingEventHandler,
         (
             crate::integration::proposals::StakingEventsHandler<Self>,
-            crate::integration::working_group::StakingEventsHandler<Self>,
+            crate::integration::working_group::StakingEventsHandler,
         ),
     );
     type StakeId = u64;
@@ -427,11 +437,29 @@ impl common::currency::GovernanceCurrency for Runtime {
 impl governance::election::Trait for Runtime {
     type Event = Event;
     type CouncilElected = (Council, integration::proposals::CouncilElectedHandler);
+    type RevealSubmitter = integration::election::OffchainRevealSubmitter;
+    type ManifestoContentId = ContentId;
+    type ManifestoContentValidator = integration::election::ManifestoContentValidator;
+}
+
+parameter_types! {
+    pub const MotionApprovalThresholdPercentage: u32 = 66;
+    pub const MaxMotionCallLength: u32 = 1024;
+    pub const MotionExpiryPeriod: BlockNumber = 3 * DAYS;
 }
 
 impl governance::council::Trait for Runtime {
     type Event = Event;
     type CouncilTermEnded = (CouncilElection,);
+    type MotionOrigin = Origin;
+    type MotionCall = Call;
+    type MotionCallFilter = integration::council::MotionCallFilter;
+    type CouncilOrigin = council::EnsureRootOrCouncilMotion<AccountId>;
+    type MaxMotionCallLength = MaxMotionCallLength;
+    type MotionExpiryPeriod = MotionExpiryPeriod;
+    type CouncilSize = integration::council::CouncilSizeProvider;
+    type MotionApprovalThresholdPercentage = MotionApprovalThresholdPercentage;
+    type ExecutingProposalId = integration::council::ExecutingProposalIdProvider;
 }
 
 impl memo::Trait for Runtime {
@@ -464,7 +492,7 @@ impl storage::data_object_storage_registry::Trait for Runtime {
 
 impl membership::Trait for Runtime {
     type Event = Event;
-    type MemberId = u64;
+    type MemberId = MemberId;
     type PaidTermId = u64;
     type SubscriptionId = u64;
     type ActorId = ActorId;
@@ -480,6 +508,9 @@ impl forum::Trait for Runtime {
 // The storage working group instance alias.
 pub type StorageWorkingGroupInstance = working_group::Instance2;
 
+// The content directory working group instance alias.
+pub type ContentDirectoryWorkingGroupInstance = working_group::Instance3;
+
 parameter_types! {
     pub const MaxWorkerNumberLimit: u32 = 100;
 }
@@ -487,6 +518,13 @@ parameter_types! {
 impl working_group::Trait<StorageWorkingGroupInstance> for Runtime {
     type Event = Event;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type LeaderManagerOrigin = council::EnsureRootOrCouncilMotion<AccountId>;
+}
+
+impl working_group::Trait<ContentDirectoryWorkingGroupInstance> for Runtime {
+    type Event = Event;
+    type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type LeaderManagerOrigin = council::EnsureRootOrCouncilMotion<AccountId>;
 }
 
 impl service_discovery::Trait for Runtime {
@@ -498,22 +536,36 @@ parameter_types! {
     pub const ProposalRejectionFee: u64 = 5000;
     pub const ProposalTitleMaxLength: u32 = 40;
     pub const ProposalDescriptionMaxLength: u32 = 3000;
+    pub const ProposalRationaleMaxLength: u32 = 1000;
     pub const ProposalMaxActiveProposalLimit: u32 = 5;
+    pub const ProposalMaxProposalDependencies: u32 = 10;
+    pub const ProposalAmendmentPeriod: BlockNumber = HOURS;
+    pub const ProposalMaxProposalAmendments: u32 = 5;
+    pub const ProposalRetentionPeriod: BlockNumber = 30 * DAYS;
 }
 
 impl proposals_engine::Trait for Runtime {
     type Event = Event;
     type ProposerOriginValidator = MembershipOriginValidator<Self>;
     type VoterOriginValidator = CouncilManager<Self>;
+    type VotingWeightProvider = CouncilManager<Self>;
     type TotalVotersCounter = CouncilManager<Self>;
-    type ProposalId = u32;
+    type VoteObserver = integration::council::CouncilAttendanceTracker;
+    type ProposalObserver = ProposalCodex;
+    type ProposalId = ProposalId;
     type StakeHandlerProvider = proposals_engine::DefaultStakeHandlerProvider;
     type CancellationFee = ProposalCancellationFee;
     type RejectionFee = ProposalRejectionFee;
     type TitleMaxLength = ProposalTitleMaxLength;
     type DescriptionMaxLength = ProposalDescriptionMaxLength;
+    type RationaleMaxLength = ProposalRationaleMaxLength;
     type MaxActiveProposalLimit = ProposalMaxActiveProposalLimit;
+    type MaxProposalDependencies = ProposalMaxProposalDependencies;
+    type AmendmentPeriod = ProposalAmendmentPeriod;
+    type MaxProposalAmendments = ProposalMaxProposalAmendments;
+    type ProposalRetentionPeriod = ProposalRetentionPeriod;
     type DispatchableCallCode = Call;
+    type VetoOrigin = council::EnsureRootOrCouncilMotion<AccountId>;
 }
 impl Default for Call {
     fn default() -> Self {
@@ -531,6 +583,7 @@ parameter_types! {
 impl proposals_discussion::Trait for Runtime {
     type Event = Event;
     type PostAuthorOriginValidator = MembershipOriginValidator<Self>;
+    type CouncilOriginValidator = CouncilManager<Self>;
     type ThreadId = ThreadId;
     type PostId = PostId;
     type MaxPostEditionNumber = ProposalMaxPostEditionNumber;
@@ -542,15 +595,22 @@ impl proposals_discussion::Trait for Runtime {
 parameter_types! {
     pub const TextProposalMaxLength: u32 = 5_000;
     pub const RuntimeUpgradeWasmProposalMaxLength: u32 = 3_000_000;
+    pub const RuntimeUpgradePreimageByteDeposit: Balance = 1; // TODO: adjust deposit
+    pub const SignalProposalDeposit: Balance = 1_000; // TODO: adjust deposit
 }
 
 impl proposals_codex::Trait for Runtime {
+    type Event = Event;
     type MembershipOriginValidator = MembershipOriginValidator<Self>;
     type TextProposalMaxLength = TextProposalMaxLength;
     type RuntimeUpgradeWasmProposalMaxLength = RuntimeUpgradeWasmProposalMaxLength;
+    type RuntimeUpgradePreimageByteDeposit = RuntimeUpgradePreimageByteDeposit;
+    type SignalProposalDeposit = SignalProposalDeposit;
     type ProposalEncoder = ExtrinsicProposalEncoder;
 }
 
+impl migration::Trait for Runtime {}
+
 parameter_types! {
     pub const TombstoneDeposit: Balance = 1; // TODO: adjust fee
     pub const RentByteFee: Balance = 1; // TODO: adjust fee
@@ -601,7 +661,7 @@ construct_runtime!(
         Sudo: pallet_sudo::{Module, Call, Config<T>, Storage, Event<T>},
         // Joystream
         CouncilElection: election::{Module, Call, Storage, Event<T>, Config<T>},
-        Council: council::{Module, Call, Storage, Event<T>, Config<T>},
+        Council: council::{Module, Call, Storage, Event<T>, Config<T>, Origin},
         Memo: memo::{Module, Call, Storage, Event<T>},
         Members: membership::{Module, Call, Storage, Event<T>, Config<T>},
         Forum: forum::{Module, Call, Storage, Event<T>, Config<T>},
@@ -620,9 +680,14 @@ construct_runtime!(
         // --- Proposals
         ProposalsEngine: proposals_engine::{Module, Call, Storage, Event<T>},
         ProposalsDiscussion: proposals_discussion::{Module, Call, Storage, Event<T>},
-        ProposalsCodex: proposals_codex::{Module, Call, Storage, Config<T>},
+        ProposalsCodex: proposals_codex::{Module, Call, Storage, Event<T>, Config<T>},
         // --- Working groups
         // reserved for the future use: ForumWorkingGroup: working_group::<Instance1>::{Module, Call, Storage, Event<T>},
         StorageWorkingGroup: working_group::<Instance2>::{Module, Call, Storage, Config<T>, Event<T>},
+        // The modules below are appended to keep the indices of the existing modules.
+        // --- Runtime upgrade
+        Migration: migration::{Module, Storage, Config},
+        // --- Working groups
+        ContentDirectoryWorkingGroup: working_group::<Instance3>::{Module, Call, Storage, Config<T>, Event<T>},
     }
 );
@@ -0,0 +1,51 @@
+//! Runtime storage migrations. Migrations are executed once on the runtime upgrade to the new
+//! spec version.
+
+use frame_support::weights::Weight;
+use frame_support::{decl_module, decl_storage};
+
+use crate::VERSION;
+
+/// Migration module trait. Includes the modules whose storage gets migrated.
+pub trait Trait:
+    system::Trait + proposals_engine::Trait + proposals_discussion::Trait + proposals_codex::Trait
+{
+}
+
+decl_storage! {
+    trait Store for Module<T: Trait> as Migration {
+        /// Spec version of the runtime the storage was migrated to. Initialized to the spec
+        /// version of the genesis runtime: the new chains skip the migrations.
+        pub SpecVersion get(fn spec_version) build(|_config: &GenesisConfig| {
+            Some(VERSION.spec_version)
+        }): Option<u32>;
+    }
+}
+
+decl_module! {
+    pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+        fn on_runtime_upgrade() -> Weight {
+            let migrated = Self::spec_version()
+                .map_or(false, |spec_version| spec_version >= VERSION.spec_version);
+
+            if !migrated {
+                Self::runtime_upgraded();
+
+                SpecVersion::put(VERSION.spec_version);
+            }
+
+            10_000_000 // TODO: adjust weight
+        }
+    }
+}
+
+impl<T: Trait> Module<T> {
+    // Migrates the storage of the proposals, their details, discussions and the proposal parameters
+    // to the current layout. Should be replaced with the migrations of the next runtime upgrade.
+    fn runtime_upgraded() {
+        <proposals_engine::Module<T>>::migrate_proposals();
+        <proposals_codex::Module<T>>::migrate_proposal_details();
+        <proposals_discussion::Module<T>>::migrate_posts();
+        <proposals_codex::Module<T>>::migrate_proposal_parameters();
+    }
+}
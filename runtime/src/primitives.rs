@@ -63,6 +63,12 @@ pub type PostId = u64;
 /// Represent an actor in membership group, which is the same in the working groups.
 pub type ActorId = u64;
 
+/// Represents a member identifier.
+pub type MemberId = u64;
+
+/// Represents a proposal identifier.
+pub type ProposalId = u32;
+
 /// App-specific crypto used for reporting equivocation/misbehavior in BABE and
 /// GRANDPA. Any rewards for misbehavior reporting will be paid out to this
 /// account.
@@ -93,3 +99,32 @@ pub mod report {
         type GenericPublic = sp_core::sr25519::Public;
     }
 }
+
+/// App-specific crypto used for submitting the scheduled council election reveals. The voter
+/// account key should be inserted into the node keystore with this key type.
+pub mod election_reveal {
+    use super::{Signature, Verify};
+    use sp_core::crypto::KeyTypeId;
+    use system::offchain::AppCrypto;
+
+    /// Key type for the automatic reveals of the council election votes.
+    pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"jrvl");
+
+    mod app {
+        use sp_application_crypto::{app_crypto, sr25519};
+        app_crypto!(sr25519, super::KEY_TYPE);
+    }
+
+    /// Identity of the voter submitting the scheduled reveal.
+    pub type RevealerId = app::Public;
+
+    /// An `AppCrypto` type to allow submitting signed reveal transactions using the voter
+    /// application key as signer.
+    pub struct RevealerAppCrypto;
+
+    impl AppCrypto<<Signature as Verify>::Signer, Signature> for RevealerAppCrypto {
+        type RuntimeAppPublic = RevealerId;
+        type GenericSignature = sp_core::sr25519::Signature;
+        type GenericPublic = sp_core::sr25519::Public;
+    }
+}
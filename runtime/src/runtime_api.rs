@@ -12,13 +12,18 @@ use sp_std::vec::Vec;
 
 use crate::constants::PRIMARY_PROBABILITY;
 use crate::{
-    AccountId, AuthorityDiscoveryId, Balance, BlockNumber, EpochDuration, GrandpaAuthorityList,
-    GrandpaId, Hash, Index, RuntimeVersion, Signature, VERSION,
+    AccountId, AuthorityDiscoveryId, Balance, BlockNumber, ContentId, EpochDuration,
+    GrandpaAuthorityList, GrandpaId, Hash, Index, MemberId, ProposalId, RuntimeVersion, Signature,
+    VERSION,
 };
 use crate::{
-    AllModules, AuthorityDiscovery, Babe, Call, Grandpa, Historical, InherentDataExt,
-    RandomnessCollectiveFlip, Runtime, SessionKeys, System, TransactionPayment,
+    AllModules, AuthorityDiscovery, Babe, Call, Council, CouncilElection, Grandpa, Historical,
+    InherentDataExt, ProposalsEngine, RandomnessCollectiveFlip, Runtime, SessionKeys, System,
+    TransactionPayment,
 };
+use governance::council::{SpendRecord, SpendingCategory, TermIndex};
+use governance::election::{ElectionResult, ElectionStage, Manifesto, RevealKey};
+use proposals_engine::{ProposalVotingStatus, VoteKind};
 
 /// The SignedExtension to the basic transaction logic.
 pub type SignedExtra = (
@@ -54,9 +59,90 @@ pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<AccountId, Call, Signa
 pub type Executive =
     frame_executive::Executive<Runtime, Block, system::ChainContext<Runtime>, Runtime, AllModules>;
 
+/// Proposal type of the proposals engine.
+pub type RuntimeProposal = proposals_engine::ProposalOf<Runtime>;
+
+/// Archived proposal type of the proposals engine.
+pub type RuntimeArchivedProposal = proposals_engine::ArchivedProposalOf<Runtime>;
+
+/// Result of the proposal dry run: the reason if the proposal execution would fail.
+pub type ProposalDryRunResult = Result<(), proposals_engine::DryRunError<ProposalId>>;
+
 /// Export of the private const generated within the macro.
 pub const EXPORTED_RUNTIME_API_VERSIONS: sp_version::ApisVec = RUNTIME_API_VERSIONS;
 
+sp_api::decl_runtime_apis! {
+    /// Council election API: exposes the election state and the vote commitment hashing.
+    pub trait CouncilElectionApi {
+        /// Current election stage with its end block. None if no election is running.
+        fn stage() -> Option<ElectionStage<BlockNumber>>;
+
+        /// Block number at which the current election stage ends.
+        fn stage_ends_at() -> Option<BlockNumber>;
+
+        /// Current election round.
+        fn round() -> u32;
+
+        /// Applicants of the current election with their total stakes.
+        fn applicant_stakes() -> Vec<(AccountId, Balance)>;
+
+        /// Applicants of the current election with their manifestos.
+        fn applicant_manifestos() -> Vec<(AccountId, Manifesto<ContentId>)>;
+
+        /// Archived results of the election round. None if the round didn't elect a council.
+        fn election_result(
+            round: u32,
+        ) -> Option<ElectionResult<AccountId, Balance, BlockNumber, ContentId>>;
+
+        /// Salted commitment hash for a vote, as expected by the reveal extrinsic.
+        fn vote_commitment(vote: AccountId, salt: Vec<u8>) -> Hash;
+
+        /// Reveal data for the scheduled reveal of a vote encrypted with ChaCha20-Poly1305.
+        /// None if the vote cannot be encrypted.
+        fn encrypt_reveal(key: RevealKey, vote: AccountId, salt: Vec<u8>) -> Option<Vec<u8>>;
+    }
+
+    /// Council API: exposes the council mint spending reports.
+    pub trait CouncilApi {
+        /// Index of the current council term.
+        fn term_number() -> TermIndex;
+
+        /// Council mint spends made in the term.
+        fn spend_ledger(term: TermIndex) -> Vec<SpendRecord<AccountId, Balance, BlockNumber>>;
+
+        /// Total amount spent in the term with the totals per spending category.
+        fn spending_totals(term: TermIndex) -> (Balance, Vec<(SpendingCategory, Balance)>);
+    }
+
+    /// Proposals engine API: exposes the proposals in progress and their voting status.
+    pub trait ProposalsEngineApi {
+        /// Proposals open for voting with their voting status.
+        fn active_proposals() -> Vec<(ProposalId, RuntimeProposal, ProposalVotingStatus<BlockNumber>)>;
+
+        /// Approved proposals pending execution.
+        fn pending_execution_proposals() -> Vec<(ProposalId, RuntimeProposal)>;
+
+        /// Voting status of the proposal open for voting, including the decision if the voting
+        /// ended now. None if the proposal is not open for voting.
+        fn voting_status(proposal_id: ProposalId) -> Option<ProposalVotingStatus<BlockNumber>>;
+
+        /// Current votes of the proposal by voter.
+        fn votes(proposal_id: ProposalId) -> Vec<(MemberId, VoteKind)>;
+
+        /// Dispatches the proposal call at the current state without committing the changes.
+        /// Returns the unmet dependencies or the dispatch error if the proposal execution
+        /// would fail.
+        fn dry_run(proposal_id: ProposalId) -> ProposalDryRunResult;
+
+        /// A page of the proposals history: archived proposals with ids starting from the
+        /// provided id. The page covers `limit` proposal ids.
+        fn proposal_history(
+            first_proposal_id: ProposalId,
+            limit: u32,
+        ) -> Vec<(ProposalId, RuntimeArchivedProposal)>;
+    }
+}
+
 impl_runtime_apis! {
     impl sp_api::Core<Block> for Runtime {
         fn version() -> RuntimeVersion {
@@ -201,4 +287,93 @@ impl_runtime_apis! {
             SessionKeys::decode_into_raw_public_keys(&encoded)
         }
     }
+
+    impl self::CouncilElectionApi<Block> for Runtime {
+        fn stage() -> Option<ElectionStage<BlockNumber>> {
+            CouncilElection::stage()
+        }
+
+        fn stage_ends_at() -> Option<BlockNumber> {
+            CouncilElection::stage_ends_at()
+        }
+
+        fn round() -> u32 {
+            CouncilElection::round()
+        }
+
+        fn applicant_stakes() -> Vec<(AccountId, Balance)> {
+            CouncilElection::applicants_with_stakes()
+        }
+
+        fn applicant_manifestos() -> Vec<(AccountId, Manifesto<ContentId>)> {
+            CouncilElection::applicants_with_manifestos()
+        }
+
+        fn election_result(
+            round: u32,
+        ) -> Option<ElectionResult<AccountId, Balance, BlockNumber, ContentId>> {
+            CouncilElection::election_results(round)
+        }
+
+        fn vote_commitment(vote: AccountId, salt: Vec<u8>) -> Hash {
+            CouncilElection::vote_commitment(&vote, &salt)
+        }
+
+        fn encrypt_reveal(key: RevealKey, vote: AccountId, salt: Vec<u8>) -> Option<Vec<u8>> {
+            CouncilElection::encrypt_reveal(&key, &vote, &salt)
+        }
+    }
+
+    impl self::CouncilApi<Block> for Runtime {
+        fn term_number() -> TermIndex {
+            Council::term_number()
+        }
+
+        fn spend_ledger(term: TermIndex) -> Vec<SpendRecord<AccountId, Balance, BlockNumber>> {
+            Council::term_spend_ledger(term)
+        }
+
+        fn spending_totals(term: TermIndex) -> (Balance, Vec<(SpendingCategory, Balance)>) {
+            (
+                Council::term_spending_total(term),
+                Council::term_spending_totals_by_category(term),
+            )
+        }
+    }
+
+    impl self::ProposalsEngineApi<Block> for Runtime {
+        fn active_proposals(
+        ) -> Vec<(ProposalId, RuntimeProposal, ProposalVotingStatus<BlockNumber>)> {
+            ProposalsEngine::active_proposals()
+                .into_iter()
+                .filter_map(|(proposal_id, proposal)| {
+                    ProposalsEngine::proposal_voting_status(proposal_id)
+                        .map(|voting_status| (proposal_id, proposal, voting_status))
+                })
+                .collect()
+        }
+
+        fn pending_execution_proposals() -> Vec<(ProposalId, RuntimeProposal)> {
+            ProposalsEngine::pending_execution_proposals()
+        }
+
+        fn voting_status(proposal_id: ProposalId) -> Option<ProposalVotingStatus<BlockNumber>> {
+            ProposalsEngine::proposal_voting_status(proposal_id)
+        }
+
+        fn votes(proposal_id: ProposalId) -> Vec<(MemberId, VoteKind)> {
+            ProposalsEngine::proposal_votes(proposal_id)
+        }
+
+        fn dry_run(proposal_id: ProposalId) -> ProposalDryRunResult {
+            ProposalsEngine::dry_run_proposal(proposal_id)
+        }
+
+        fn proposal_history(
+            first_proposal_id: ProposalId,
+            limit: u32,
+        ) -> Vec<(ProposalId, RuntimeArchivedProposal)> {
+            ProposalsEngine::proposal_history(first_proposal_id, limit)
+        }
+    }
 }
@@ -18,5 +18,14 @@ pub(crate) fn initial_test_ext() -> sp_io::TestExternalities {
 
     council_config.assimilate_storage(&mut t).unwrap();
 
+    // build the proposals codex config with zero grace and voting periods
+    proposals_codex::GenesisConfig::<crate::Runtime> {
+        proposal_parameters: proposals_codex::proposal_parameters_with_grace_and_voting_periods(
+            0, 0,
+        ),
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
     t.into()
 }
@@ -10,8 +10,8 @@ use governance::election_params::ElectionParameters;
 use membership;
 use proposals_engine::{
     ActiveStake, ApprovedProposalStatus, BalanceOf, FinalizationData, Proposal,
-    ProposalDecisionStatus, ProposalParameters, ProposalStatus, VoteKind, VotersParameters,
-    VotingResults,
+    ProposalCreationParameters, ProposalDecisionStatus, ProposalParameters, ProposalStatus,
+    VoteKind, VotersParameters, VotingResults,
 };
 
 use frame_support::dispatch::{DispatchError, DispatchResult};
@@ -32,6 +32,7 @@ pub type Council = governance::council::Module<Runtime>;
 pub type Election = governance::election::Module<Runtime>;
 pub type ProposalCodex = proposals_codex::Module<Runtime>;
 pub type Mint = minting::Module<Runtime>;
+pub type DataObjectTypeRegistry = storage::data_object_type_registry::Module<Runtime>;
 
 fn setup_members(count: u8) {
     let authority_account_id = <Runtime as system::Trait>::AccountId::default();
@@ -137,6 +138,7 @@ impl VoteGenerator {
             self.current_voter_id,
             self.proposal_id,
             vote_kind,
+            Vec::new(),
         )
     }
 }
@@ -168,6 +170,8 @@ impl Default for DummyProposalFixture {
                 slashing_threshold_percentage: 60,
                 grace_period: 0,
                 required_stake: None,
+                weighted_voting: false,
+                constitutionality: 1,
             },
             account_id: <Runtime as system::Trait>::AccountId::default(),
             proposer_id: 0,
@@ -213,15 +217,19 @@ impl DummyProposalFixture {
     }
 
     fn create_proposal_and_assert(self, result: Result<u32, DispatchError>) -> Option<u32> {
-        let proposal_id_result = ProposalsEngine::create_proposal(
-            self.account_id,
-            self.proposer_id,
-            self.parameters,
-            self.title,
-            self.description,
-            self.stake_balance,
-            self.proposal_code,
-        );
+        let proposal_id_result = ProposalsEngine::create_proposal(ProposalCreationParameters {
+            account_id: self.account_id,
+            proposer_id: self.proposer_id,
+            proposal_type: 0,
+            parameters: self.parameters,
+            title: self.title,
+            description: self.description,
+            stake_balance: self.stake_balance,
+            encoded_dispatchable_call_code: self.proposal_code,
+            execution_schedule: None,
+            dependencies: Vec::new(),
+            batch: false,
+        });
         assert_eq!(proposal_id_result, result);
 
         proposal_id_result.ok()
@@ -282,6 +290,8 @@ fn proposal_cancellation_with_slashes_with_balance_checks_succeeds() {
             slashing_threshold_percentage: 60,
             grace_period: 5,
             required_stake: Some(stake_amount),
+            weighted_voting: false,
+            constitutionality: 1,
         };
         let dummy_proposal = DummyProposalFixture::default()
             .with_parameters(parameters)
@@ -310,6 +320,9 @@ fn proposal_cancellation_with_slashes_with_balance_checks_succeeds() {
             parameters,
             proposer_id: member_id,
             created_at: 0,
+            activated_at: 0,
+            council_approvals: 0,
+            execution_schedule: None,
             status: ProposalStatus::Active(Some(ActiveStake {
                 stake_id: 0,
                 source_account_id: account_id.clone(),
@@ -412,6 +425,64 @@ fn proposal_reset_succeeds() {
     });
 }
 
+#[test]
+fn proposal_veto_by_council_motion_succeeds() {
+    initial_test_ext().execute_with(|| {
+        setup_members(2);
+        setup_council();
+
+        let res = Election::set_election_parameters(
+            RawOrigin::Root.into(),
+            ElectionParameters {
+                announcing_period: 1,
+                voting_period: 1,
+                revealing_period: 1,
+                council_size: 6,
+                candidacy_limit: 10,
+                new_term_duration: 2000000,
+                min_council_stake: 0,
+                min_voting_stake: 0,
+            },
+        );
+        assert_eq!(res, Ok(()));
+        assert_eq!(Council::motion_approval_threshold(), 4);
+
+        let dummy_proposal = DummyProposalFixture::default().with_voting_period(100);
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let veto_call =
+            crate::Call::ProposalsEngine(proposals_engine::Call::veto_proposal(proposal_id));
+        let councilor1: [u8; 32] = [1; 32];
+        assert_eq!(
+            Council::propose_motion(
+                RawOrigin::Signed(councilor1.into()).into(),
+                Box::new(veto_call)
+            ),
+            Ok(())
+        );
+        let motion_id = Council::motion_count();
+
+        for seed in 2..5 {
+            let councilor: [u8; 32] = [seed; 32];
+            assert!(<governance::council::Motions<Runtime>>::contains_key(
+                motion_id
+            ));
+            assert_eq!(
+                Council::approve_motion(RawOrigin::Signed(councilor.into()).into(), motion_id),
+                Ok(())
+            );
+        }
+
+        assert!(!<governance::council::Motions<Runtime>>::contains_key(
+            motion_id
+        ));
+        assert_eq!(
+            ProposalsEngine::proposals(proposal_id).status,
+            ProposalStatus::finalized_successfully(ProposalDecisionStatus::Vetoed, 0)
+        );
+    });
+}
+
 fn elect_single_councilor() {
     let res = Election::set_election_parameters(
         RawOrigin::Root.into(),
@@ -559,6 +630,8 @@ fn text_proposal_execution_succeeds() {
                 b"body".to_vec(),
                 Some(<BalanceOf<Runtime>>::from(25000u32)),
                 b"text".to_vec(),
+                None,
+                Vec::new(),
             )
         })
         .with_member_id(member_id as u64);
@@ -581,6 +654,8 @@ fn set_lead_proposal_execution_succeeds() {
                 b"body".to_vec(),
                 Some(<BalanceOf<Runtime>>::from(50000u32)),
                 Some((member_id as u64, account_id.into())),
+                None,
+                Vec::new(),
             )
         })
         .with_member_id(member_id as u64);
@@ -613,6 +688,8 @@ fn spending_proposal_execution_succeeds() {
                 Some(<BalanceOf<Runtime>>::from(25_000_u32)),
                 new_balance,
                 target_account_id.clone().into(),
+                None,
+                Vec::new(),
             )
         })
         .with_member_id(member_id as u64);
@@ -647,6 +724,8 @@ fn set_content_working_group_mint_capacity_execution_succeeds() {
                 b"body".to_vec(),
                 Some(<BalanceOf<Runtime>>::from(50000u32)),
                 new_balance,
+                None,
+                Vec::new(),
             )
         });
 
@@ -682,6 +761,9 @@ fn set_election_parameters_proposal_execution_succeeds() {
                 b"body".to_vec(),
                 Some(<BalanceOf<Runtime>>::from(200_000_u32)),
                 election_parameters,
+                false,
+                None,
+                Vec::new(),
             )
         });
         codex_extrinsic_test_fixture.call_extrinsic_and_assert();
@@ -690,6 +772,47 @@ fn set_election_parameters_proposal_execution_succeeds() {
     });
 }
 
+#[test]
+fn set_election_parameters_at_next_election_proposal_execution_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let member_id = 1;
+        let account_id: [u8; 32] = [member_id; 32];
+
+        let election_parameters = ElectionParameters {
+            announcing_period: 14400,
+            voting_period: 14400,
+            revealing_period: 14400,
+            council_size: 4,
+            candidacy_limit: 25,
+            new_term_duration: 14400,
+            min_council_stake: 1,
+            min_voting_stake: 1,
+        };
+        assert_eq!(Election::pending_election_parameters(), None);
+
+        let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
+            ProposalCodex::create_set_election_parameters_proposal(
+                RawOrigin::Signed(account_id.clone().into()).into(),
+                member_id as u64,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Runtime>>::from(200_000_u32)),
+                election_parameters,
+                true,
+                None,
+                Vec::new(),
+            )
+        });
+        codex_extrinsic_test_fixture.call_extrinsic_and_assert();
+
+        assert_eq!(
+            Election::pending_election_parameters(),
+            Some(election_parameters)
+        );
+        assert_eq!(Election::announcing_period(), 0);
+    });
+}
+
 #[test]
 fn set_validator_count_proposal_execution_succeeds() {
     initial_test_ext().execute_with(|| {
@@ -707,6 +830,8 @@ fn set_validator_count_proposal_execution_succeeds() {
                 b"body".to_vec(),
                 Some(<BalanceOf<Runtime>>::from(100_000_u32)),
                 new_validator_count,
+                None,
+                Vec::new(),
             )
         });
         codex_extrinsic_test_fixture.call_extrinsic_and_assert();
@@ -714,3 +839,89 @@ fn set_validator_count_proposal_execution_succeeds() {
         assert_eq!(<pallet_staking::ValidatorCount>::get(), new_validator_count);
     });
 }
+
+#[test]
+fn set_new_memberships_allowed_proposal_execution_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let member_id = 1;
+        let account_id: [u8; 32] = [member_id; 32];
+
+        assert!(Membership::new_memberships_allowed());
+
+        let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
+            ProposalCodex::create_set_new_memberships_allowed_proposal(
+                RawOrigin::Signed(account_id.clone().into()).into(),
+                member_id as u64,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Runtime>>::from(25_000_u32)),
+                false,
+                None,
+                Vec::new(),
+            )
+        });
+        codex_extrinsic_test_fixture.call_extrinsic_and_assert();
+
+        assert!(!Membership::new_memberships_allowed());
+    });
+}
+
+#[test]
+fn set_max_memo_length_proposal_execution_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let member_id = 1;
+        let account_id: [u8; 32] = [member_id; 32];
+
+        let max_memo_length = 100;
+        assert_ne!(memo::Module::<Runtime>::max_memo_length(), max_memo_length);
+
+        let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
+            ProposalCodex::create_set_max_memo_length_proposal(
+                RawOrigin::Signed(account_id.clone().into()).into(),
+                member_id as u64,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Runtime>>::from(25_000_u32)),
+                max_memo_length,
+                None,
+                Vec::new(),
+            )
+        });
+        codex_extrinsic_test_fixture.call_extrinsic_and_assert();
+
+        assert_eq!(memo::Module::<Runtime>::max_memo_length(), max_memo_length);
+    });
+}
+
+#[test]
+fn register_data_object_type_proposal_execution_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let member_id = 1;
+        let account_id: [u8; 32] = [member_id; 32];
+
+        let data_object_type_id = DataObjectTypeRegistry::next_data_object_type_id();
+
+        let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
+            ProposalCodex::create_register_data_object_type_proposal(
+                RawOrigin::Signed(account_id.clone().into()).into(),
+                member_id as u64,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Runtime>>::from(25_000_u32)),
+                proposals_codex::DataObjectTypeParameters {
+                    description: b"description".to_vec(),
+                    active: false,
+                },
+                None,
+                Vec::new(),
+            )
+        });
+        codex_extrinsic_test_fixture.call_extrinsic_and_assert();
+
+        let data_object_type = DataObjectTypeRegistry::data_object_types(data_object_type_id)
+            .expect("Data object type must be registered");
+
+        assert_eq!(data_object_type.description, b"description".to_vec());
+        assert!(!data_object_type.active);
+    });
+}
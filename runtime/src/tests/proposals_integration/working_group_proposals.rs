@@ -7,29 +7,34 @@ use hiring::ActivateOpeningAt;
 use proposals_codex::AddOpeningParameters;
 use working_group::{OpeningPolicyCommitment, RewardPolicy};
 
-use crate::{Balance, BlockNumber, StorageWorkingGroupInstance};
+use crate::{
+    Balance, BlockNumber, ContentDirectoryWorkingGroupInstance, StorageWorkingGroupInstance,
+};
 use sp_std::collections::btree_set::BTreeSet;
 
-type StorageWorkingGroup = working_group::Module<Runtime, StorageWorkingGroupInstance>;
+type WorkingGroupInstance<I> = working_group::Module<Runtime, I>;
 
 type Hiring = hiring::Module<Runtime>;
 
-fn add_opening(
+fn add_opening<I: working_group::Instance>(
     member_id: u8,
     account_id: [u8; 32],
     activate_at: hiring::ActivateOpeningAt<BlockNumber>,
     opening_policy_commitment: Option<OpeningPolicyCommitment<BlockNumber, u128>>,
     sequence_number: u32, // action sequence number to align with other actions
-) -> u64 {
+    working_group: WorkingGroup,
+) -> u64
+where
+    Runtime: working_group::Trait<I>,
+{
     let expected_proposal_id = sequence_number;
     let run_to_block = sequence_number * 2;
 
-    let opening_id = StorageWorkingGroup::next_opening_id();
+    let opening_id = WorkingGroupInstance::<I>::next_opening_id();
 
-    assert!(!<working_group::OpeningById<
-        Runtime,
-        StorageWorkingGroupInstance,
-    >>::contains_key(opening_id));
+    assert!(!<working_group::OpeningById<Runtime, I>>::contains_key(
+        opening_id
+    ));
 
     let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
         ProposalCodex::create_add_working_group_leader_opening_proposal(
@@ -44,8 +49,10 @@ fn add_opening(
                     .clone()
                     .unwrap_or(OpeningPolicyCommitment::default()),
                 human_readable_text: Vec::new(),
-                working_group: WorkingGroup::Storage,
+                working_group,
             },
+            None,
+            Vec::new(),
         )
     })
     .with_expected_proposal_id(expected_proposal_id)
@@ -61,6 +68,7 @@ fn begin_review_applications(
     account_id: [u8; 32],
     opening_id: u64,
     sequence_number: u32, // action sequence number to align with other actions
+    working_group: WorkingGroup,
 ) {
     let expected_proposal_id = sequence_number;
     let run_to_block = sequence_number * 2;
@@ -73,7 +81,9 @@ fn begin_review_applications(
             b"body".to_vec(),
             Some(<BalanceOf<Runtime>>::from(25_000_u32)),
             opening_id,
-            WorkingGroup::Storage,
+            working_group,
+            None,
+            Vec::new(),
         )
     })
     .disable_setup_enviroment()
@@ -90,6 +100,7 @@ fn fill_opening(
     successful_application_id: u64,
     reward_policy: Option<RewardPolicy<Balance, BlockNumber>>,
     sequence_number: u32, // action sequence number to align with other actions
+    working_group: WorkingGroup,
 ) {
     let expected_proposal_id = sequence_number;
     let run_to_block = sequence_number * 2;
@@ -105,8 +116,10 @@ fn fill_opening(
                 opening_id,
                 successful_application_id,
                 reward_policy: reward_policy.clone(),
-                working_group: WorkingGroup::Storage,
+                working_group,
             },
+            None,
+            Vec::new(),
         )
     })
     .disable_setup_enviroment()
@@ -130,6 +143,7 @@ fn decrease_stake(
     leader_worker_id: u64,
     stake_amount: Balance,
     sequence_number: u32, // action sequence number to align with other actions
+    working_group: WorkingGroup,
 ) {
     let expected_proposal_id = sequence_number;
     let run_to_block = sequence_number * 2;
@@ -143,7 +157,9 @@ fn decrease_stake(
             Some(<BalanceOf<Runtime>>::from(50_000_u32)),
             leader_worker_id,
             stake_amount,
-            WorkingGroup::Storage,
+            working_group,
+            None,
+            Vec::new(),
         )
     })
     .disable_setup_enviroment()
@@ -159,6 +175,7 @@ fn slash_stake(
     leader_worker_id: u64,
     stake_amount: Balance,
     sequence_number: u32, // action sequence number to align with other actions
+    working_group: WorkingGroup,
 ) {
     let expected_proposal_id = sequence_number;
     let run_to_block = sequence_number * 2;
@@ -172,7 +189,9 @@ fn slash_stake(
             Some(<BalanceOf<Runtime>>::from(50_000_u32)),
             leader_worker_id,
             stake_amount,
-            WorkingGroup::Storage,
+            working_group,
+            None,
+            Vec::new(),
         )
     })
     .disable_setup_enviroment()
@@ -188,6 +207,7 @@ fn set_reward(
     leader_worker_id: u64,
     reward_amount: Balance,
     sequence_number: u32, // action sequence number to align with other actions
+    working_group: WorkingGroup,
 ) {
     let expected_proposal_id = sequence_number;
     let run_to_block = sequence_number * 2;
@@ -201,7 +221,9 @@ fn set_reward(
             Some(<BalanceOf<Runtime>>::from(50_000_u32)),
             leader_worker_id,
             reward_amount,
-            WorkingGroup::Storage,
+            working_group,
+            None,
+            Vec::new(),
         )
     })
     .disable_setup_enviroment()
@@ -211,20 +233,23 @@ fn set_reward(
     codex_extrinsic_test_fixture.call_extrinsic_and_assert();
 }
 
-fn set_mint_capacity(
+fn set_mint_capacity<I: working_group::Instance>(
     member_id: u8,
     account_id: [u8; 32],
     mint_capacity: Balance,
     sequence_number: u32, // action sequence number to align with other actions
     setup_environment: bool,
-) {
+    working_group: WorkingGroup,
+) where
+    Runtime: working_group::Trait<I>,
+{
     let expected_proposal_id = sequence_number;
     let run_to_block = sequence_number * 2;
 
     let mint_id_result = <minting::Module<Runtime>>::add_mint(0, None);
 
     if let Ok(mint_id) = mint_id_result {
-        <working_group::Mint<Runtime, StorageWorkingGroupInstance>>::put(mint_id);
+        <working_group::Mint<Runtime, I>>::put(mint_id);
     }
 
     let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
@@ -235,7 +260,9 @@ fn set_mint_capacity(
             b"body".to_vec(),
             Some(<BalanceOf<Runtime>>::from(50_000_u32)),
             mint_capacity,
-            WorkingGroup::Storage,
+            working_group,
+            None,
+            Vec::new(),
         )
     })
     .with_setup_enviroment(setup_environment)
@@ -251,6 +278,7 @@ fn terminate_role(
     leader_worker_id: u64,
     slash: bool,
     sequence_number: u32, // action sequence number to align with other actions
+    working_group: WorkingGroup,
 ) {
     let expected_proposal_id = sequence_number;
     let run_to_block = sequence_number * 2;
@@ -266,8 +294,10 @@ fn terminate_role(
                 worker_id: leader_worker_id,
                 rationale: Vec::new(),
                 slash,
-                working_group: WorkingGroup::Storage,
+                working_group,
             },
+            None,
+            Vec::new(),
         )
     })
     .disable_setup_enviroment()
@@ -279,51 +309,81 @@ fn terminate_role(
 
 #[test]
 fn create_add_working_group_leader_opening_proposal_execution_succeeds() {
+    run_create_add_working_group_leader_opening_proposal_execution_succeeds::<
+        StorageWorkingGroupInstance,
+    >(WorkingGroup::Storage);
+    run_create_add_working_group_leader_opening_proposal_execution_succeeds::<
+        ContentDirectoryWorkingGroupInstance,
+    >(WorkingGroup::Content);
+}
+
+fn run_create_add_working_group_leader_opening_proposal_execution_succeeds<
+    I: working_group::Instance,
+>(
+    working_group: WorkingGroup,
+) where
+    Runtime: working_group::Trait<I>,
+{
     initial_test_ext().execute_with(|| {
         let member_id = 1;
         let account_id: [u8; 32] = [member_id; 32];
 
-        let next_opening_id = StorageWorkingGroup::next_opening_id();
+        let next_opening_id = WorkingGroupInstance::<I>::next_opening_id();
 
-        assert!(!<working_group::OpeningById<
-            Runtime,
-            StorageWorkingGroupInstance,
-        >>::contains_key(next_opening_id));
+        assert!(!<working_group::OpeningById<Runtime, I>>::contains_key(
+            next_opening_id
+        ));
 
-        let opening_id = add_opening(
+        let opening_id = add_opening::<I>(
             member_id,
             account_id,
             ActivateOpeningAt::CurrentBlock,
             None,
             1,
+            working_group,
         );
 
         // Check for expected opening id.
         assert_eq!(opening_id, next_opening_id);
 
         // Check for the new opening creation.
-        assert!(<working_group::OpeningById<
-            Runtime,
-            StorageWorkingGroupInstance,
-        >>::contains_key(opening_id));
+        assert!(<working_group::OpeningById<Runtime, I>>::contains_key(
+            opening_id
+        ));
     });
 }
 
 #[test]
 fn create_begin_review_working_group_leader_applications_proposal_execution_succeeds() {
+    run_create_begin_review_working_group_leader_applications_proposal_execution_succeeds::<
+        StorageWorkingGroupInstance,
+    >(WorkingGroup::Storage);
+    run_create_begin_review_working_group_leader_applications_proposal_execution_succeeds::<
+        ContentDirectoryWorkingGroupInstance,
+    >(WorkingGroup::Content);
+}
+
+fn run_create_begin_review_working_group_leader_applications_proposal_execution_succeeds<
+    I: working_group::Instance,
+>(
+    working_group: WorkingGroup,
+) where
+    Runtime: working_group::Trait<I>,
+{
     initial_test_ext().execute_with(|| {
         let member_id = 1;
         let account_id: [u8; 32] = [member_id; 32];
 
-        let opening_id = add_opening(
+        let opening_id = add_opening::<I>(
             member_id,
             account_id.clone(),
             ActivateOpeningAt::CurrentBlock,
             None,
             1,
+            working_group,
         );
 
-        let opening = StorageWorkingGroup::opening_by_id(opening_id);
+        let opening = WorkingGroupInstance::<I>::opening_by_id(opening_id);
 
         let hiring_opening = Hiring::opening_by_id(opening.hiring_opening_id);
         assert_eq!(
@@ -339,7 +399,7 @@ fn create_begin_review_working_group_leader_applications_proposal_execution_succ
             }
         );
 
-        begin_review_applications(member_id, account_id, opening_id, 2);
+        begin_review_applications(member_id, account_id, opening_id, 2, working_group);
 
         let hiring_opening = Hiring::opening_by_id(opening.hiring_opening_id);
         assert_eq!(
@@ -360,19 +420,35 @@ fn create_begin_review_working_group_leader_applications_proposal_execution_succ
 
 #[test]
 fn create_fill_working_group_leader_opening_proposal_execution_succeeds() {
+    run_create_fill_working_group_leader_opening_proposal_execution_succeeds::<
+        StorageWorkingGroupInstance,
+    >(WorkingGroup::Storage);
+    run_create_fill_working_group_leader_opening_proposal_execution_succeeds::<
+        ContentDirectoryWorkingGroupInstance,
+    >(WorkingGroup::Content);
+}
+
+fn run_create_fill_working_group_leader_opening_proposal_execution_succeeds<
+    I: working_group::Instance,
+>(
+    working_group: WorkingGroup,
+) where
+    Runtime: working_group::Trait<I>,
+{
     initial_test_ext().execute_with(|| {
         let member_id = 1;
         let account_id: [u8; 32] = [member_id; 32];
 
-        let opening_id = add_opening(
+        let opening_id = add_opening::<I>(
             member_id,
             account_id.clone(),
             ActivateOpeningAt::CurrentBlock,
             None,
             1,
+            working_group,
         );
 
-        let apply_result = StorageWorkingGroup::apply_on_opening(
+        let apply_result = WorkingGroupInstance::<I>::apply_on_opening(
             RawOrigin::Signed(account_id.clone().into()).into(),
             member_id as u64,
             opening_id,
@@ -386,9 +462,9 @@ fn create_fill_working_group_leader_opening_proposal_execution_succeeds() {
 
         let expected_application_id = 0;
 
-        begin_review_applications(member_id, account_id, opening_id, 2);
+        begin_review_applications(member_id, account_id, opening_id, 2, working_group);
 
-        let lead = StorageWorkingGroup::current_lead();
+        let lead = WorkingGroupInstance::<I>::current_lead();
         assert!(lead.is_none());
 
         fill_opening(
@@ -398,15 +474,29 @@ fn create_fill_working_group_leader_opening_proposal_execution_succeeds() {
             expected_application_id,
             None,
             3,
+            working_group,
         );
 
-        let lead = StorageWorkingGroup::current_lead();
+        let lead = WorkingGroupInstance::<I>::current_lead();
         assert!(lead.is_some());
     });
 }
 
 #[test]
 fn create_decrease_group_leader_stake_proposal_execution_succeeds() {
+    run_create_decrease_group_leader_stake_proposal_execution_succeeds::<StorageWorkingGroupInstance>(
+        WorkingGroup::Storage,
+    );
+    run_create_decrease_group_leader_stake_proposal_execution_succeeds::<
+        ContentDirectoryWorkingGroupInstance,
+    >(WorkingGroup::Content);
+}
+
+fn run_create_decrease_group_leader_stake_proposal_execution_succeeds<I: working_group::Instance>(
+    working_group: WorkingGroup,
+) where
+    Runtime: working_group::Trait<I>,
+{
     initial_test_ext().execute_with(|| {
         let member_id = 1;
         let account_id: [u8; 32] = [member_id; 32];
@@ -422,15 +512,16 @@ fn create_decrease_group_leader_stake_proposal_execution_succeeds() {
             ..OpeningPolicyCommitment::default()
         };
 
-        let opening_id = add_opening(
+        let opening_id = add_opening::<I>(
             member_id,
             account_id.clone(),
             ActivateOpeningAt::CurrentBlock,
             Some(opening_policy_commitment),
             1,
+            working_group,
         );
 
-        let apply_result = StorageWorkingGroup::apply_on_opening(
+        let apply_result = WorkingGroupInstance::<I>::apply_on_opening(
             RawOrigin::Signed(account_id.clone().into()).into(),
             member_id as u64,
             opening_id,
@@ -444,9 +535,9 @@ fn create_decrease_group_leader_stake_proposal_execution_succeeds() {
 
         let expected_application_id = 0;
 
-        begin_review_applications(member_id, account_id, opening_id, 2);
+        begin_review_applications(member_id, account_id, opening_id, 2, working_group);
 
-        let lead = StorageWorkingGroup::current_lead();
+        let lead = WorkingGroupInstance::<I>::current_lead();
         assert!(lead.is_none());
 
         fill_opening(
@@ -456,9 +547,10 @@ fn create_decrease_group_leader_stake_proposal_execution_succeeds() {
             expected_application_id,
             None,
             3,
+            working_group,
         );
 
-        let leader_worker_id = StorageWorkingGroup::current_lead().unwrap();
+        let leader_worker_id = WorkingGroupInstance::<I>::current_lead().unwrap();
 
         let stake_id = 1;
         let old_balance = Balances::free_balance(&account_id.into());
@@ -473,6 +565,7 @@ fn create_decrease_group_leader_stake_proposal_execution_succeeds() {
             leader_worker_id,
             decreasing_stake_amount,
             4,
+            working_group,
         );
 
         let new_balance = Balances::free_balance(&account_id.into());
@@ -488,6 +581,19 @@ fn create_decrease_group_leader_stake_proposal_execution_succeeds() {
 
 #[test]
 fn create_slash_group_leader_stake_proposal_execution_succeeds() {
+    run_create_slash_group_leader_stake_proposal_execution_succeeds::<StorageWorkingGroupInstance>(
+        WorkingGroup::Storage,
+    );
+    run_create_slash_group_leader_stake_proposal_execution_succeeds::<
+        ContentDirectoryWorkingGroupInstance,
+    >(WorkingGroup::Content);
+}
+
+fn run_create_slash_group_leader_stake_proposal_execution_succeeds<I: working_group::Instance>(
+    working_group: WorkingGroup,
+) where
+    Runtime: working_group::Trait<I>,
+{
     initial_test_ext().execute_with(|| {
         let member_id = 1;
         let account_id: [u8; 32] = [member_id; 32];
@@ -503,15 +609,16 @@ fn create_slash_group_leader_stake_proposal_execution_succeeds() {
             ..OpeningPolicyCommitment::default()
         };
 
-        let opening_id = add_opening(
+        let opening_id = add_opening::<I>(
             member_id,
             account_id.clone(),
             ActivateOpeningAt::CurrentBlock,
             Some(opening_policy_commitment),
             1,
+            working_group,
         );
 
-        let apply_result = StorageWorkingGroup::apply_on_opening(
+        let apply_result = WorkingGroupInstance::<I>::apply_on_opening(
             RawOrigin::Signed(account_id.clone().into()).into(),
             member_id as u64,
             opening_id,
@@ -525,9 +632,9 @@ fn create_slash_group_leader_stake_proposal_execution_succeeds() {
 
         let expected_application_id = 0;
 
-        begin_review_applications(member_id, account_id, opening_id, 2);
+        begin_review_applications(member_id, account_id, opening_id, 2, working_group);
 
-        let lead = StorageWorkingGroup::current_lead();
+        let lead = WorkingGroupInstance::<I>::current_lead();
         assert!(lead.is_none());
 
         fill_opening(
@@ -537,9 +644,10 @@ fn create_slash_group_leader_stake_proposal_execution_succeeds() {
             expected_application_id,
             None,
             3,
+            working_group,
         );
 
-        let leader_worker_id = StorageWorkingGroup::current_lead().unwrap();
+        let leader_worker_id = WorkingGroupInstance::<I>::current_lead().unwrap();
 
         let stake_id = 1;
         let old_balance = Balances::free_balance(&account_id.into());
@@ -554,6 +662,7 @@ fn create_slash_group_leader_stake_proposal_execution_succeeds() {
             leader_worker_id,
             slashing_stake_amount,
             4,
+            working_group,
         );
 
         let new_balance = Balances::free_balance(&account_id.into());
@@ -569,16 +678,31 @@ fn create_slash_group_leader_stake_proposal_execution_succeeds() {
 
 #[test]
 fn create_set_working_group_mint_capacity_proposal_execution_succeeds() {
+    run_create_set_working_group_mint_capacity_proposal_execution_succeeds::<
+        StorageWorkingGroupInstance,
+    >(WorkingGroup::Storage);
+    run_create_set_working_group_mint_capacity_proposal_execution_succeeds::<
+        ContentDirectoryWorkingGroupInstance,
+    >(WorkingGroup::Content);
+}
+
+fn run_create_set_working_group_mint_capacity_proposal_execution_succeeds<
+    I: working_group::Instance,
+>(
+    working_group: WorkingGroup,
+) where
+    Runtime: working_group::Trait<I>,
+{
     initial_test_ext().execute_with(|| {
         let member_id = 1;
         let account_id: [u8; 32] = [member_id; 32];
 
-        assert_eq!(StorageWorkingGroup::mint(), 0);
+        assert_eq!(WorkingGroupInstance::<I>::mint(), 0);
 
         let mint_capacity = 999999;
-        set_mint_capacity(member_id, account_id, mint_capacity, 1, true);
+        set_mint_capacity::<I>(member_id, account_id, mint_capacity, 1, true, working_group);
 
-        let mint_id = StorageWorkingGroup::mint();
+        let mint_id = WorkingGroupInstance::<I>::mint();
         let mint = <minting::Module<Runtime>>::mints(mint_id);
 
         assert_eq!(mint.capacity(), mint_capacity);
@@ -587,6 +711,19 @@ fn create_set_working_group_mint_capacity_proposal_execution_succeeds() {
 
 #[test]
 fn create_set_group_leader_reward_proposal_execution_succeeds() {
+    run_create_set_group_leader_reward_proposal_execution_succeeds::<StorageWorkingGroupInstance>(
+        WorkingGroup::Storage,
+    );
+    run_create_set_group_leader_reward_proposal_execution_succeeds::<
+        ContentDirectoryWorkingGroupInstance,
+    >(WorkingGroup::Content);
+}
+
+fn run_create_set_group_leader_reward_proposal_execution_succeeds<I: working_group::Instance>(
+    working_group: WorkingGroup,
+) where
+    Runtime: working_group::Trait<I>,
+{
     initial_test_ext().execute_with(|| {
         let member_id = 1;
         let account_id: [u8; 32] = [member_id; 32];
@@ -602,15 +739,16 @@ fn create_set_group_leader_reward_proposal_execution_succeeds() {
             ..OpeningPolicyCommitment::default()
         };
 
-        let opening_id = add_opening(
+        let opening_id = add_opening::<I>(
             member_id,
             account_id.clone(),
             ActivateOpeningAt::CurrentBlock,
             Some(opening_policy_commitment),
             1,
+            working_group,
         );
 
-        let apply_result = StorageWorkingGroup::apply_on_opening(
+        let apply_result = WorkingGroupInstance::<I>::apply_on_opening(
             RawOrigin::Signed(account_id.clone().into()).into(),
             member_id as u64,
             opening_id,
@@ -624,9 +762,9 @@ fn create_set_group_leader_reward_proposal_execution_succeeds() {
 
         let expected_application_id = 0;
 
-        begin_review_applications(member_id, account_id, opening_id, 2);
+        begin_review_applications(member_id, account_id, opening_id, 2, working_group);
 
-        let lead = StorageWorkingGroup::current_lead();
+        let lead = WorkingGroupInstance::<I>::current_lead();
         assert!(lead.is_none());
 
         let old_reward_amount = 100;
@@ -636,7 +774,7 @@ fn create_set_group_leader_reward_proposal_execution_succeeds() {
             payout_interval: None,
         });
 
-        set_mint_capacity(member_id, account_id, 999999, 3, false);
+        set_mint_capacity::<I>(member_id, account_id, 999999, 3, false, working_group);
 
         fill_opening(
             member_id,
@@ -645,11 +783,12 @@ fn create_set_group_leader_reward_proposal_execution_succeeds() {
             expected_application_id,
             reward_policy,
             4,
+            working_group,
         );
 
-        let leader_worker_id = StorageWorkingGroup::current_lead().unwrap();
+        let leader_worker_id = WorkingGroupInstance::<I>::current_lead().unwrap();
 
-        let worker = StorageWorkingGroup::worker_by_id(leader_worker_id);
+        let worker = WorkingGroupInstance::<I>::worker_by_id(leader_worker_id);
         let relationship_id = worker.reward_relationship.unwrap();
 
         let relationship = recurring_rewards::RewardRelationships::<Runtime>::get(relationship_id);
@@ -662,6 +801,7 @@ fn create_set_group_leader_reward_proposal_execution_succeeds() {
             leader_worker_id,
             new_reward_amount,
             5,
+            working_group,
         );
 
         let relationship = recurring_rewards::RewardRelationships::<Runtime>::get(relationship_id);
@@ -671,6 +811,19 @@ fn create_set_group_leader_reward_proposal_execution_succeeds() {
 
 #[test]
 fn create_terminate_group_leader_role_proposal_execution_succeeds() {
+    run_create_terminate_group_leader_role_proposal_execution_succeeds::<StorageWorkingGroupInstance>(
+        WorkingGroup::Storage,
+    );
+    run_create_terminate_group_leader_role_proposal_execution_succeeds::<
+        ContentDirectoryWorkingGroupInstance,
+    >(WorkingGroup::Content);
+}
+
+fn run_create_terminate_group_leader_role_proposal_execution_succeeds<I: working_group::Instance>(
+    working_group: WorkingGroup,
+) where
+    Runtime: working_group::Trait<I>,
+{
     initial_test_ext().execute_with(|| {
         let member_id = 1;
         let account_id: [u8; 32] = [member_id; 32];
@@ -686,15 +839,16 @@ fn create_terminate_group_leader_role_proposal_execution_succeeds() {
             ..OpeningPolicyCommitment::default()
         };
 
-        let opening_id = add_opening(
+        let opening_id = add_opening::<I>(
             member_id,
             account_id.clone(),
             ActivateOpeningAt::CurrentBlock,
             Some(opening_policy_commitment),
             1,
+            working_group,
         );
 
-        let apply_result = StorageWorkingGroup::apply_on_opening(
+        let apply_result = WorkingGroupInstance::<I>::apply_on_opening(
             RawOrigin::Signed(account_id.clone().into()).into(),
             member_id as u64,
             opening_id,
@@ -708,9 +862,9 @@ fn create_terminate_group_leader_role_proposal_execution_succeeds() {
 
         let expected_application_id = 0;
 
-        begin_review_applications(member_id, account_id, opening_id, 2);
+        begin_review_applications(member_id, account_id, opening_id, 2, working_group);
 
-        let lead = StorageWorkingGroup::current_lead();
+        let lead = WorkingGroupInstance::<I>::current_lead();
         assert!(lead.is_none());
 
         let old_reward_amount = 100;
@@ -720,7 +874,7 @@ fn create_terminate_group_leader_role_proposal_execution_succeeds() {
             payout_interval: None,
         });
 
-        set_mint_capacity(member_id, account_id, 999999, 3, false);
+        set_mint_capacity::<I>(member_id, account_id, 999999, 3, false, working_group);
 
         fill_opening(
             member_id,
@@ -729,9 +883,10 @@ fn create_terminate_group_leader_role_proposal_execution_succeeds() {
             expected_application_id,
             reward_policy,
             4,
+            working_group,
         );
 
-        let leader_worker_id = StorageWorkingGroup::current_lead().unwrap();
+        let leader_worker_id = WorkingGroupInstance::<I>::current_lead().unwrap();
 
         let stake_id = 1;
         let old_balance = Balances::free_balance(&account_id.into());
@@ -739,9 +894,16 @@ fn create_terminate_group_leader_role_proposal_execution_succeeds() {
 
         assert_eq!(get_stake_balance(old_stake), stake_amount);
 
-        terminate_role(member_id, account_id, leader_worker_id, false, 5);
+        terminate_role(
+            member_id,
+            account_id,
+            leader_worker_id,
+            false,
+            5,
+            working_group,
+        );
 
-        assert!(StorageWorkingGroup::current_lead().is_none());
+        assert!(WorkingGroupInstance::<I>::current_lead().is_none());
 
         let new_balance = Balances::free_balance(&account_id.into());
         let new_stake = <stake::Module<Runtime>>::stakes(stake_id);
@@ -753,6 +915,21 @@ fn create_terminate_group_leader_role_proposal_execution_succeeds() {
 
 #[test]
 fn create_terminate_group_leader_role_proposal_with_slashing_execution_succeeds() {
+    run_create_terminate_group_leader_role_proposal_with_slashing_execution_succeeds::<
+        StorageWorkingGroupInstance,
+    >(WorkingGroup::Storage);
+    run_create_terminate_group_leader_role_proposal_with_slashing_execution_succeeds::<
+        ContentDirectoryWorkingGroupInstance,
+    >(WorkingGroup::Content);
+}
+
+fn run_create_terminate_group_leader_role_proposal_with_slashing_execution_succeeds<
+    I: working_group::Instance,
+>(
+    working_group: WorkingGroup,
+) where
+    Runtime: working_group::Trait<I>,
+{
     initial_test_ext().execute_with(|| {
         let member_id = 1;
         let account_id: [u8; 32] = [member_id; 32];
@@ -768,15 +945,16 @@ fn create_terminate_group_leader_role_proposal_with_slashing_execution_succeeds(
             ..OpeningPolicyCommitment::default()
         };
 
-        let opening_id = add_opening(
+        let opening_id = add_opening::<I>(
             member_id,
             account_id.clone(),
             ActivateOpeningAt::CurrentBlock,
             Some(opening_policy_commitment),
             1,
+            working_group,
         );
 
-        let apply_result = StorageWorkingGroup::apply_on_opening(
+        let apply_result = WorkingGroupInstance::<I>::apply_on_opening(
             RawOrigin::Signed(account_id.clone().into()).into(),
             member_id as u64,
             opening_id,
@@ -790,9 +968,9 @@ fn create_terminate_group_leader_role_proposal_with_slashing_execution_succeeds(
 
         let expected_application_id = 0;
 
-        begin_review_applications(member_id, account_id, opening_id, 2);
+        begin_review_applications(member_id, account_id, opening_id, 2, working_group);
 
-        let lead = StorageWorkingGroup::current_lead();
+        let lead = WorkingGroupInstance::<I>::current_lead();
         assert!(lead.is_none());
 
         let old_reward_amount = 100;
@@ -802,7 +980,7 @@ fn create_terminate_group_leader_role_proposal_with_slashing_execution_succeeds(
             payout_interval: None,
         });
 
-        set_mint_capacity(member_id, account_id, 999999, 3, false);
+        set_mint_capacity::<I>(member_id, account_id, 999999, 3, false, working_group);
 
         fill_opening(
             member_id,
@@ -811,9 +989,10 @@ fn create_terminate_group_leader_role_proposal_with_slashing_execution_succeeds(
             expected_application_id,
             reward_policy,
             4,
+            working_group,
         );
 
-        let leader_worker_id = StorageWorkingGroup::current_lead().unwrap();
+        let leader_worker_id = WorkingGroupInstance::<I>::current_lead().unwrap();
 
         let stake_id = 1;
         let old_balance = Balances::free_balance(&account_id.into());
@@ -821,9 +1000,16 @@ fn create_terminate_group_leader_role_proposal_with_slashing_execution_succeeds(
 
         assert_eq!(get_stake_balance(old_stake), stake_amount);
 
-        terminate_role(member_id, account_id, leader_worker_id, true, 5);
+        terminate_role(
+            member_id,
+            account_id,
+            leader_worker_id,
+            true,
+            5,
+            working_group,
+        );
 
-        assert!(StorageWorkingGroup::current_lead().is_none());
+        assert!(WorkingGroupInstance::<I>::current_lead().is_none());
 
         let new_balance = Balances::free_balance(&account_id.into());
         let new_stake = <stake::Module<Runtime>>::stakes(stake_id);
@@ -1,8 +1,15 @@
 import { Text, u32, Tuple, u8, Vec, Option, Null, Bytes } from '@polkadot/types'
-import { bool, u128 } from '@polkadot/types/primitive'
+import { bool, u64, u128 } from '@polkadot/types/primitive'
 import { BlockNumber, Balance } from '@polkadot/types/interfaces'
 import AccountId from '@polkadot/types/generic/AccountId'
-import { ThreadId, WorkingGroup, JoyEnum, JoyStructDecorated } from './common'
+import {
+  ThreadId,
+  PostId,
+  WorkingGroup,
+  InputValidationLengthConstraint,
+  JoyEnum,
+  JoyStructDecorated,
+} from './common'
 import { MemberId } from './members'
 import { RoleParameters } from './roles'
 import { StakeId } from './stake'
@@ -40,6 +47,10 @@ export type ProposalParametersType = {
   slashingThresholdPercentage: u32
   // Proposal stake
   requiredStake: Option<Balance>
+  // Votes are weighted by the voting weight instead of being counted by head.
+  weightedVoting: bool
+  // Number of consecutive councils that must approve the proposal before its execution.
+  constitutionality: u32
 }
 
 export class ProposalParameters
@@ -51,15 +62,25 @@ export class ProposalParameters
     slashingQuorumPercentage: u32,
     slashingThresholdPercentage: u32,
     requiredStake: Option.with(u128),
+    weightedVoting: bool,
+    constitutionality: u32,
   })
   implements ProposalParametersType {}
 
+export class ExecutionSchedule extends JoyEnum({
+  AtBlock: u32, // BlockNumber
+  NotBefore: u32, // BlockNumber
+} as const) {}
+
 export type IProposal = {
   parameters: ProposalParameters
   proposerId: MemberId
   title: Text
   description: Text
   createdAt: BlockNumber
+  activatedAt: BlockNumber
+  councilApprovals: u32
+  executionSchedule: Option<ExecutionSchedule>
   status: ProposalStatus
   votingResults: VotingResults
 }
@@ -76,6 +97,7 @@ export const IProposalStatus: { [key: string]: string } = {
   ExecutionFailed: 'ExecutionFailed',
   Finalized: 'Finalized',
   Slashed: 'Slashed',
+  PendingConstitutionality: 'PendingConstitutionality',
 }
 
 export type IActiveStake = {
@@ -131,10 +153,12 @@ export class FinalizationData
 
 export class Active extends Option.with(ActiveStake) {}
 export class Finalized extends FinalizationData {}
+export class PendingConstitutionality extends Option.with(ActiveStake) {}
 
 export class ProposalStatus extends JoyEnum({
   Active,
   Finalized,
+  PendingConstitutionality,
 } as const) {}
 
 export const VoteKinds = ['Approve', 'Reject', 'Slash', 'Abstain'] as const
@@ -170,6 +194,12 @@ export class Proposal
     description: Text,
     // When it was created.
     createdAt: u32, // BlockNumber
+    // When the current voting started.
+    activatedAt: u32, // BlockNumber
+    // Number of councils that approved the proposal so far.
+    councilApprovals: u32,
+    // Optional execution block defined by the proposer.
+    executionSchedule: Option.with(ExecutionSchedule),
     /// Current proposal status
     status: ProposalStatus,
     /// Curring voting result for the proposal
@@ -187,6 +217,12 @@ export class DiscussionThread extends JoyStructDecorated({
   author_id: MemberId,
 }) {}
 
+export class ModerationAction extends JoyStructDecorated({
+  moderated_at: u32, // BlockNumber
+  moderator_id: MemberId,
+  rationale: Bytes,
+}) {}
+
 export class DiscussionPost extends JoyStructDecorated({
   text: Bytes,
   /// When post was added.
@@ -199,6 +235,10 @@ export class DiscussionPost extends JoyStructDecorated({
   thread_id: ThreadId,
   /// Defines how many times this post was edited. Zero on creation.
   edition_number: u32,
+  /// Parent post id of the reply. None for the top level posts.
+  parent_post_id: Option.with(PostId),
+  /// Moderation of the post. Moderated posts are hidden.
+  moderation: Option.with(ModerationAction),
 }) {}
 
 export type IAddOpeningParameters = {
@@ -249,10 +289,38 @@ export class TerminateRoleParameters
   })
   implements ITerminateRoleParameters {}
 
+export class RecurringGrantParameters extends JoyStructDecorated({
+  destination: AccountId,
+  amount_per_payout: u128, // Balance
+  payout_interval: u32, // BlockNumber
+  ends_at: u32, // BlockNumber
+}) {}
+
+export class MembershipUserInfoConstraints extends JoyStructDecorated({
+  min_handle_length: u32,
+  max_handle_length: u32,
+  max_avatar_uri_length: u32,
+  max_about_text_length: u32,
+}) {}
+
+export class ForumInputValidationConstraints extends JoyStructDecorated({
+  category_title: InputValidationLengthConstraint,
+  category_description: InputValidationLengthConstraint,
+  thread_title: InputValidationLengthConstraint,
+  post_text: InputValidationLengthConstraint,
+  thread_moderation_rationale: InputValidationLengthConstraint,
+  post_moderation_rationale: InputValidationLengthConstraint,
+}) {}
+
+export class DataObjectTypeParameters extends JoyStructDecorated({
+  description: Bytes,
+  active: bool,
+}) {}
+
 export class ProposalDetails extends JoyEnum({
   Text: Text,
-  RuntimeUpgrade: Bytes,
-  SetElectionParameters: ElectionParameters,
+  RuntimeUpgrade: 'Hash',
+  SetElectionParameters: Tuple.with([ElectionParameters, bool]),
   Spending: SpendingParams,
   SetLead: SetLead,
   SetContentWorkingGroupMintCapacity: u128,
@@ -267,6 +335,22 @@ export class ProposalDetails extends JoyEnum({
   SlashWorkingGroupLeaderStake: Tuple.with([WorkerId, 'Balance', WorkingGroup]),
   SetWorkingGroupLeaderReward: Tuple.with([WorkerId, 'Balance', WorkingGroup]),
   TerminateWorkingGroupLeaderRole: TerminateRoleParameters,
+  Batch: 'Vec<ProposalDetails>',
+  UpdateProposalParameters: Tuple.with([u32, ProposalParameters]),
+  RecurringGrant: RecurringGrantParameters,
+  CancelRecurringGrant: u32,
+  SetNewMembershipsAllowed: bool,
+  SetMembershipUserInfoConstraints: MembershipUserInfoConstraints,
+  SetMembershipScreeningAuthority: AccountId,
+  SetForumSudo: Option.with(AccountId),
+  SetForumInputValidationConstraints: ForumInputValidationConstraints,
+  SetMaxMemoLength: u32,
+  SetServiceDiscoveryDefaultLifetime: u32, // BlockNumber
+  RegisterDataObjectType: DataObjectTypeParameters,
+  UpdateDataObjectType: Tuple.with([u64, DataObjectTypeParameters]),
+  ActivateDataObjectType: u64,
+  DeactivateDataObjectType: u64,
+  SetServiceDiscoveryBootstrapEndpoints: Vec.with(Bytes),
 } as const) {}
 
 // export default proposalTypes;
@@ -282,12 +366,19 @@ export const proposalsTypes = {
   ThreadCounter,
   DiscussionThread,
   DiscussionPost,
+  ModerationAction,
+  ExecutionSchedule,
+  RecurringGrantParameters,
+  MembershipUserInfoConstraints,
+  ForumInputValidationConstraints,
+  DataObjectTypeParameters,
   AddOpeningParameters,
   FillOpeningParameters,
   TerminateRoleParameters,
   // Expose in registry for api.createType purposes:
   ActiveStake,
   Finalized,
+  PendingConstitutionality,
   ProposalDecisionStatus,
   ExecutionFailed,
   Approved,